@@ -1,6 +1,7 @@
-//! This is an example of sorting arrays along an axis.
-//! This file may not be so instructive except for advanced users, instead it
-//! could be a "feature preview" before sorting is added to the main crate.
+//! This is an example of permuting the subviews of an array along an axis,
+//! for example to order the rows by a key. To sort each lane of an array, use
+//! `sort_axis` and `argsort_axis` instead.
+//! This file may not be so instructive except for advanced users.
 //!
 use ndarray::prelude::*;
 use ndarray::{Data, RemoveAxis, Zip};
@@ -49,7 +50,7 @@ impl Permutation {
 pub trait SortArray {
     /// ***Panics*** if `axis` is out of bounds.
     fn identity(&self, axis: Axis) -> Permutation;
-    fn sort_axis_by<F>(&self, axis: Axis, less_than: F) -> Permutation
+    fn sort_permutation_by<F>(&self, axis: Axis, less_than: F) -> Permutation
     where
         F: FnMut(usize, usize) -> bool;
 }
@@ -74,7 +75,7 @@ where
         }
     }
 
-    fn sort_axis_by<F>(&self, axis: Axis, mut less_than: F) -> Permutation
+    fn sort_permutation_by<F>(&self, axis: Axis, mut less_than: F) -> Permutation
     where
         F: FnMut(usize, usize) -> bool,
     {
@@ -169,7 +170,7 @@ fn main() {
     let a = Array::linspace(0., 63., 64).into_shape((8, 8)).unwrap();
     let strings = a.map(|x| x.to_string());
 
-    let perm = a.sort_axis_by(Axis(1), |i, j| a[[i, 0]] > a[[j, 0]]);
+    let perm = a.sort_permutation_by(Axis(1), |i, j| a[[i, 0]] > a[[j, 0]]);
     println!("{:?}", perm);
     let b = a.permute_axis(Axis(0), &perm);
     println!("{:?}", b);
@@ -236,7 +237,7 @@ mod tests {
         let at = a.t().to_owned();
 
         // c layout permute
-        let perm = a.sort_axis_by(Axis(0), |i, j| a[[i, 0]] < a[[j, 0]]);
+        let perm = a.sort_permutation_by(Axis(0), |i, j| a[[i, 0]] < a[[j, 0]]);
 
         let b = a.permute_axis(Axis(0), &perm);
         assert_eq!(b, answer);
@@ -1,7 +1,9 @@
 use std::cell::Cell;
+use std::fmt;
 use std::mem::MaybeUninit;
 
 use crate::math_cell::MathCell;
+use crate::{ArrayBase, Data, Ix1, ScalarOperand};
 
 /// A producer element that can be assigned to once
 pub trait AssignElem<T> {
@@ -37,3 +39,92 @@ impl<'a, T> AssignElem<T> for &'a mut MaybeUninit<T> {
         *self = MaybeUninit::new(input);
     }
 }
+
+/// The values of [`.mask_assign()`](crate::ArrayBase::mask_assign): either a
+/// scalar, which is assigned to every selected element, or a reference to a
+/// one-dimensional array with one value for each selected element, in order.
+pub trait MaskValues<A> {
+    /// Return the number of values, or `None` for a scalar.
+    fn count(&self) -> Option<usize>;
+
+    /// Return the value for the selected element `index`.
+    fn value(&self, index: usize) -> A;
+}
+
+/// A scalar, assigned to every selected element.
+impl<A: ScalarOperand> MaskValues<A> for A {
+    fn count(&self) -> Option<usize> {
+        None
+    }
+
+    fn value(&self, _index: usize) -> A {
+        self.clone()
+    }
+}
+
+/// One value for each selected element.
+impl<A, S> MaskValues<A> for &ArrayBase<S, Ix1>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+{
+    fn count(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn value(&self, index: usize) -> A {
+        self[index].clone()
+    }
+}
+
+/// How the fancy indexing methods, like [`.take()`](crate::ArrayBase::take),
+/// treat indices that are out of bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexMode {
+    /// Panic. A negative index counts from the end of the axis.
+    Raise,
+    /// Wrap around the axis, so that index `i` of an axis of length `n` is
+    /// `i` modulo `n`, in `0..n`.
+    Wrap,
+    /// Clip to the bounds of the axis, so that negative indices are the first
+    /// element and too large indices are the last.
+    Clip,
+}
+
+/// An index element of the fancy indexing methods, like
+/// [`.take()`](crate::ArrayBase::take): a primitive integer, where a negative
+/// index counts from the end of the axis.
+pub trait TakeIndex: Copy + fmt::Display {
+    /// Return the index into an axis of length `len`, or `None` if it is out
+    /// of bounds in `mode`.
+    fn resolve(self, len: usize, mode: IndexMode) -> Option<usize>;
+}
+
+macro_rules! impl_take_index {
+    ($($t:ty),*) => {
+        $(
+            impl TakeIndex for $t {
+                fn resolve(self, len: usize, mode: IndexMode) -> Option<usize> {
+                    // i128 holds every index and length without overflow
+                    let (index, len) = (self as i128, len as i128);
+                    if len == 0 {
+                        return None;
+                    }
+                    let index = match mode {
+                        IndexMode::Raise if index < 0 => index + len,
+                        IndexMode::Raise => index,
+                        IndexMode::Wrap => index.rem_euclid(len),
+                        IndexMode::Clip => index.max(0).min(len - 1),
+                    };
+                    if 0 <= index && index < len {
+                        Some(index as usize)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_take_index!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
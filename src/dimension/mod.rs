@@ -6,8 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::error::{from_kind, ErrorKind, ShapeError};
-use crate::slice::SliceArg;
+use crate::error::{from_kind, out_of_bounds, ErrorKind, ShapeError};
+use crate::slice::{ellipsis_len, expand_ellipsis, SliceArg};
 use crate::{Ix, Ixs, Slice, SliceInfoElem};
 use crate::shape_builder::Strides;
 use num_integer::div_floor;
@@ -20,7 +20,7 @@ pub use self::dim::*;
 pub use self::dimension_trait::Dimension;
 pub use self::dynindeximpl::IxDynImpl;
 pub use self::ndindex::NdIndex;
-pub use self::ops::DimAdd;
+pub use self::ops::{DimAdd, DimSub};
 pub use self::remove_axis::RemoveAxis;
 
 pub(crate) use self::axes::axes_of;
@@ -408,6 +408,67 @@ fn to_abs_slice(axis_len: usize, slice: Slice) -> (usize, usize, isize) {
     (start, end, step)
 }
 
+/// Return the length of `axis`, or an `OutOfBounds` error without details
+/// if it is not an axis of `dim`.
+pub(crate) fn checked_len_of<D: Dimension>(dim: &D, axis: Axis) -> Result<Ix, ShapeError> {
+    dim.slice()
+        .get(axis.index())
+        .cloned()
+        .ok_or_else(|| from_kind(ErrorKind::OutOfBounds))
+}
+
+/// Return an error unless `index` is within an axis of length `len`, counting
+/// negative indices from the back.
+pub(crate) fn check_index(axis: usize, index: Ixs, len: Ix) -> Result<(), ShapeError> {
+    if (index < 0 && index.unsigned_abs() <= len) || (index >= 0 && (index as Ix) < len) {
+        Ok(())
+    } else {
+        Err(out_of_bounds(axis, index, len))
+    }
+}
+
+/// Return an error unless `bound` is a valid start or end of a slice of an
+/// axis of length `len`, counting negative bounds from the back.
+fn check_slice_bound(axis: usize, bound: Ixs, len: Ix) -> Result<(), ShapeError> {
+    if bound.unsigned_abs() <= len {
+        Ok(())
+    } else {
+        Err(out_of_bounds(axis, bound, len))
+    }
+}
+
+/// Return an error if slicing an array of shape `dim` with `indices` would
+/// panic.
+///
+/// The error is `OutOfBounds` for an index or a slice bound which is out of
+/// bounds, `Unsupported` for a step of zero, and `IncompatibleShape` if the
+/// number of axes doesn't match.
+pub(crate) fn check_slice_info(dim: &[Ix], indices: &[SliceInfoElem]) -> Result<(), ShapeError> {
+    let in_ndim = indices.in_ndim();
+    let ellipsis_len = match indices.iter().filter(|s| s.is_ellipsis()).count() {
+        0 if in_ndim == dim.len() => 0,
+        1 if in_ndim <= dim.len() => dim.len() - in_ndim,
+        _ => return Err(from_kind(ErrorKind::IncompatibleShape)),
+    };
+    let elems = expand_ellipsis(indices, ellipsis_len).filter(|s| !s.is_new_axis());
+    for (axis, (&len, elem)) in dim.iter().zip(elems).enumerate() {
+        match elem {
+            SliceInfoElem::Index(index) => check_index(axis, index, len)?,
+            SliceInfoElem::Slice { start, end, step } => {
+                check_slice_bound(axis, start, len)?;
+                if let Some(end) = end {
+                    check_slice_bound(axis, end, len)?;
+                }
+                if step == 0 {
+                    return Err(from_kind(ErrorKind::Unsupported));
+                }
+            }
+            SliceInfoElem::NewAxis | SliceInfoElem::Ellipsis => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
 /// Returns the offset from the lowest-address element to the logically first
 /// element.
 pub fn offset_from_low_addr_ptr_to_logical_ptr<D: Dimension>(dim: &D, strides: &D) -> usize {
@@ -609,11 +670,12 @@ pub fn slices_intersect<D: Dimension>(
     indices1: impl SliceArg<D>,
     indices2: impl SliceArg<D>,
 ) -> bool {
-    debug_assert_eq!(indices1.in_ndim(), indices2.in_ndim());
-    for (&axis_len, &si1, &si2) in izip!(
+    let indices1 = expand_ellipsis(indices1.as_ref(), ellipsis_len(indices1.as_ref(), dim.ndim()));
+    let indices2 = expand_ellipsis(indices2.as_ref(), ellipsis_len(indices2.as_ref(), dim.ndim()));
+    for (&axis_len, si1, si2) in izip!(
         dim.slice(),
-        indices1.as_ref().iter().filter(|si| !si.is_new_axis()),
-        indices2.as_ref().iter().filter(|si| !si.is_new_axis()),
+        indices1.filter(|si| !si.is_new_axis()),
+        indices2.filter(|si| !si.is_new_axis()),
     ) {
         // The slices do not intersect iff any pair of `SliceInfoElem` does not intersect.
         match (si1, si2) {
@@ -662,7 +724,10 @@ pub fn slices_intersect<D: Dimension>(
                     return false;
                 }
             }
-            (SliceInfoElem::NewAxis, _) | (_, SliceInfoElem::NewAxis) => unreachable!(),
+            (SliceInfoElem::NewAxis, _)
+            | (_, SliceInfoElem::NewAxis)
+            | (SliceInfoElem::Ellipsis, _)
+            | (_, SliceInfoElem::Ellipsis) => unreachable!(),
         }
     }
     true
@@ -88,3 +88,31 @@ impl_dimadd_const_out_dyn!(6, IxDyn);
 impl<D: Dimension> DimAdd<D> for IxDyn {
     type Output = IxDyn;
 }
+
+/// Subtracts the second dimension from the first at compile time.
+pub trait DimSub<D: Dimension> {
+    /// The difference of the two dimensions.
+    type Output: Dimension;
+}
+
+macro_rules! impl_dimsub_const {
+    ($lhs:expr; $($rhs:expr),+) => {
+        $(
+            impl DimSub<Dim<[usize; $rhs]>> for Dim<[usize; $lhs]> {
+                type Output = Dim<[usize; $lhs - $rhs]>;
+            }
+        )+
+    };
+}
+
+impl_dimsub_const!(0; 0);
+impl_dimsub_const!(1; 0, 1);
+impl_dimsub_const!(2; 0, 1, 2);
+impl_dimsub_const!(3; 0, 1, 2, 3);
+impl_dimsub_const!(4; 0, 1, 2, 3, 4);
+impl_dimsub_const!(5; 0, 1, 2, 3, 4, 5);
+impl_dimsub_const!(6; 0, 1, 2, 3, 4, 5, 6);
+
+impl<D: Dimension> DimSub<D> for IxDyn {
+    type Output = IxDyn;
+}
@@ -15,6 +15,7 @@ use std::fmt;
 pub struct ShapeError {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    out_of_bounds: Option<IndexOutOfBounds>,
 }
 
 impl ShapeError {
@@ -28,6 +29,27 @@ impl ShapeError {
     pub fn from_kind(error: ErrorKind) -> Self {
         from_kind(error)
     }
+
+    /// Return the index and the axis, if this is an [`ErrorKind::OutOfBounds`]
+    /// error for an index along an axis.
+    #[inline]
+    pub fn out_of_bounds(&self) -> Option<IndexOutOfBounds> {
+        self.out_of_bounds
+    }
+}
+
+/// The details of an [`ErrorKind::OutOfBounds`] error: an index, which is
+/// out of bounds for an axis.
+///
+/// See [`ShapeError::out_of_bounds`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The axis
+    pub axis: usize,
+    /// The index; negative indices count from the back of the axis
+    pub index: isize,
+    /// The length of the axis
+    pub len: usize,
 }
 
 /// Error code for an error related to array shape or layout.
@@ -53,7 +75,18 @@ pub enum ErrorKind {
 
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError {
-    ShapeError { repr: k }
+    ShapeError {
+        repr: k,
+        out_of_bounds: None,
+    }
+}
+
+/// Return an [`ErrorKind::OutOfBounds`] error for `index` along `axis`.
+pub fn out_of_bounds(axis: usize, index: isize, len: usize) -> ShapeError {
+    ShapeError {
+        repr: ErrorKind::OutOfBounds,
+        out_of_bounds: Some(IndexOutOfBounds { axis, index, len }),
+    }
 }
 
 impl PartialEq for ErrorKind {
@@ -83,7 +116,15 @@ impl fmt::Display for ShapeError {
             ErrorKind::Unsupported => "unsupported operation",
             ErrorKind::Overflow => "arithmetic overflow",
         };
-        write!(f, "ShapeError/{:?}: {}", self.kind(), description)
+        write!(f, "ShapeError/{:?}: {}", self.kind(), description)?;
+        if let Some(IndexOutOfBounds { axis, index, len }) = self.out_of_bounds {
+            write!(
+                f,
+                ": index {} is out of bounds for axis {} with length {}",
+                index, axis, len
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -8,6 +8,16 @@
 
 //! Methods for two-dimensional arrays.
 use crate::imp_prelude::*;
+use crate::Slice;
+use alloc::vec::Vec;
+use core::ops::Add;
+use num_traits::Zero;
+
+/// Return the number of elements of a row of length `len` before column
+/// `i + k`, for the rows of triangular matrices.
+fn columns_before(i: usize, k: isize, len: usize) -> usize {
+    (i as isize).saturating_add(k).max(0).min(len as isize) as usize
+}
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -141,4 +151,202 @@ where
         let (m, n) = self.dim();
         m == n
     }
+
+    /// Return the sum of the elements on the main diagonal.
+    ///
+    /// The array doesn't have to be square; the trace of an empty array is
+    /// zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.trace(), 5.);
+    /// ```
+    pub fn trace(&self) -> A
+    where
+        S: Data,
+        A: Clone + Add<Output = A> + Zero,
+    {
+        self.diag().sum()
+    }
+
+    /// Return a view of the `k`-th diagonal of the array.
+    ///
+    /// The diagonal `k` is the sequence indexed by *(0, k)*, *(1, k + 1)*
+    /// etc. for `k >= 0`, and by *(-k, 0)*, *(1 - k, 1)* etc. for `k < 0`, as
+    /// long as both axes have elements. So `k > 0` is above the main diagonal
+    /// and `k < 0` below it; the diagonal is empty if it is outside of the
+    /// array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.diag_offset(0), a.diag());
+    /// assert_eq!(a.diag_offset(1), array![2, 6]);
+    /// assert_eq!(a.diag_offset(-1), array![4]);
+    /// assert_eq!(a.diag_offset(3).len(), 0);
+    /// ```
+    pub fn diag_offset(&self, k: isize) -> ArrayView1<'_, A>
+    where
+        S: Data,
+    {
+        self.view().into_diag_offset(k)
+    }
+
+    /// Return a read-write view of the `k`-th diagonal of the array.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals.
+    pub fn diag_offset_mut(&mut self, k: isize) -> ArrayViewMut1<'_, A>
+    where
+        S: DataMut,
+    {
+        self.view_mut().into_diag_offset(k)
+    }
+
+    /// Return the `k`-th diagonal as a one-dimensional array.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals.
+    pub fn into_diag_offset(mut self, k: isize) -> ArrayBase<S, Ix1> {
+        let axis = if k >= 0 { Axis(1) } else { Axis(0) };
+        let offset = k.unsigned_abs().min(self.len_of(axis));
+        self.slice_axis_inplace(axis, Slice::from(offset..));
+        self.into_diag()
+    }
+
+    /// Return a copy of the array with the elements below the `k`-th
+    /// diagonal set to zero, like NumPy’s `triu`.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals; the upper triangle with `k = 0` includes the main diagonal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(a.triu(0), array![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    /// assert_eq!(a.triu(1), array![[0, 2, 3], [0, 0, 6], [0, 0, 0]]);
+    /// assert_eq!(a.triu(-1), array![[1, 2, 3], [4, 5, 6], [0, 8, 9]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn triu(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let mut triu = self.to_owned();
+        triu.triu_inplace(k);
+        triu
+    }
+
+    /// Return a copy of the array with the elements above the `k`-th
+    /// diagonal set to zero, like NumPy’s `tril`.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals; the lower triangle with `k = 0` includes the main diagonal.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.tril(0), array![[1, 0, 0], [4, 5, 0]]);
+    /// assert_eq!(a.tril(-1), array![[0, 0, 0], [4, 0, 0]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn tril(&self, k: isize) -> Array2<A>
+    where
+        S: Data,
+        A: Clone + Zero,
+    {
+        let mut tril = self.to_owned();
+        tril.tril_inplace(k);
+        tril
+    }
+
+    /// Set the elements below the `k`-th diagonal to zero.
+    ///
+    /// This is the in place version of [`.triu()`](Self::triu).
+    pub fn triu_inplace(&mut self, k: isize)
+    where
+        S: DataMut,
+        A: Zero,
+    {
+        let ncols = self.ncols();
+        for (i, mut row) in self.rows_mut().into_iter().enumerate() {
+            for x in row.iter_mut().take(columns_before(i, k, ncols)) {
+                *x = A::zero();
+            }
+        }
+    }
+
+    /// Set the elements above the `k`-th diagonal to zero.
+    ///
+    /// This is the in place version of [`.tril()`](Self::tril).
+    pub fn tril_inplace(&mut self, k: isize)
+    where
+        S: DataMut,
+        A: Zero,
+    {
+        let ncols = self.ncols();
+        for (i, mut row) in self.rows_mut().into_iter().enumerate() {
+            for x in row.iter_mut().skip(columns_before(i, k.saturating_add(1), ncols)) {
+                *x = A::zero();
+            }
+        }
+    }
+
+    /// Return the indices of the elements on and above the `k`-th diagonal,
+    /// in row major order, as the rows of an array of shape `(count, 2)`,
+    /// like NumPy’s `triu_indices_from`.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals. The flat positions of the indices are given by
+    /// [`ravel_multi_index_array`](crate::ravel_multi_index_array).
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 3));
+    /// assert_eq!(a.triu_indices(1), array![[0, 1], [0, 2], [1, 2]]);
+    /// ```
+    pub fn triu_indices(&self, k: isize) -> Array2<usize> {
+        let (nrows, ncols) = self.dim();
+        triangle_indices(nrows, ncols, |i| columns_before(i, k, ncols)..ncols)
+    }
+
+    /// Return the indices of the elements on and below the `k`-th diagonal,
+    /// in row major order, as the rows of an array of shape `(count, 2)`,
+    /// like NumPy’s `tril_indices_from`.
+    ///
+    /// See [`.diag_offset()`](Self::diag_offset) for the numbering of the
+    /// diagonals.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 2));
+    /// assert_eq!(a.tril_indices(0), array![[0, 0], [1, 0], [1, 1], [2, 0], [2, 1]]);
+    /// ```
+    pub fn tril_indices(&self, k: isize) -> Array2<usize> {
+        let (nrows, ncols) = self.dim();
+        triangle_indices(nrows, ncols, |i| 0..columns_before(i, k.saturating_add(1), ncols))
+    }
+}
+
+/// Return the indices `(i, j)` for `j` in `columns(i)`, for each row `i`.
+fn triangle_indices<F>(nrows: usize, ncols: usize, columns: F) -> Array2<usize>
+where
+    F: Fn(usize) -> core::ops::Range<usize>,
+{
+    let mut indices = Vec::with_capacity(nrows.saturating_mul(ncols));
+    for i in 0..nrows {
+        for j in columns(i) {
+            indices.push(i);
+            indices.push(j);
+        }
+    }
+    let count = indices.len() / 2;
+    Array2::from_shape_vec((count, 2), indices).unwrap()
 }
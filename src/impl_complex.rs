@@ -0,0 +1,245 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::Num;
+use std::mem;
+use std::ops::Neg;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Methods specific to arrays with `Complex` elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = Complex<T>>,
+    D: Dimension,
+{
+    /// Return a read-only view of the real components of the elements.
+    ///
+    /// No data is copied; the view has the same shape as the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// assert_eq!(a.re(), array![1., 3.]);
+    /// assert_eq!(a.im(), array![2., 4.]);
+    /// ```
+    pub fn re(&self) -> ArrayView<'_, T, D>
+    where
+        S: Data,
+    {
+        self.view().split_complex().re
+    }
+
+    /// Return a read-only view of the imaginary components of the elements.
+    ///
+    /// No data is copied; the view has the same shape as the array.
+    pub fn im(&self) -> ArrayView<'_, T, D>
+    where
+        S: Data,
+    {
+        self.view().split_complex().im
+    }
+
+    /// Return a read-write view of the real components of the elements.
+    ///
+    /// No data is copied; the view has the same shape as the array.
+    pub fn re_mut(&mut self) -> ArrayViewMut<'_, T, D>
+    where
+        S: DataMut,
+    {
+        self.view_mut().split_complex().re
+    }
+
+    /// Return a read-write view of the imaginary components of the elements.
+    ///
+    /// No data is copied; the view has the same shape as the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let mut a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// a.im_mut().fill(0.);
+    /// assert_eq!(a, array![Complex64::new(1., 0.), Complex64::new(3., 0.)]);
+    /// ```
+    pub fn im_mut(&mut self) -> ArrayViewMut<'_, T, D>
+    where
+        S: DataMut,
+    {
+        self.view_mut().split_complex().im
+    }
+
+    /// Return a read-only view of the array as an array of real numbers,
+    /// with a new trailing axis of length 2 holding the real and imaginary
+    /// components of each element.
+    ///
+    /// No data is copied.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// assert_eq!(a.view_as_real(), array![[1., 2.], [3., 4.]]);
+    /// ```
+    pub fn view_as_real(&self) -> ArrayView<'_, T, D::Larger>
+    where
+        S: Data,
+    {
+        let (dim, strides) = self.real_dim_strides();
+        unsafe { ArrayView::new(self.ptr.cast(), dim, strides) }
+    }
+
+    /// Return a read-write view of the array as an array of real numbers,
+    /// with a new trailing axis of length 2 holding the real and imaginary
+    /// components of each element.
+    ///
+    /// No data is copied.
+    pub fn view_as_real_mut(&mut self) -> ArrayViewMut<'_, T, D::Larger>
+    where
+        S: DataMut,
+    {
+        let view = self.view_mut();
+        let (dim, strides) = view.real_dim_strides();
+        unsafe { ArrayViewMut::new(view.ptr.cast(), dim, strides) }
+    }
+
+    /// Return the dimension and strides of the array reinterpreted as an
+    /// array of `T` with a trailing axis of length 2.
+    fn real_dim_strides(&self) -> (D::Larger, D::Larger) {
+        // Check that the size and alignment of `Complex<T>` are as expected.
+        // These assertions should always pass, for arbitrary `T`.
+        assert_eq!(
+            mem::size_of::<Complex<T>>(),
+            mem::size_of::<T>().checked_mul(2).unwrap()
+        );
+        assert_eq!(mem::align_of::<Complex<T>>(), mem::align_of::<T>());
+
+        let n = self.ndim();
+        let mut dim = self.dim.insert_axis(Axis(n));
+        let mut strides = self.strides.insert_axis(Axis(n));
+        dim[n] = 2;
+        strides[n] = 1;
+        // Double the strides. In the zero-sized element case and for axes of
+        // length <= 1, we leave the strides as-is to avoid possible overflow.
+        if mem::size_of::<T>() != 0 {
+            for ax in 0..n {
+                if dim[ax] > 1 {
+                    strides[ax] = (strides[ax] as isize * 2) as usize;
+                }
+            }
+        }
+        (dim, strides)
+    }
+
+    /// Return the complex conjugate of each element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., -4.)];
+    /// assert_eq!(a.conj(), array![Complex64::new(1., -2.), Complex64::new(3., 4.)]);
+    /// ```
+    pub fn conj(&self) -> Array<Complex<T>, D>
+    where
+        S: Data,
+        T: Clone + Num + Neg<Output = T>,
+    {
+        self.map(|z| z.conj())
+    }
+
+    /// Replace each element with its complex conjugate.
+    pub fn conj_inplace(&mut self)
+    where
+        S: DataMut,
+        T: Clone + Neg<Output = T>,
+    {
+        self.im_mut().mapv_inplace(|im| -im);
+    }
+
+    /// Return the squared modulus `re² + im²` of each element.
+    pub fn norm_sqr(&self) -> Array<T, D>
+    where
+        S: Data,
+        T: Clone + Num,
+    {
+        self.map(|z| z.norm_sqr())
+    }
+
+    /// Return the modulus (absolute value) of each element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(3., 4.), Complex64::new(0., -2.)];
+    /// assert_eq!(a.norm(), array![5., 2.]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn norm(&self) -> Array<T, D>
+    where
+        S: Data,
+        T: Float,
+    {
+        self.map(|z| z.norm())
+    }
+
+    /// Return the argument (phase angle) of each element, in radians in the
+    /// range `(-π, π]`.
+    #[cfg(feature = "std")]
+    pub fn arg(&self) -> Array<T, D>
+    where
+        S: Data,
+        T: Float,
+    {
+        self.map(|z| z.arg())
+    }
+}
+
+impl<T, D> Array<Complex<T>, D>
+where
+    D: Dimension,
+{
+    /// Create an array of complex numbers from the moduli `r` and the
+    /// arguments `theta` (in radians) of its elements.
+    ///
+    /// **Panics** if the shapes of `r` and `theta` don't match.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::{array, Array1};
+    /// use num_complex::Complex64;
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Array1::<Complex64>::from_polar(&array![1., 2.], &array![0., PI / 2.]);
+    /// assert_abs_diff_eq!(z.re(), array![1., 0.], epsilon = 1e-12);
+    /// assert_abs_diff_eq!(z.im(), array![0., 2.], epsilon = 1e-12);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_polar<S1, S2>(r: &ArrayBase<S1, D>, theta: &ArrayBase<S2, D>) -> Self
+    where
+        T: Float,
+        S1: Data<Elem = T>,
+        S2: Data<Elem = T>,
+    {
+        Zip::from(r)
+            .and(theta)
+            .map_collect(|&r, &theta| Complex::from_polar(r, theta))
+    }
+}
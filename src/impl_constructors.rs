@@ -231,6 +231,60 @@ where
         arr
     }
 
+    /// Create a square 2D matrix with `diag` as its `k`-th diagonal and
+    /// zeros elsewhere.
+    ///
+    /// The matrix has `diag.len() + |k|` rows and columns; see
+    /// [`.diag_offset()`](ArrayBase::diag_offset) for the numbering of the
+    /// diagonals.
+    ///
+    /// **Panics** if the number of elements would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let array = Array2::from_diag_offset(&array![1, 2], -1);
+    /// assert_eq!(array, array![[0, 0, 0], [1, 0, 0], [0, 2, 0]]);
+    /// ```
+    pub fn from_diag_offset<S2>(diag: &ArrayBase<S2, Ix1>, k: isize) -> Self
+    where
+        A: Clone + Zero,
+        S: DataMut,
+        S2: Data<Elem = A>,
+    {
+        let n = diag
+            .len()
+            .checked_add(k.unsigned_abs())
+            .expect("ndarray: Shape too large, diagonal length overflows usize");
+        let mut arr = Self::zeros((n, n));
+        arr.diag_offset_mut(k).assign(diag);
+        arr
+    }
+
+    /// Create a matrix of shape `(n, m)` with ones on and below the `k`-th
+    /// diagonal and zeros elsewhere, like NumPy’s `tri`.
+    ///
+    /// See [`.diag_offset()`](ArrayBase::diag_offset) for the numbering of the
+    /// diagonals.
+    ///
+    /// **Panics** if `n * m` would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let array = Array2::<i32>::tri(2, 3, 0);
+    /// assert_eq!(array, array![[1, 0, 0], [1, 1, 0]]);
+    /// ```
+    pub fn tri(n: Ix, m: Ix, k: isize) -> Self
+    where
+        S: DataMut,
+        A: Clone + Zero + One,
+    {
+        let mut tri = Self::from_elem((n, m), A::one());
+        tri.tril_inplace(k);
+        tri
+    }
+
     /// Create a square 2D matrix of the specified size, with the specified
     /// element along the diagonal and zeros elsewhere.
     ///
@@ -0,0 +1,140 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::MaskValues;
+
+/// # Boolean Mask Methods
+///
+/// These methods select the elements of an array with a mask of `bool`,
+/// like boolean indexing in NumPy.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Return a one-dimensional array of the elements where `mask` is
+    /// `true`, in logical order.
+    ///
+    /// The mask is broadcast to the shape of the array.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, aview1};
+    ///
+    /// let a = array![[1, -2, 3], [-4, 5, -6]];
+    /// assert_eq!(a.mask_select(&a.mapv(|x| x > 0)), array![1, 3, 5]);
+    ///
+    /// // a mask of the columns, broadcast to each row
+    /// assert_eq!(a.mask_select(&aview1(&[true, false, true])), array![1, 3, -4, -6]);
+    /// ```
+    pub fn mask_select<S2, E>(&self, mask: &ArrayBase<S2, E>) -> Array1<A>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = bool>,
+        E: Dimension,
+    {
+        let mask = mask.broadcast_unwrap(self.raw_dim());
+        self.iter()
+            .zip(&mask)
+            .filter(|&(_, &selected)| selected)
+            .map(|(x, _)| x.clone())
+            .collect()
+    }
+
+    /// Assign `values` to the elements where `mask` is `true`.
+    ///
+    /// `values` is either a scalar, which is assigned to each selected
+    /// element, or a reference to a one-dimensional array with one value for
+    /// each selected element, in logical order; see [`MaskValues`]. The
+    /// mask is broadcast to the shape of the array.
+    ///
+    /// **Panics** if broadcasting isn’t possible, or if the length of the
+    /// array of values is not the number of selected elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, -2, 3], [-4, 5, -6]];
+    /// let negative = a.mapv(|x| x < 0);
+    /// a.mask_assign(&negative, 0);
+    /// assert_eq!(a, array![[1, 0, 3], [0, 5, 0]]);
+    ///
+    /// a.mask_assign(&negative, &array![7, 8, 9]);
+    /// assert_eq!(a, array![[1, 7, 3], [8, 5, 9]]);
+    /// ```
+    pub fn mask_assign<S2, E, V>(&mut self, mask: &ArrayBase<S2, E>, values: V)
+    where
+        S: DataMut,
+        S2: Data<Elem = bool>,
+        E: Dimension,
+        V: MaskValues<A>,
+    {
+        let mask = mask.broadcast_unwrap(self.raw_dim());
+        if let Some(len) = values.count() {
+            let count = mask.iter().filter(|&&selected| selected).count();
+            if len != count {
+                panic!(
+                    "ndarray: could not assign {} values to the {} elements selected by the mask",
+                    len, count
+                );
+            }
+        }
+        let selected = self
+            .iter_mut()
+            .zip(&mask)
+            .filter(|&(_, &selected)| selected);
+        for (index, (x, _)) in selected.enumerate() {
+            *x = values.value(index);
+        }
+    }
+
+    /// Along `axis`, select the subviews where `mask` is `true`, and copy
+    /// them into a new array.
+    ///
+    /// This is [`.select()`](Self::select) with a mask instead of indices.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the length of `mask` is
+    /// not the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.compress(Axis(0), &array![true, false, true]), array![[1, 2], [5, 6]]);
+    /// assert_eq!(a.compress(Axis(1), &array![false, true]), array![[2], [4], [6]]);
+    /// ```
+    pub fn compress<S2>(&self, axis: Axis, mask: &ArrayBase<S2, Ix1>) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = bool>,
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if mask.len() != axis_len {
+            panic!(
+                "ndarray: mask of length {} does not match axis {} of length {}",
+                mask.len(),
+                axis.index(),
+                axis_len
+            );
+        }
+        let indices: Vec<usize> = mask
+            .iter()
+            .enumerate()
+            .filter(|&(_, &selected)| selected)
+            .map(|(i, _)| i)
+            .collect();
+        self.select(axis, &indices)
+    }
+}
@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::convert::TryFrom;
 use std::mem::{size_of, ManuallyDrop};
 use alloc::slice;
 use alloc::vec;
@@ -24,7 +25,7 @@ use crate::dimension::{
 };
 use crate::dimension::broadcast::co_broadcast;
 use crate::dimension::reshape_dim;
-use crate::error::{self, ErrorKind, ShapeError, from_kind};
+use crate::error::{self, ErrorKind, ShapeError, from_kind, out_of_bounds};
 use crate::math_cell::MathCell;
 use crate::itertools::zip;
 use crate::AxisDescription;
@@ -36,7 +37,7 @@ use crate::iter::{
     AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksMut,
     IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
 };
-use crate::slice::{MultiSliceArg, SliceArg};
+use crate::slice::{ellipsis_len, expand_ellipsis, MultiSliceArg, SliceArg};
 use crate::stacking::concatenate;
 use crate::{NdIndex, Slice, SliceInfoElem};
 
@@ -464,6 +465,35 @@ where
         self.view().slice_move(info)
     }
 
+    /// Return a sliced view of the array, or an error instead of panicking
+    /// like [`.slice()`](Self::slice).
+    ///
+    /// **Errors** with [`ErrorKind::OutOfBounds`] if an index or a slice bound
+    /// is out of bounds, with the details in
+    /// [`ShapeError::out_of_bounds`](crate::ShapeError::out_of_bounds); with
+    /// `ErrorKind::Unsupported` if a step size is zero; and with
+    /// `ErrorKind::IncompatibleShape` if `info` does not match the number of
+    /// array axes.
+    ///
+    /// ```
+    /// use ndarray::{s, Array2, ErrorKind, IndexOutOfBounds};
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4));
+    /// assert_eq!(a.try_slice(s![1.., -1]).unwrap().shape(), &[2]);
+    ///
+    /// let error = a.try_slice(s![.., 2..5]).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::OutOfBounds);
+    /// assert_eq!(error.out_of_bounds(), Some(IndexOutOfBounds { axis: 1, index: 5, len: 4 }));
+    /// ```
+    pub fn try_slice<I>(&self, info: I) -> Result<ArrayView<'_, A, I::OutDim>, ShapeError>
+    where
+        I: SliceArg<D>,
+        S: Data,
+    {
+        dimension::check_slice_info(self.shape(), info.as_ref())?;
+        Ok(self.slice(info))
+    }
+
     /// Return a sliced read-write view of the array.
     ///
     /// See [*Slicing*](#slicing) for full documentation.
@@ -479,6 +509,27 @@ where
         self.view_mut().slice_move(info)
     }
 
+    /// Return a sliced read-write view of the array, or an error instead of
+    /// panicking like [`.slice_mut()`](Self::slice_mut).
+    ///
+    /// **Errors** with [`ErrorKind::OutOfBounds`] if an index or a slice bound
+    /// is out of bounds, with the details in
+    /// [`ShapeError::out_of_bounds`](crate::ShapeError::out_of_bounds); with
+    /// `ErrorKind::Unsupported` if a step size is zero; and with
+    /// `ErrorKind::IncompatibleShape` if `info` does not match the number of
+    /// array axes.
+    pub fn try_slice_mut<I>(
+        &mut self,
+        info: I,
+    ) -> Result<ArrayViewMut<'_, A, I::OutDim>, ShapeError>
+    where
+        I: SliceArg<D>,
+        S: DataMut,
+    {
+        dimension::check_slice_info(self.shape(), info.as_ref())?;
+        Ok(self.slice_mut(info))
+    }
+
     /// Return multiple disjoint, sliced, mutable views of the array.
     ///
     /// See [*Slicing*](#slicing) for full documentation. See also
@@ -521,18 +572,27 @@ where
     where
         I: SliceArg<D>,
     {
-        assert_eq!(
-            info.in_ndim(),
-            self.ndim(),
-            "The input dimension of `info` must match the array to be sliced.",
-        );
-        let out_ndim = info.out_ndim();
+        let (ellipsis_len, out_ndim) = if info.as_ref().iter().any(SliceInfoElem::is_ellipsis) {
+            let ellipsis_len = ellipsis_len(info.as_ref(), self.ndim());
+            let out_ndim = expand_ellipsis(info.as_ref(), ellipsis_len)
+                .filter(|elem| !elem.is_index())
+                .count();
+            (ellipsis_len, out_ndim)
+        } else {
+            assert_eq!(
+                info.in_ndim(),
+                self.ndim(),
+                "The input dimension of `info` must match the array to be sliced.",
+            );
+            (0, info.out_ndim())
+        };
+        let elems = expand_ellipsis(info.as_ref(), ellipsis_len);
         let mut new_dim = I::OutDim::zeros(out_ndim);
         let mut new_strides = I::OutDim::zeros(out_ndim);
 
         let mut old_axis = 0;
         let mut new_axis = 0;
-        info.as_ref().iter().for_each(|&ax_info| match ax_info {
+        elems.for_each(|ax_info| match ax_info {
             SliceInfoElem::Slice { start, end, step } => {
                 // Slice the axis in-place to update the `dim`, `strides`, and `ptr`.
                 self.slice_axis_inplace(Axis(old_axis), Slice { start, end, step });
@@ -558,6 +618,7 @@ where
                 new_strides[new_axis] = 0;
                 new_axis += 1;
             }
+            SliceInfoElem::Ellipsis => unreachable!(),
         });
         debug_assert_eq!(old_axis, self.ndim());
         debug_assert_eq!(new_axis, out_ndim);
@@ -590,13 +651,18 @@ where
     where
         I: SliceArg<D>,
     {
-        assert_eq!(
-            info.in_ndim(),
-            self.ndim(),
-            "The input dimension of `info` must match the array to be sliced.",
-        );
+        let ellipsis_len = if info.as_ref().iter().any(SliceInfoElem::is_ellipsis) {
+            ellipsis_len(info.as_ref(), self.ndim())
+        } else {
+            assert_eq!(
+                info.in_ndim(),
+                self.ndim(),
+                "The input dimension of `info` must match the array to be sliced.",
+            );
+            0
+        };
         let mut axis = 0;
-        info.as_ref().iter().for_each(|&ax_info| match ax_info {
+        expand_ellipsis(info.as_ref(), ellipsis_len).for_each(|ax_info| match ax_info {
                 SliceInfoElem::Slice { start, end, step } => {
                     self.slice_axis_inplace(Axis(axis), Slice { start, end, step });
                     axis += 1;
@@ -607,6 +673,7 @@ where
                     axis += 1;
                 }
                 SliceInfoElem::NewAxis => panic!("`slice_collapse` does not support `NewAxis`."),
+                SliceInfoElem::Ellipsis => unreachable!(),
             });
         debug_assert_eq!(axis, self.ndim());
     }
@@ -940,6 +1007,40 @@ where
         self.view().index_axis_move(axis, index)
     }
 
+    /// Returns a view restricted to `index` along the axis, with the axis
+    /// removed, or an error instead of panicking like
+    /// [`.index_axis()`](Self::index_axis).
+    ///
+    /// **Errors** with [`ErrorKind::OutOfBounds`] if `axis` or `index` is out
+    /// of bounds; for `index`, with the details in
+    /// [`ShapeError::out_of_bounds`](crate::ShapeError::out_of_bounds).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, IndexOutOfBounds};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.try_index_axis(Axis(0), 2).unwrap(), array![5, 6]);
+    /// let error = a.try_index_axis(Axis(1), 2).unwrap_err();
+    /// assert_eq!(error.out_of_bounds(), Some(IndexOutOfBounds { axis: 1, index: 2, len: 2 }));
+    /// assert!(a.try_index_axis(Axis(2), 0).is_err());
+    /// ```
+    pub fn try_index_axis(
+        &self,
+        axis: Axis,
+        index: usize,
+    ) -> Result<ArrayView<'_, A, D::Smaller>, ShapeError>
+    where
+        S: Data,
+        D: RemoveAxis,
+    {
+        let len = dimension::checked_len_of(&self.dim, axis)?;
+        if index >= len {
+            let index = isize::try_from(index).unwrap_or(isize::MAX);
+            return Err(out_of_bounds(axis.index(), index, len));
+        }
+        Ok(self.index_axis(axis, index))
+    }
+
     /// Returns a mutable view restricted to `index` along the axis, with the
     /// axis removed.
     ///
@@ -1058,6 +1159,37 @@ where
         }
     }
 
+    /// Along `axis`, select arbitrary subviews corresponding to `indices`
+    /// and copy them into a new array, or return an error instead of
+    /// panicking like [`.select()`](Self::select).
+    ///
+    /// **Errors** with [`ErrorKind::OutOfBounds`] if `axis` or an element of
+    /// `indices` is out of bounds; for an element of `indices`, with the
+    /// details of the first one in
+    /// [`ShapeError::out_of_bounds`](crate::ShapeError::out_of_bounds).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, IndexOutOfBounds};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.try_select(Axis(0), &[2, 0]).unwrap(), array![[5, 6], [1, 2]]);
+    /// let error = a.try_select(Axis(0), &[1, 3, 4]).unwrap_err();
+    /// assert_eq!(error.out_of_bounds(), Some(IndexOutOfBounds { axis: 0, index: 3, len: 3 }));
+    /// ```
+    pub fn try_select(&self, axis: Axis, indices: &[Ix]) -> Result<Array<A, D>, ShapeError>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let len = dimension::checked_len_of(&self.dim, axis)?;
+        if let Some(&index) = indices.iter().find(|&&index| index >= len) {
+            let index = isize::try_from(index).unwrap_or(isize::MAX);
+            return Err(out_of_bounds(axis.index(), index, len));
+        }
+        Ok(self.select(axis, indices))
+    }
+
     /// Return a producer and iterable that traverses over the *generalized*
     /// rows of the array. For a 2D array these are the regular rows.
     ///
@@ -2096,6 +2228,31 @@ where
         unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
     }
 
+    /// Act like a larger size and/or shape array by *broadcasting*
+    /// into a larger shape, or return an error instead of `None` like
+    /// [`.broadcast()`](Self::broadcast).
+    ///
+    /// **Errors** with `ErrorKind::IncompatibleShape` if the shapes can not
+    /// be broadcast together, and with `ErrorKind::Overflow` if the number of
+    /// elements of `dim` would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, ErrorKind};
+    ///
+    /// let a = array![1, 2];
+    /// assert_eq!(a.try_broadcast_to((2, 2)).unwrap(), array![[1, 2], [1, 2]]);
+    /// assert_eq!(a.try_broadcast_to((2, 3)).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    /// ```
+    pub fn try_broadcast_to<E>(&self, dim: E) -> Result<ArrayView<'_, A, E::Dim>, ShapeError>
+    where
+        E: IntoDimension,
+        S: Data,
+    {
+        let dim = dim.into_dimension();
+        size_of_shape_checked(&dim)?;
+        self.broadcast(dim).ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))
+    }
+
     /// For two arrays or views, find their common shape if possible and
     /// broadcast them as array views into that shape.
     ///
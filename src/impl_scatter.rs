@@ -0,0 +1,229 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use core::ops::Add;
+use num_traits::{Bounded, Zero};
+
+use crate::imp_prelude::*;
+use crate::impl_take::resolve_all;
+use crate::{IndexMode, Slice, TakeIndex};
+
+/// Check that `src` has the shape of `dim`, except along `axis`, where it has
+/// one subview for each index.
+pub(crate) fn check_scatter_shape<D: Dimension>(dim: &D, src: &D, axis: Axis, len: usize) {
+    let mut expected = dim.clone();
+    expected.set_axis(axis, len);
+    if expected != *src {
+        panic!(
+            "ndarray: source of shape {:?} does not match {} indices along axis {} of an \
+             array of shape {:?}",
+            src.slice(),
+            len,
+            axis.index(),
+            dim.slice()
+        );
+    }
+}
+
+/// Return the bounds of the segments: segment `s` is the range
+/// `bounds[s]..bounds[s + 1]` of `segment_ids`.
+///
+/// The result has one subview for each segment up to the last id, so the ids
+/// determine its size, and the size of `bounds`.
+///
+/// **Panics** if `segment_ids` is not sorted, if its length is not the length
+/// of `axis` in `dim`, or if an id is so large that the number of elements of
+/// the result would exceed `isize::MAX`.
+pub(crate) fn segment_bounds<D>(segment_ids: &[usize], axis: Axis, dim: &D) -> Vec<usize>
+where
+    D: Dimension,
+{
+    let len = dim[axis.index()];
+    if segment_ids.len() != len {
+        panic!(
+            "ndarray: {} segment ids do not match axis {} of length {}",
+            segment_ids.len(),
+            axis.index(),
+            len
+        );
+    }
+    // the number of elements of one subview of the result, counting empty
+    // axes as one like `size_of_shape_checked` does
+    let subview_size = dim
+        .slice()
+        .iter()
+        .enumerate()
+        .filter(|&(i, &d)| i != axis.index() && d != 0)
+        .try_fold(1usize, |acc, (_, &d)| acc.checked_mul(d));
+    let max_segments = subview_size.map_or(0, |size| isize::MAX as usize / size);
+    let mut bounds = Vec::new();
+    for (i, &id) in segment_ids.iter().enumerate() {
+        if id < bounds.len().saturating_sub(1) {
+            panic!(
+                "ndarray: segment ids are not sorted: {} at position {} follows {}",
+                id,
+                i,
+                bounds.len() - 1
+            );
+        }
+        if id >= max_segments {
+            panic!("ndarray: segment id {} gives a result of too many elements", id);
+        }
+        while bounds.len() <= id {
+            bounds.push(i);
+        }
+    }
+    bounds.push(len);
+    bounds
+}
+
+pub(crate) fn segment_dim<D: Dimension>(dim: &D, axis: Axis, bounds: &[usize]) -> D {
+    let mut dim = dim.clone();
+    dim.set_axis(axis, bounds.len() - 1);
+    dim
+}
+
+/// Replace `x` with `y` if it is greater, or if `x` is not comparable, like
+/// `NaN`, so that the maximum skips the elements which are not comparable.
+pub(crate) fn max_assign<A>(x: &mut A, y: &A)
+where
+    A: Clone + PartialOrd,
+{
+    if *y > *x || (*x).partial_cmp(&*x).is_none() {
+        *x = y.clone();
+    }
+}
+
+/// # Scatter and Segment Reduction Methods
+///
+/// These methods accumulate the subviews along an axis into the subviews
+/// that an array of indices or of segment ids maps them to, like
+/// `index_add` and `segment_sum` in tensor libraries.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Along `axis`, add the subviews of `src` to the subviews at `indices`,
+    /// accumulating the subviews with repeated indices.
+    ///
+    /// For a two-dimensional array and `axis` zero, it computes
+    /// `a[[indices[k], j]] += src[[k, j]]` for each `k` and `j`. `src` has the
+    /// shape of the array, except along `axis`, where it has the length of
+    /// `indices`. Negative indices count from the end of the axis.
+    ///
+    /// **Panics** if `axis` is out of bounds, if an index is out of bounds, or
+    /// if the shape of `src` doesn’t match.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1, Axis};
+    ///
+    /// // a histogram
+    /// let mut counts = Array1::<u32>::zeros(4);
+    /// let bins = [1, 3, 1, 1, 0];
+    /// counts.scatter_add(Axis(0), &bins, &Array1::ones(bins.len()));
+    /// assert_eq!(counts, array![1, 3, 0, 1]);
+    ///
+    /// // sum the messages into the target node of each edge
+    /// let mut nodes = array![[0., 0.], [0., 0.], [0., 0.]];
+    /// let targets = [2, 0, 2];
+    /// let messages = array![[1., 2.], [3., 4.], [5., 6.]];
+    /// nodes.scatter_add(Axis(0), &targets, &messages);
+    /// assert_eq!(nodes, array![[3., 4.], [0., 0.], [6., 8.]]);
+    /// ```
+    pub fn scatter_add<I, S2>(&mut self, axis: Axis, indices: &[I], src: &ArrayBase<S2, D>)
+    where
+        A: Clone + Add<Output = A>,
+        S: DataMut,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+        I: TakeIndex,
+    {
+        let indices = resolve_all(indices, axis.index(), self.len_of(axis), IndexMode::Raise);
+        check_scatter_shape(&self.raw_dim(), &src.raw_dim(), axis, indices.len());
+        for (sub, &index) in src.axis_iter(axis).zip(&indices) {
+            self.index_axis_mut(axis, index)
+                .zip_mut_with(&sub, |x, y| *x = x.clone() + y.clone());
+        }
+    }
+
+    /// Return the sums of the segments of subviews along `axis`.
+    ///
+    /// `segment_ids` gives the segment of each subview along `axis`; it must
+    /// be sorted. Subview `s` of the result, along `axis`, is the sum of the
+    /// subviews of segment `s`, and it is zero if the segment is empty. The
+    /// result has one subview for each segment up to the last id, so a large
+    /// id allocates a large result, even for a few subviews.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `segment_ids`
+    /// is not the length of the axis, if it is not sorted, or if the result
+    /// would have more than `isize::MAX` elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6], [7, 8]];
+    /// assert_eq!(a.segment_sum(Axis(0), &[0, 0, 2, 2]), array![[4, 6], [0, 0], [12, 14]]);
+    /// assert_eq!(a.segment_sum(Axis(1), &[0, 0]), array![[3], [7], [11], [15]]);
+    /// ```
+    pub fn segment_sum(&self, axis: Axis, segment_ids: &[usize]) -> Array<A, D>
+    where
+        A: Clone + Zero,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let bounds = segment_bounds(segment_ids, axis, &self.raw_dim());
+        let mut out: Array<A, D> = Array::zeros(segment_dim(&self.raw_dim(), axis, &bounds));
+        for (sub, &id) in self.axis_iter(axis).zip(segment_ids) {
+            out.index_axis_mut(axis, id)
+                .zip_mut_with(&sub, |x, y| *x = x.clone() + y.clone());
+        }
+        out
+    }
+
+    /// Return the maxima of the segments of subviews along `axis`.
+    ///
+    /// This is like [`.segment_sum()`](Self::segment_sum), but the elements
+    /// of an empty segment are `A::min_value()`. Elements which are not
+    /// comparable, like `NaN`, are skipped, unless all the elements of the
+    /// segment are.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `segment_ids`
+    /// is not the length of the axis, if it is not sorted, or if the result
+    /// would have more than `isize::MAX` elements.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 8], [3, 4], [5, 6], [7, 2]];
+    /// let max = a.segment_max(Axis(0), &[0, 0, 2, 2]);
+    /// assert_eq!(max, array![[3, 8], [i32::MIN, i32::MIN], [7, 6]]);
+    /// ```
+    pub fn segment_max(&self, axis: Axis, segment_ids: &[usize]) -> Array<A, D>
+    where
+        A: Clone + PartialOrd + Bounded,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let bounds = segment_bounds(segment_ids, axis, &self.raw_dim());
+        let dim = segment_dim(&self.raw_dim(), axis, &bounds);
+        let mut out = Array::from_elem(dim, A::min_value());
+        for (mut max, range) in out.axis_iter_mut(axis).zip(bounds.windows(2)) {
+            let segment = self.slice_axis(axis, Slice::from(range[0]..range[1]));
+            let mut segment = segment.axis_iter(axis);
+            if let Some(first) = segment.next() {
+                max.assign(&first);
+                for sub in segment {
+                    max.zip_mut_with(&sub, max_assign);
+                }
+            }
+        }
+        out
+    }
+}
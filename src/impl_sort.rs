@@ -0,0 +1,198 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Sort `perm`, a sequence of positions in `lane`, by the elements at them.
+fn sort_positions<A, F>(perm: &mut [usize], lane: &ArrayView1<'_, A>, stable: bool, mut compare: F)
+where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    let by = |&i: &usize, &j: &usize| compare(&lane[i], &lane[j]);
+    if stable {
+        perm.sort_by(by);
+    } else {
+        perm.sort_unstable_by(by);
+    }
+}
+
+/// Sort a lane in place, using the slice sort if it is contiguous and
+/// otherwise sorting its positions and permuting it with swaps.
+pub(crate) fn sort_lane<A, F>(mut lane: ArrayViewMut1<'_, A>, stable: bool, mut compare: F)
+where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    if let Some(slice) = lane.as_slice_mut() {
+        if stable {
+            slice.sort_by(compare);
+        } else {
+            slice.sort_unstable_by(compare);
+        }
+        return;
+    }
+    let mut perm: Vec<usize> = (0..lane.len()).collect();
+    sort_positions(&mut perm, &lane.view(), stable, &mut compare);
+    // move the element at perm[j] to j, following each cycle of the
+    // permutation and marking the visited positions as fixed points
+    for i in 0..perm.len() {
+        let mut j = i;
+        loop {
+            let k = perm[j];
+            perm[j] = j;
+            if k == i {
+                break;
+            }
+            lane.swap(j, k);
+            j = k;
+        }
+    }
+}
+
+/// Write the positions of the sorted elements of `lane` into `out`.
+pub(crate) fn argsort_lane<A, F>(out: ArrayViewMut1<'_, usize>, lane: ArrayView1<'_, A>, compare: F)
+where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    let mut perm: Vec<usize> = (0..lane.len()).collect();
+    sort_positions(&mut perm, &lane, true, compare);
+    for (x, k) in out.into_iter().zip(perm) {
+        *x = k;
+    }
+}
+
+/// # Sorting Methods
+///
+/// These methods sort each lane along an axis independently, in place even
+/// when the lanes are not contiguous.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Sort each lane along `axis` in ascending order.
+    ///
+    /// The sort is stable: equal elements keep their order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[3, 1, 2], [5, 6, 4]];
+    /// a.sort_axis(Axis(1));
+    /// assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    /// a.sort_axis(Axis(0));
+    /// assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn sort_axis(&mut self, axis: Axis)
+    where
+        A: Ord,
+        S: DataMut,
+    {
+        self.sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`.
+    ///
+    /// The sort is stable: equal elements keep their order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[0.5, -1., 2.], [3., 0., -2.]];
+    /// a.sort_axis_by(Axis(0), |x, y| y.partial_cmp(x).unwrap());
+    /// assert_eq!(a, array![[3., 0., 2.], [0.5, -1., -2.]]);
+    /// ```
+    pub fn sort_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        S: DataMut,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        Zip::from(self.lanes_mut(axis)).for_each(|lane| sort_lane(lane, true, &mut compare));
+    }
+
+    /// Sort each lane along `axis` in ascending order, without preserving
+    /// the order of equal elements.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_unstable(&mut self, axis: Axis)
+    where
+        A: Ord,
+        S: DataMut,
+    {
+        self.sort_axis_unstable_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` with the comparison function `compare`,
+    /// without preserving the order of equal elements.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sort_axis_unstable_by<F>(&mut self, axis: Axis, mut compare: F)
+    where
+        S: DataMut,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        Zip::from(self.lanes_mut(axis)).for_each(|lane| sort_lane(lane, false, &mut compare));
+    }
+
+    /// Return the positions along `axis` which sort each lane in ascending
+    /// order, like NumPy’s `argsort`.
+    ///
+    /// The sort is stable, so equal elements keep their order. The result
+    /// has the shape of the array and orders it with
+    /// [`.take_along_axis()`](Self::take_along_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[10, 30, 20], [60, 40, 50]];
+    /// let order = a.argsort_axis(Axis(1));
+    /// assert_eq!(order, array![[0, 2, 1], [1, 2, 0]]);
+    /// assert_eq!(a.take_along_axis(&order, Axis(1)), array![[10, 20, 30], [40, 50, 60]]);
+    /// ```
+    pub fn argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where
+        A: Ord,
+        S: Data,
+    {
+        self.argsort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Return the positions along `axis` which sort each lane with the
+    /// comparison function `compare`.
+    ///
+    /// The sort is stable, so equal elements keep their order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![2.5, -1., 0.];
+    /// assert_eq!(a.argsort_axis_by(Axis(0), |x, y| x.partial_cmp(y).unwrap()), array![1, 2, 0]);
+    /// ```
+    pub fn argsort_axis_by<F>(&self, axis: Axis, mut compare: F) -> Array<usize, D>
+    where
+        S: Data,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut out = Array::zeros(self.raw_dim());
+        Zip::from(out.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|out, lane| argsort_lane(out, lane, &mut compare));
+        out
+    }
+}
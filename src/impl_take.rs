@@ -0,0 +1,292 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::dimension::broadcast::co_broadcast;
+use crate::imp_prelude::*;
+use crate::{IndexMode, IntoDimension, TakeIndex};
+
+#[cold]
+#[inline(never)]
+fn take_index_error(index: &dyn fmt::Display, axis: usize, len: usize) -> ! {
+    panic!(
+        "ndarray: index {} is out of bounds for axis {} with length {}",
+        index, axis, len
+    );
+}
+
+/// Return `index` resolved into an axis of length `len`.
+///
+/// **Panics** if it is out of bounds in `mode`.
+fn resolve<I: TakeIndex>(index: I, axis: usize, len: usize, mode: IndexMode) -> usize {
+    match index.resolve(len, mode) {
+        Some(i) => i,
+        None => take_index_error(&index, axis, len),
+    }
+}
+
+pub(crate) fn resolve_all<I>(indices: &[I], axis: usize, len: usize, mode: IndexMode) -> Vec<usize>
+where
+    I: TakeIndex,
+{
+    indices.iter().map(|&i| resolve(i, axis, len, mode)).collect()
+}
+
+/// Return the shape of the result of `.take_along_axis()`: the shapes of
+/// the array and the indices broadcast together, except along `axis`, where
+/// it is the length of the indices.
+fn along_axis_dim<D: Dimension>(dim: &D, indices_dim: &D, axis: Axis) -> D {
+    let mut dim = dim.clone();
+    let mut indices_dim = indices_dim.clone();
+    let len = indices_dim[axis.index()];
+    dim[axis.index()] = 1;
+    indices_dim[axis.index()] = 1;
+    let mut out: D = co_broadcast(&dim, &indices_dim).unwrap_or_else(|_| {
+        panic!(
+            "ndarray: could not broadcast indices of shape {:?} with array of shape {:?} \
+             along axis {}",
+            indices_dim.slice(),
+            dim.slice(),
+            axis.index()
+        )
+    });
+    out[axis.index()] = len;
+    out
+}
+
+/// # Fancy Indexing Methods
+///
+/// These methods gather and scatter elements with arrays of indices, like
+/// integer array indexing in NumPy. The indices are primitive integers, see
+/// [`TakeIndex`]; negative indices count from the end of the axis.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Along `axis`, select the subviews at `indices` and copy them into a
+    /// new array.
+    ///
+    /// This is [`.select()`](Self::select) with negative indices and the
+    /// handling of out of bounds indices given by `mode`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if an index is out of bounds
+    /// in `mode`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, IndexMode};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(a.take(&[-1, 0], Axis(0), IndexMode::Raise), array![[5, 6], [1, 2]]);
+    /// assert_eq!(a.take(&[3, -1], Axis(0), IndexMode::Wrap), array![[1, 2], [5, 6]]);
+    /// assert_eq!(a.take(&[5, 0], Axis(1), IndexMode::Clip), array![[2, 1], [4, 3], [6, 5]]);
+    /// ```
+    pub fn take<I>(&self, indices: &[I], axis: Axis, mode: IndexMode) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        D: RemoveAxis,
+        I: TakeIndex,
+    {
+        let indices = resolve_all(indices, axis.index(), self.len_of(axis), mode);
+        self.select(axis, &indices)
+    }
+
+    /// Along `axis`, assign the subviews of `values` to the subviews at
+    /// `indices`, in order, so that the last one wins for repeated indices.
+    ///
+    /// This is the inverse of [`.take()`](Self::take): `values` is broadcast
+    /// to the shape that `.take()` returns for `indices`.
+    ///
+    /// **Panics** if `axis` is out of bounds, if an index is out of bounds in
+    /// `mode`, or if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, IndexMode};
+    ///
+    /// let mut a = array![[1, 2], [3, 4], [5, 6]];
+    /// a.put(&[0, -1], &array![[0, 0], [9, 9]], Axis(0), IndexMode::Raise);
+    /// assert_eq!(a, array![[0, 0], [3, 4], [9, 9]]);
+    /// a.put(&[1], &array![7], Axis(1), IndexMode::Raise);
+    /// assert_eq!(a, array![[0, 7], [3, 7], [9, 7]]);
+    /// ```
+    pub fn put<I, S2, E>(
+        &mut self,
+        indices: &[I],
+        values: &ArrayBase<S2, E>,
+        axis: Axis,
+        mode: IndexMode,
+    ) where
+        A: Clone,
+        S: DataMut,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+        E: Dimension,
+        I: TakeIndex,
+    {
+        let indices = resolve_all(indices, axis.index(), self.len_of(axis), mode);
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, indices.len());
+        let values = values.broadcast_unwrap(dim);
+        for (k, &index) in indices.iter().enumerate() {
+            self.index_axis_mut(axis, index)
+                .assign(&values.index_axis(axis, k));
+        }
+    }
+
+    /// Gather the elements at `indices` along `axis`, for each position
+    /// along the other axes.
+    ///
+    /// The result `r` is such that, for a two-dimensional array and `axis`
+    /// one, `r[[i, j]] = a[[i, indices[[i, j]]]]`. The shapes of the array and
+    /// of `indices` are broadcast together, except along `axis`. This is the
+    /// counterpart of NumPy’s `take_along_axis`; for example, it orders an
+    /// array with the indices of an argsort.
+    ///
+    /// **Panics** if `axis` is out of bounds, if an index is out of bounds, or
+    /// if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[10, 30, 20], [60, 40, 50]];
+    /// let order = array![[0, 2, 1], [1, 2, 0]];
+    /// assert_eq!(a.take_along_axis(&order, Axis(1)), array![[10, 20, 30], [40, 50, 60]]);
+    ///
+    /// // the last element of each row
+    /// assert_eq!(a.take_along_axis(&array![[-1]], Axis(1)), array![[20], [50]]);
+    /// ```
+    pub fn take_along_axis<I, S2>(&self, indices: &ArrayBase<S2, D>, axis: Axis) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = I>,
+        I: TakeIndex,
+    {
+        let out_dim = along_axis_dim(&self.raw_dim(), &indices.raw_dim(), axis);
+        let mut source_dim = out_dim.clone();
+        source_dim[axis.index()] = self.len_of(axis);
+        let source = self.broadcast_unwrap(source_dim);
+        let indices = indices.broadcast_unwrap(out_dim.clone());
+        let len = self.len_of(axis);
+        Array::from_shape_fn(out_dim, |index| {
+            let mut index = index.into_dimension();
+            let i = resolve(indices[index.clone()], axis.index(), len, IndexMode::Raise);
+            index[axis.index()] = i;
+            source[index].clone()
+        })
+    }
+
+    /// Scatter `values` to the elements at `indices` along `axis`, for each
+    /// position along the other axes, in logical order, so that the last one
+    /// wins for repeated indices.
+    ///
+    /// This is the inverse of [`.take_along_axis()`](Self::take_along_axis):
+    /// for a two-dimensional array and `axis` one, it assigns
+    /// `a[[i, indices[[i, j]]]] = values[[i, j]]`. `indices` is broadcast to
+    /// the shape of the array, except along `axis`, and `values` to the shape
+    /// of the broadcast indices.
+    ///
+    /// **Panics** if `axis` is out of bounds, if an index is out of bounds, or
+    /// if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// // zero the largest element of each row
+    /// a.put_along_axis(&array![[2], [2]], &array![0], Axis(1));
+    /// assert_eq!(a, array![[1, 2, 0], [4, 5, 0]]);
+    /// ```
+    pub fn put_along_axis<I, S2, S3, E>(
+        &mut self,
+        indices: &ArrayBase<S2, D>,
+        values: &ArrayBase<S3, E>,
+        axis: Axis,
+    ) where
+        A: Clone,
+        S: DataMut,
+        S2: Data<Elem = I>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+        I: TakeIndex,
+    {
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = indices.len_of(axis);
+        let indices = indices.broadcast_unwrap(dim.clone());
+        let values = values.broadcast_unwrap(dim);
+        let len = self.len_of(axis);
+        for (index, &i) in indices.indexed_iter() {
+            let index = index.into_dimension();
+            let value = values[index.clone()].clone();
+            let mut target = index;
+            target[axis.index()] = resolve(i, axis.index(), len, IndexMode::Raise);
+            self[target] = value;
+        }
+    }
+
+    /// Return the outer product of indexing each axis with its indices, like
+    /// indexing with `np.ix_` in NumPy.
+    ///
+    /// Axis `k` is indexed with `indices[k]`, or kept whole if it is `None`;
+    /// the axes after those of `indices` are kept whole. The result has the
+    /// length of `indices[k]` along axis `k`.
+    ///
+    /// **Panics** if `indices` is longer than the number of axes, or if an
+    /// index is out of bounds in `mode`.
+    ///
+    /// ```
+    /// use ndarray::{array, IndexMode};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// let corners = a.take_outer(&[Some(vec![0, -1]), Some(vec![0, -1])], IndexMode::Raise);
+    /// assert_eq!(corners, array![[1, 3], [7, 9]]);
+    /// let rows = a.take_outer(&[Some(vec![2, 2]), None], IndexMode::Raise);
+    /// assert_eq!(rows, array![[7, 8, 9], [7, 8, 9]]);
+    /// ```
+    pub fn take_outer<I, T>(&self, indices: &[Option<T>], mode: IndexMode) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+        I: TakeIndex,
+        T: AsRef<[I]>,
+    {
+        if indices.len() > self.ndim() {
+            panic!(
+                "ndarray: {} index lists are too many for an array with {} axes",
+                indices.len(),
+                self.ndim()
+            );
+        }
+        let lists: Vec<Option<Vec<usize>>> = indices
+            .iter()
+            .enumerate()
+            .map(|(axis, list)| {
+                list.as_ref()
+                    .map(|list| resolve_all(list.as_ref(), axis, self.len_of(Axis(axis)), mode))
+            })
+            .collect();
+        let mut dim = self.raw_dim();
+        for (axis, list) in lists.iter().enumerate() {
+            if let Some(list) = list {
+                dim[axis] = list.len();
+            }
+        }
+        Array::from_shape_fn(dim, |index| {
+            let mut index = index.into_dimension();
+            for (axis, list) in lists.iter().enumerate() {
+                if let Some(list) = list {
+                    index[axis] = list[index[axis]];
+                }
+            }
+            self[index].clone()
+        })
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::broadcast::co_broadcast;
+use crate::imp_prelude::*;
+use crate::{DimMax, Zip};
+
+/// The dimension of the result of [`where_`].
+type WhereDim<D1, D2, D3> = <<D1 as DimMax<D2>>::Output as DimMax<D3>>::Output;
+
+/// Return an array with the elements of `x` where `cond` is `true`, and
+/// those of `y` elsewhere, like NumPy’s `where`.
+///
+/// The three arrays are broadcast to their common shape, with the same rules
+/// as the arithmetic operators; use a zero-dimensional array, like
+/// [`aview0`](crate::aview0), for a scalar.
+///
+/// **Panics** if broadcasting isn’t possible.
+///
+/// ```
+/// use ndarray::{array, aview0, where_};
+///
+/// let a = array![[1, -2, 3], [-4, 5, -6]];
+/// let positive = a.mapv(|x| x > 0);
+/// assert_eq!(where_(&positive, &a, &aview0(&0)), array![[1, 0, 3], [0, 5, 0]]);
+///
+/// // a condition on the rows, and a value for each column
+/// let rows = array![[true], [false]];
+/// assert_eq!(where_(&rows, &a, &array![7, 8, 9]), array![[1, -2, 3], [7, 8, 9]]);
+/// ```
+pub fn where_<A, S1, S2, S3, D1, D2, D3>(
+    cond: &ArrayBase<S1, D1>,
+    x: &ArrayBase<S2, D2>,
+    y: &ArrayBase<S3, D3>,
+) -> Array<A, WhereDim<D1, D2, D3>>
+where
+    A: Clone,
+    S1: Data<Elem = bool>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D1: Dimension + DimMax<D2>,
+    D2: Dimension,
+    D3: Dimension,
+    <D1 as DimMax<D2>>::Output: DimMax<D3>,
+{
+    let shape = co_broadcast::<D1, D2, <D1 as DimMax<D2>>::Output>(&cond.dim, &x.dim)
+        .and_then(|shape| co_broadcast::<_, D3, WhereDim<D1, D2, D3>>(&shape, &y.dim))
+        .unwrap_or_else(|_| {
+            panic!(
+                "ndarray: could not broadcast condition of shape {:?} with arrays of shapes \
+                 {:?} and {:?}",
+                cond.shape(),
+                x.shape(),
+                y.shape()
+            )
+        });
+    Zip::from(&cond.broadcast_unwrap(shape.clone()))
+        .and(&x.broadcast_unwrap(shape.clone()))
+        .and(&y.broadcast_unwrap(shape))
+        .map_collect(|&c, x, y| if c { x.clone() } else { y.clone() })
+}
+
+/// # Conditional Selection Methods
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Limit the values of each element to the interval between the
+    /// corresponding elements of `min` and `max`.
+    ///
+    /// The elements below `min` are replaced with it, and then those above
+    /// `max`, so the result is `max` where `min > max`, like NumPy’s `clip`.
+    /// The bounds are broadcast to the shape of the array, and elements which
+    /// are not comparable with a bound, like `NaN`, are left unchanged. For
+    /// scalar bounds of floats, see [`.clip()`](Self::clip).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{array, aview0};
+    ///
+    /// let a = array![[1, 5, 9], [-3, 4, 12]];
+    /// let clipped = a.clip_broadcast(&array![[0], [5]], &aview0(&8));
+    /// assert_eq!(clipped, array![[1, 5, 8], [5, 5, 8]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn clip_broadcast<S2, S3, E1, E2>(
+        &self,
+        min: &ArrayBase<S2, E1>,
+        max: &ArrayBase<S3, E2>,
+    ) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+        E1: Dimension,
+        E2: Dimension,
+    {
+        let min = min.broadcast_unwrap(self.raw_dim());
+        let max = max.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&min).and(&max).map_collect(|x, min, max| {
+            let x = if x < min { min } else { x };
+            if x > max { max.clone() } else { x.clone() }
+        })
+    }
+}
@@ -12,7 +12,7 @@ use crate::split_at::SplitAt;
 use crate::Axis;
 use crate::Layout;
 use crate::NdProducer;
-use crate::{ArrayBase, Data};
+use crate::{Array1, Array2, ArrayBase, Data, Ix1, Ix2, Order};
 
 /// An iterator over the indexes of an array shape.
 ///
@@ -50,6 +50,170 @@ where
     indices(array.dim())
 }
 
+/// Return the strides of the elements of `dim` in the given order, checking
+/// that its size doesn’t overflow.
+fn order_strides<D: Dimension>(dim: &D, order: Order) -> D {
+    if dim.size_checked().is_none() {
+        panic!("ndarray: the size of shape {:?} overflows usize", dim.slice());
+    }
+    if order.is_row_major() {
+        dim.default_strides()
+    } else {
+        dim.fortran_strides()
+    }
+}
+
+fn unravel<D: Dimension>(flat: usize, dim: &D, strides: &D) -> D {
+    if flat >= dim.size() {
+        panic!(
+            "ndarray: flat index {} is out of bounds for shape {:?}",
+            flat,
+            dim.slice()
+        );
+    }
+    let mut index = D::zeros(dim.ndim());
+    let axes = index.slice_mut().iter_mut().zip(dim.slice()).zip(strides.slice());
+    for ((ix, &len), &stride) in axes {
+        *ix = flat / stride % len;
+    }
+    index
+}
+
+fn ravel<D, I>(index: I, dim: &D, strides: &D) -> usize
+where
+    D: Dimension,
+    I: ExactSizeIterator<Item = usize>,
+{
+    if index.len() != dim.ndim() {
+        panic!(
+            "ndarray: index of {} axes does not match shape {:?}",
+            index.len(),
+            dim.slice()
+        );
+    }
+    let mut flat = 0;
+    for (axis, ((ix, &len), &stride)) in index.zip(dim.slice()).zip(strides.slice()).enumerate() {
+        if ix >= len {
+            panic!(
+                "ndarray: index {} is out of bounds for axis {} with length {}",
+                ix, axis, len
+            );
+        }
+        flat += ix * stride;
+    }
+    flat
+}
+
+/// Return the index of the element at position `flat` in the elements of
+/// `shape` in the given order, like NumPy’s `unravel_index`.
+///
+/// This is the inverse of [`ravel_multi_index`].
+///
+/// **Panics** if `flat` is not less than the number of elements of `shape`,
+/// or if that number overflows `usize`.
+///
+/// ```
+/// use ndarray::{unravel_index, Order};
+///
+/// assert_eq!(unravel_index(7, (3, 4), Order::RowMajor), (1, 3));
+/// assert_eq!(unravel_index(7, (3, 4), Order::ColumnMajor), (1, 2));
+/// ```
+pub fn unravel_index<E>(flat: usize, shape: E, order: Order) -> <E::Dim as Dimension>::Pattern
+where
+    E: IntoDimension,
+{
+    let dim = shape.into_dimension();
+    let strides = order_strides(&dim, order);
+    unravel(flat, &dim, &strides).into_pattern()
+}
+
+/// Return the position of the element at `index` in the elements of `shape`
+/// in the given order, like NumPy’s `ravel_multi_index`.
+///
+/// This is the inverse of [`unravel_index`].
+///
+/// **Panics** if `index` is out of bounds for `shape`, or if the number of
+/// elements of `shape` overflows `usize`.
+///
+/// ```
+/// use ndarray::{ravel_multi_index, Order};
+///
+/// assert_eq!(ravel_multi_index((1, 3), (3, 4), Order::RowMajor), 7);
+/// assert_eq!(ravel_multi_index((1, 3), (3, 4), Order::ColumnMajor), 10);
+/// ```
+pub fn ravel_multi_index<I, E>(index: I, shape: E, order: Order) -> usize
+where
+    I: IntoDimension<Dim = E::Dim>,
+    E: IntoDimension,
+{
+    let dim = shape.into_dimension();
+    let strides = order_strides(&dim, order);
+    ravel(index.into_dimension().slice().iter().cloned(), &dim, &strides)
+}
+
+/// Return the indices of the elements at the positions `flat` in the
+/// elements of `shape` in the given order, as the rows of an array of shape
+/// `(flat.len(), ndim)`.
+///
+/// This is [`unravel_index`] for each element of `flat`, and the inverse of
+/// [`ravel_multi_index_array`].
+///
+/// **Panics** if an element of `flat` is not less than the number of
+/// elements of `shape`, or if that number overflows `usize`.
+///
+/// ```
+/// use ndarray::{array, unravel_index_array, Order};
+///
+/// let index = unravel_index_array(&array![0, 7, 11], (3, 4), Order::RowMajor);
+/// assert_eq!(index, array![[0, 0], [1, 3], [2, 3]]);
+/// ```
+pub fn unravel_index_array<S, E>(flat: &ArrayBase<S, Ix1>, shape: E, order: Order) -> Array2<usize>
+where
+    S: Data<Elem = usize>,
+    E: IntoDimension,
+{
+    let dim = shape.into_dimension();
+    let strides = order_strides(&dim, order);
+    let mut out = Array2::zeros((flat.len(), dim.ndim()));
+    for (mut row, &flat) in out.rows_mut().into_iter().zip(flat) {
+        let index = unravel(flat, &dim, &strides);
+        for (x, &ix) in row.iter_mut().zip(index.slice()) {
+            *x = ix;
+        }
+    }
+    out
+}
+
+/// Return the positions of the elements at the indices in the rows of
+/// `index` in the elements of `shape` in the given order.
+///
+/// This is [`ravel_multi_index`] for each row of `index`, and the inverse of
+/// [`unravel_index_array`].
+///
+/// **Panics** if a row of `index` is out of bounds for `shape`, or if the
+/// number of elements of `shape` overflows `usize`.
+///
+/// ```
+/// use ndarray::{array, ravel_multi_index_array, Order};
+///
+/// let index = array![[0, 0], [1, 3], [2, 3]];
+/// let flat = ravel_multi_index_array(&index, (3, 4), Order::ColumnMajor);
+/// assert_eq!(flat, array![0, 10, 11]);
+/// ```
+pub fn ravel_multi_index_array<S, E>(
+    index: &ArrayBase<S, Ix2>,
+    shape: E,
+    order: Order,
+) -> Array1<usize>
+where
+    S: Data<Elem = usize>,
+    E: IntoDimension,
+{
+    let dim = shape.into_dimension();
+    let strides = order_strides(&dim, order);
+    index.rows().into_iter().map(|row| ravel(row.iter().cloned(), &dim, &strides)).collect()
+}
+
 impl<D> Iterator for IndicesIter<D>
 where
     D: Dimension,
@@ -60,7 +60,10 @@ impl<'a, A, D: Dimension> ExactChunks<'a, A, D> {
             a.dim[i] /= chunk[i];
         }
         let inner_strides = a.raw_strides();
-        a.strides *= &chunk;
+        for (stride, &c) in a.strides.slice_mut().iter_mut().zip(chunk.slice()) {
+            // strides are stored as usize, but may be negative
+            *stride = (*stride as isize * c as isize) as usize;
+        }
 
         ExactChunks {
             base: a,
@@ -150,7 +153,10 @@ impl<'a, A, D: Dimension> ExactChunksMut<'a, A, D> {
             a.dim[i] /= chunk[i];
         }
         let inner_strides = a.raw_strides();
-        a.strides *= &chunk;
+        for (stride, &c) in a.strides.slice_mut().iter_mut().zip(chunk.slice()) {
+            // strides are stored as usize, but may be negative
+            *stride = (*stride as isize * c as isize) as usize;
+        }
 
         ExactChunksMut {
             base: a,
@@ -130,16 +130,20 @@ use alloc::sync::Arc;
 
 pub use crate::dimension::dim::*;
 pub use crate::dimension::{Axis, AxisDescription, Dimension, IntoDimension, RemoveAxis};
-pub use crate::dimension::{DimAdd, DimMax};
+pub use crate::dimension::{DimAdd, DimMax, DimSub};
 
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
-pub use crate::error::{ErrorKind, ShapeError};
+pub use crate::error::{ErrorKind, IndexOutOfBounds, ShapeError};
 pub use crate::indexes::{indices, indices_of};
+pub use crate::indexes::{ravel_multi_index, ravel_multi_index_array};
+pub use crate::indexes::{unravel_index, unravel_index_array};
 pub use crate::order::Order;
 pub use crate::slice::{
-    MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim,
+    EllipsisSliceInfo, MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem,
+    SliceNextDim,
 };
+pub use crate::slice_parse::{parse_slice, ParseSliceError};
 
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut};
@@ -148,10 +152,16 @@ pub use crate::arraytraits::AsArray;
 #[cfg(feature = "std")]
 pub use crate::linalg_traits::NdFloat;
 pub use crate::linalg_traits::LinalgScalar;
+#[cfg(feature = "std")]
+pub use crate::numeric::{Norm, RunningStats};
 
 #[allow(deprecated)] // stack_new_axis
 pub use crate::stacking::{concatenate, stack, stack_new_axis};
 
+pub use crate::linalg::{einsum, tensordot};
+
+pub use crate::impl_where::where_;
+
 pub use crate::math_cell::MathCell;
 pub use crate::impl_views::IndexLonger;
 pub use crate::shape_builder::{Shape, ShapeBuilder, ShapeArg, StrideShape};
@@ -168,7 +178,7 @@ mod argument_traits;
 mod array_serde;
 mod arrayformat;
 mod arraytraits;
-pub use crate::argument_traits::AssignElem;
+pub use crate::argument_traits::{AssignElem, IndexMode, MaskValues, TakeIndex};
 mod data_repr;
 mod data_traits;
 
@@ -199,6 +209,7 @@ mod partial;
 mod shape_builder;
 #[macro_use]
 mod slice;
+mod slice_parse;
 mod split_at;
 mod stacking;
 mod low_level_util;
@@ -1576,6 +1587,12 @@ mod impl_constructors;
 mod impl_methods;
 mod impl_owned_array;
 mod impl_special_element_types;
+mod impl_complex;
+mod impl_mask;
+mod impl_take;
+mod impl_scatter;
+mod impl_where;
+mod impl_sort;
 mod impl_wgpu_array;
 
 /// Private Methods
@@ -1653,6 +1670,8 @@ mod numeric;
 
 pub mod linalg;
 
+pub mod sparse;
+
 mod impl_ops;
 mod impl_wgpu_ops;
 pub use crate::impl_ops::ScalarOperand;
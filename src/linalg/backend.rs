@@ -0,0 +1,291 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Runtime selection of the matrix multiplication backend.
+
+use core::fmt;
+use num_complex::Complex;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+
+#[cfg(feature = "std")]
+use parking_lot::RwLock;
+
+use crate::imp_prelude::*;
+
+/// A matrix multiplication `C ← α A B + β C`, with arguments
+/// `(α, A, B, β, C)`.
+///
+/// When `β` is zero, the elements of `C` are zero on entry.
+pub type GemmFn<A> = fn(A, ArrayView2<'_, A>, ArrayView2<'_, A>, A, ArrayViewMut2<'_, A>);
+
+/// User provided matrix multiplication functions, by element type.
+///
+/// The multiplications of an element type without a function use the
+/// [`Native`](GemmBackend::Native) backend.
+///
+/// ```
+/// use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+/// use ndarray::linalg::{general_mat_mul, gemm_stats, reset_gemm_stats, with_gemm_backend};
+/// use ndarray::linalg::{CustomGemm, GemmBackend, GemmFallback};
+///
+/// // a backend for f64, which delegates to the native one
+/// fn gemm(
+///     alpha: f64,
+///     a: ArrayView2<f64>,
+///     b: ArrayView2<f64>,
+///     beta: f64,
+///     mut c: ArrayViewMut2<f64>,
+/// ) {
+///     with_gemm_backend(GemmBackend::Native, || general_mat_mul(alpha, &a, &b, beta, &mut c));
+/// }
+///
+/// let custom = GemmBackend::Custom(CustomGemm { f64: Some(gemm), ..Default::default() });
+/// reset_gemm_stats();
+/// with_gemm_backend(custom, || {
+///     let a = Array2::<f64>::eye(4);
+///     assert_eq!(a.dot(&a), a);
+///     let b = Array2::<f32>::eye(4);
+///     assert_eq!(b.dot(&b), b);
+/// });
+/// let stats = gemm_stats();
+/// // the f64 product, which is also counted by the native backend, and the f32 product
+/// assert_eq!((stats.custom, stats.native), (1, 2));
+/// assert_eq!(stats.fallbacks(GemmFallback::ElementType), 1);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct CustomGemm {
+    /// The function for `f32` elements.
+    pub f32: Option<GemmFn<f32>>,
+    /// The function for `f64` elements.
+    pub f64: Option<GemmFn<f64>>,
+    /// The function for `Complex<f32>` elements.
+    pub c32: Option<GemmFn<Complex<f32>>>,
+    /// The function for `Complex<f64>` elements.
+    pub c64: Option<GemmFn<Complex<f64>>>,
+}
+
+impl fmt::Debug for CustomGemm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomGemm")
+            .field("f32", &self.f32.is_some())
+            .field("f64", &self.f64.is_some())
+            .field("c32", &self.c32.is_some())
+            .field("c64", &self.c64.is_some())
+            .finish()
+    }
+}
+
+/// The implementation of the matrix-matrix and matrix-vector
+/// multiplications of [`.dot()`], [`general_mat_mul`](super::general_mat_mul)
+/// and [`general_mat_vec_mul`](super::general_mat_vec_mul).
+///
+/// The backend is selected at runtime with [`set_gemm_backend`] or, for the
+/// current thread, with [`with_gemm_backend`]. When the selected backend
+/// can't compute a multiplication, it falls back to `Native`, and
+/// [`gemm_stats`] records why. The dot products of two vectors don't use the
+/// backend.
+///
+/// [`.dot()`]: ArrayBase::dot
+#[derive(Clone, Copy, Debug)]
+pub enum GemmBackend {
+    /// The pure Rust implementation: `matrixmultiply` for `f32`, `f64` and
+    /// their complex numbers, and a simple loop for other element types.
+    Native,
+    /// cblas `gemm`, for large enough matrices of `f32`, `f64` and their
+    /// complex numbers with a compatible memory layout, and cblas `gemv` for
+    /// the matrix-vector multiplications of `f32` and `f64`. It needs crate
+    /// feature `blas`.
+    Blas,
+    /// User provided functions, which also compute the matrix-vector
+    /// multiplications with the vectors as matrices of one column.
+    Custom(CustomGemm),
+}
+
+impl Default for GemmBackend {
+    /// Return `Blas` if crate feature `blas` is enabled, and `Native`
+    /// otherwise.
+    fn default() -> Self {
+        if cfg!(feature = "blas") {
+            GemmBackend::Blas
+        } else {
+            GemmBackend::Native
+        }
+    }
+}
+
+/// The reason a matrix multiplication fell back to the
+/// [`Native`](GemmBackend::Native) backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GemmFallback {
+    /// The matrices are below the size cutoff of the backend.
+    Small,
+    /// The backend doesn't support the element type.
+    ElementType,
+    /// The backend doesn't support the memory layout of an operand, for
+    /// example non-contiguous strides.
+    Layout,
+    /// The backend isn't available in this build, like `Blas` without crate
+    /// feature `blas`.
+    Unavailable,
+}
+
+/// The path taken by a matrix multiplication.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum GemmPath {
+    Native,
+    Blas,
+    Custom,
+}
+
+/// Counters of the matrix-matrix and matrix-vector multiplications of the
+/// current thread, by the backend which computed them.
+///
+/// A multiplication which fell back is counted both in `native` and in
+/// [`.fallbacks()`](Self::fallbacks) of its reason.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GemmStats {
+    /// The number of multiplications computed by the native backend.
+    pub native: u64,
+    /// The number of multiplications computed by blas.
+    pub blas: u64,
+    /// The number of multiplications computed by a user provided function.
+    pub custom: u64,
+    fallbacks: [u64; 4],
+}
+
+impl GemmStats {
+    /// Return the number of multiplications which fell back to the native
+    /// backend for `reason`.
+    pub fn fallbacks(&self, reason: GemmFallback) -> u64 {
+        self.fallbacks[reason as usize]
+    }
+
+    /// Return the total number of multiplications.
+    pub fn total(&self) -> u64 {
+        self.native + self.blas + self.custom
+    }
+
+    #[cfg(feature = "std")]
+    fn record(&mut self, path: GemmPath, fallback: Option<GemmFallback>) {
+        match path {
+            GemmPath::Native => self.native += 1,
+            GemmPath::Blas => self.blas += 1,
+            GemmPath::Custom => self.custom += 1,
+        }
+        if let Some(reason) = fallback {
+            self.fallbacks[reason as usize] += 1;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+lazy_static::lazy_static! {
+    static ref GEMM_BACKEND: RwLock<GemmBackend> = RwLock::new(GemmBackend::default());
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static GEMM_OVERRIDE: Cell<Option<GemmBackend>> = Cell::new(None);
+    static GEMM_STATS: Cell<GemmStats> = Cell::new(GemmStats::default());
+}
+
+/// Select the matrix multiplication backend of all threads, except those
+/// which override it with [`with_gemm_backend`].
+///
+/// This function requires crate feature `std`.
+#[cfg(feature = "std")]
+pub fn set_gemm_backend(backend: GemmBackend) {
+    *GEMM_BACKEND.write() = backend;
+}
+
+/// Return the matrix multiplication backend of the current thread.
+///
+/// This function requires crate feature `std`.
+#[cfg(feature = "std")]
+pub fn gemm_backend() -> GemmBackend {
+    GEMM_OVERRIDE
+        .with(|backend| backend.get())
+        .unwrap_or_else(|| *GEMM_BACKEND.read())
+}
+
+/// Call `f` with `backend` as the matrix multiplication backend of the
+/// current thread, and return its result.
+///
+/// The override doesn't extend to the threads which `f` starts or uses, like
+/// those of the parallel methods.
+///
+/// This function requires crate feature `std`.
+///
+/// ```
+/// use ndarray::Array2;
+/// use ndarray::linalg::{gemm_stats, reset_gemm_stats, with_gemm_backend, GemmBackend};
+///
+/// let a = Array2::<f64>::eye(20);
+/// reset_gemm_stats();
+/// let b = with_gemm_backend(GemmBackend::Native, || a.dot(&a));
+/// assert_eq!(b, a);
+/// assert_eq!(gemm_stats().native, 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn with_gemm_backend<F, R>(backend: GemmBackend, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    /// Restore the previous override, also when `f` panics.
+    struct Restore(Option<GemmBackend>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            GEMM_OVERRIDE.with(|backend| backend.set(self.0));
+        }
+    }
+
+    let _restore = Restore(GEMM_OVERRIDE.with(|previous| previous.replace(Some(backend))));
+    f()
+}
+
+/// Return the counters of the matrix multiplications of the current thread.
+///
+/// This function requires crate feature `std`.
+#[cfg(feature = "std")]
+pub fn gemm_stats() -> GemmStats {
+    GEMM_STATS.with(|stats| stats.get())
+}
+
+/// Reset the counters of the matrix multiplications of the current thread.
+///
+/// This function requires crate feature `std`.
+#[cfg(feature = "std")]
+pub fn reset_gemm_stats() {
+    GEMM_STATS.with(|stats| stats.set(GemmStats::default()));
+}
+
+/// Return the backend for the next multiplication.
+#[cfg(feature = "std")]
+pub(crate) fn current_backend() -> GemmBackend {
+    gemm_backend()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn current_backend() -> GemmBackend {
+    GemmBackend::default()
+}
+
+/// Record the path taken by a multiplication.
+#[cfg(feature = "std")]
+pub(crate) fn record(path: GemmPath, fallback: Option<GemmFallback>) {
+    GEMM_STATS.with(|stats| {
+        let mut current = stats.get();
+        current.record(path, fallback);
+        stats.set(current);
+    });
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn record(_path: GemmPath, _fallback: Option<GemmFallback>) {}
@@ -0,0 +1,74 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, Zero};
+
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_vec_mul;
+
+use super::error::{check_square, LinalgError};
+use super::{Scalar, Triangle};
+
+/// # Cholesky Decomposition
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Compute the Cholesky decomposition of the Hermitian (symmetric, for
+    /// real numbers) positive definite matrix.
+    ///
+    /// With `Triangle::Lower`, return the lower triangular `L` such that
+    /// `A = L Lᴴ`, reading only the lower triangle of the matrix. With
+    /// `Triangle::Upper`, return the upper triangular `U` such that
+    /// `A = Uᴴ U`, reading only the upper triangle.
+    ///
+    /// **Errors** if the matrix is not square or not positive definite.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::Triangle;
+    ///
+    /// let a = array![[4., 2.], [2., 5.]];
+    /// let l = a.cholesky(Triangle::Lower).unwrap();
+    /// assert_eq!(l, array![[2., 0.], [1., 2.]]);
+    /// assert_eq!(a.cholesky(Triangle::Upper).unwrap(), l.t());
+    /// ```
+    pub fn cholesky(&self, uplo: Triangle) -> Result<Array2<A>, LinalgError> {
+        let n = check_square(self.dim())?;
+        // Factorize the lower triangle; the upper triangle of A is the
+        // conjugate transpose of the lower triangle of Aᴴ.
+        let mut l = match uplo {
+            Triangle::Lower => self.to_owned(),
+            Triangle::Upper => self.t().mapv(A::conj),
+        };
+        for j in 0..n {
+            // l[j.., j] ← a[j.., j] - L[j.., ..j] conj(L[j, ..j])
+            let row = l.slice(s![j, ..j]).mapv(A::conj);
+            let (left, mut right) = l.view_mut().split_at(Axis(1), j);
+            let mut column = right.slice_mut(s![j.., 0]);
+            general_mat_vec_mul(-A::one(), &left.slice(s![j.., ..]), &row, A::one(), &mut column);
+
+            let d = column[0].re();
+            if !d.is_finite() || d <= A::Real::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let d = d.sqrt();
+            column[0] = A::from_real(d);
+            let d = A::from_real(d);
+            column.slice_mut(s![1..]).mapv_inplace(|x| x / d);
+            right.slice_mut(s![..j, 0]).fill(A::zero());
+        }
+        Ok(match uplo {
+            Triangle::Lower => l,
+            Triangle::Upper => l.t().mapv(A::conj),
+        })
+    }
+}
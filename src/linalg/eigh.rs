@@ -0,0 +1,189 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::{Float, One, Zero};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+use super::error::{check_square, LinalgError};
+use super::{Scalar, Triangle};
+
+/// Maximum number of sweeps of the Jacobi methods before giving up.
+pub(crate) const MAX_SWEEPS: usize = 100;
+
+/// A Jacobi rotation `J` in the plane of the indices `p` and `q`, such that
+/// `Jᴴ M J` is diagonal for the Hermitian 2 × 2 matrix
+/// `M = [[app, g], [conj(g), aqq]]`.
+pub(crate) struct Rotation<A: Scalar> {
+    c: A::Real,
+    s: A::Real,
+    /// `g / |g|`
+    phase: A,
+    /// `tan` of the rotation angle
+    t: A::Real,
+}
+
+impl<A: Scalar> Rotation<A> {
+    /// Compute the rotation; `g` must be nonzero.
+    pub(crate) fn new(app: A::Real, aqq: A::Real, g: A) -> Self {
+        let g_abs = g.abs();
+        let phase = g / A::from_real(g_abs);
+        let theta = (aqq - app) / (g_abs + g_abs);
+        let t = theta.signum() / (theta.abs() + theta.hypot(A::Real::one()));
+        let c = t.hypot(A::Real::one()).recip();
+        Rotation { c, s: t * c, phase, t }
+    }
+
+    /// Replace columns `p` and `q` of `a` with those of `a J`.
+    pub(crate) fn apply_right(&self, a: &mut ArrayViewMut2<'_, A>, p: usize, q: usize) {
+        let (c, s, e) = (A::from_real(self.c), A::from_real(self.s), self.phase.conj());
+        let (ap, aq) = a.multi_slice_mut((s![.., p], s![.., q]));
+        Zip::from(ap).and(aq).for_each(|x, y| {
+            let (xp, yq) = (*x, *y * e);
+            *x = c * xp - s * yq;
+            *y = s * xp + c * yq;
+        });
+    }
+
+    /// Replace rows `p` and `q` of `a` with those of `Jᴴ a`.
+    fn apply_left(&self, a: &mut ArrayViewMut2<'_, A>, p: usize, q: usize) {
+        let (c, s, e) = (A::from_real(self.c), A::from_real(self.s), self.phase);
+        let (ap, aq) = a.multi_slice_mut((s![p, ..], s![q, ..]));
+        Zip::from(ap).and(aq).for_each(|x, y| {
+            let (xp, yq) = (*x, *y * e);
+            *x = c * xp - s * yq;
+            *y = s * xp + c * yq;
+        });
+    }
+}
+
+/// Return the permutation that sorts `values` with `cmp`.
+pub(crate) fn sort_indices<T, F>(values: &[T], mut cmp: F) -> Vec<usize>
+where
+    F: FnMut(&T, &T) -> Option<Ordering>,
+{
+    let mut indices: Vec<usize> = (0..values.len()).collect();
+    indices.sort_by(|&i, &j| cmp(&values[i], &values[j]).unwrap_or(Ordering::Equal));
+    indices
+}
+
+/// Eigenvalues and optional eigenvectors of a Hermitian matrix.
+type EighOutput<A> = (Array1<<A as Scalar>::Real>, Option<Array2<A>>);
+
+/// Diagonalize the Hermitian matrix `a` with the cyclic Jacobi method, and
+/// return its eigenvalues in ascending order and optionally its
+/// eigenvectors.
+fn jacobi_eigh<A: Scalar>(mut a: Array2<A>, vectors: bool) -> Result<EighOutput<A>, LinalgError> {
+    let n = a.nrows();
+    let mut v = if vectors { Some(Array2::eye(n)) } else { None };
+    let eps = A::Real::epsilon();
+    // Off-diagonal elements below this are negligible for any eigenvalue.
+    let floor = eps * eps * a.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let g = a[[p, q]];
+                let (app, aqq) = (a[[p, p]].re(), a[[q, q]].re());
+                let g_abs = g.abs();
+                if g.is_zero() || g_abs <= floor || g_abs <= eps * (app * aqq).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let rot = Rotation::new(app, aqq, g);
+                rot.apply_right(&mut a.view_mut(), p, q);
+                rot.apply_left(&mut a.view_mut(), p, q);
+                a[[p, p]] = A::from_real(app - rot.t * g_abs);
+                a[[q, q]] = A::from_real(aqq + rot.t * g_abs);
+                a[[p, q]] = A::zero();
+                a[[q, p]] = A::zero();
+                if let Some(v) = &mut v {
+                    rot.apply_right(&mut v.view_mut(), p, q);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence);
+    }
+
+    let w: Vec<A::Real> = a.diag().iter().map(|x| x.re()).collect();
+    let order = sort_indices(&w, |x, y| x.partial_cmp(y));
+    let w = order.iter().map(|&i| w[i]).collect();
+    Ok((w, v.map(|v| v.select(Axis(1), &order))))
+}
+
+/// # Hermitian Eigendecomposition
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Return the full Hermitian (symmetric, for real numbers) matrix whose
+    /// `uplo` triangle is that of `self`.
+    fn hermitian_from(&self, uplo: Triangle) -> Result<Array2<A>, LinalgError> {
+        let n = check_square(self.dim())?;
+        Ok(Array2::from_shape_fn((n, n), |(i, j)| match (i.cmp(&j), uplo) {
+            (Ordering::Equal, _) => A::from_real(self[[i, i]].re()),
+            (Ordering::Less, Triangle::Upper) | (Ordering::Greater, Triangle::Lower) => {
+                self[[i, j]]
+            }
+            _ => self[[j, i]].conj(),
+        }))
+    }
+
+    /// Compute the eigenvalues and eigenvectors of the Hermitian (symmetric,
+    /// for real numbers) matrix with the Jacobi eigenvalue algorithm.
+    ///
+    /// Only the `uplo` triangle of the matrix is read. Return the
+    /// eigenvalues `w` in ascending order and a unitary matrix `v` whose
+    /// columns are the corresponding eigenvectors, so that
+    /// `A = V diag(w) Vᴴ`.
+    ///
+    /// **Errors** if the matrix is not square, or if the iteration doesn't
+    /// converge, which can happen if the matrix has non-finite elements.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use ndarray::linalg::Triangle;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[2., 1.], [1., 2.]];
+    /// let (w, v) = a.eigh(Triangle::Lower).unwrap();
+    /// assert_abs_diff_eq!(w, array![1., 3.], epsilon = 1e-12);
+    /// assert_abs_diff_eq!(a.dot(&v), &v * &w, epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn eigh(&self, uplo: Triangle) -> Result<(Array1<A::Real>, Array2<A>), LinalgError> {
+        let (w, v) = jacobi_eigh(self.hermitian_from(uplo)?, true)?;
+        Ok((w, v.expect("eigenvectors were computed")))
+    }
+
+    /// Compute the eigenvalues of the Hermitian (symmetric, for real
+    /// numbers) matrix in ascending order, without the eigenvectors.
+    ///
+    /// Only the `uplo` triangle of the matrix is read; see
+    /// [`.eigh()`](Self::eigh).
+    ///
+    /// **Errors** if the matrix is not square, or if the iteration doesn't
+    /// converge.
+    pub fn eigvalsh(&self, uplo: Triangle) -> Result<Array1<A::Real>, LinalgError> {
+        Ok(jacobi_eigh(self.hermitian_from(uplo)?, false)?.0)
+    }
+}
@@ -0,0 +1,440 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_mul;
+use crate::{CowArray, LinalgScalar, Zip};
+
+use super::error::LinalgError;
+
+/// Labels of the axes covered by an ellipsis start here, above the labels
+/// of the ASCII letters.
+const ELLIPSIS: usize = 128;
+
+/// Parse one term of the subscripts into its letters and the position of
+/// the ellipsis among them, if any.
+fn parse_term(term: &str) -> Result<(Vec<u8>, Option<usize>), LinalgError> {
+    let invalid = |msg| Err(LinalgError::InvalidSubscripts(msg));
+    let mut letters = Vec::new();
+    let mut ellipsis = None;
+    let mut rest = term.trim();
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_alphabetic() {
+            letters.push(c as u8);
+            rest = &rest[1..];
+        } else if let Some(after) = rest.strip_prefix("...") {
+            if ellipsis.is_some() {
+                return invalid(format!("more than one ellipsis in {:?}", term));
+            }
+            ellipsis = Some(letters.len());
+            rest = after;
+        } else if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else {
+            return invalid(format!("invalid character {:?} in {:?}", c, term));
+        }
+    }
+    Ok((letters, ellipsis))
+}
+
+/// Return the labels of `letters` with the ellipsis at `ellipsis` covering
+/// the labels `ellipsis_labels`.
+fn expand_term(letters: &[u8], ellipsis: Option<usize>, ellipsis_labels: &[usize]) -> Vec<usize> {
+    let pos = ellipsis.unwrap_or(letters.len());
+    let mut labels: Vec<usize> = letters[..pos].iter().map(|&c| c as usize).collect();
+    labels.extend_from_slice(ellipsis_labels);
+    labels.extend(letters[pos..].iter().map(|&c| c as usize));
+    labels
+}
+
+/// Parse the subscripts, and return the labels of each axis of each input
+/// and of the output, with the axes covered by ellipses labelled from
+/// `ELLIPSIS` upwards (aligned to the right, like broadcasting).
+fn parse_subscripts(
+    subscripts: &str,
+    ndims: &[usize],
+) -> Result<(Vec<Vec<usize>>, Vec<usize>), LinalgError> {
+    let invalid = |msg| Err(LinalgError::InvalidSubscripts(msg));
+    let (inputs, output) = match subscripts.find("->") {
+        Some(i) => (&subscripts[..i], Some(&subscripts[i + 2..])),
+        None => (subscripts, None),
+    };
+    let terms = inputs
+        .split(',')
+        .map(parse_term)
+        .collect::<Result<Vec<_>, _>>()?;
+    if terms.len() != ndims.len() {
+        return invalid(format!("{} terms for {} operands", terms.len(), ndims.len()));
+    }
+
+    let mut ellipsis_ndims = Vec::with_capacity(terms.len());
+    for (i, ((letters, ellipsis), &ndim)) in terms.iter().zip(ndims).enumerate() {
+        match (ndim.checked_sub(letters.len()), ellipsis) {
+            (Some(n), Some(_)) => ellipsis_ndims.push(n),
+            (Some(0), None) => ellipsis_ndims.push(0),
+            _ => {
+                return invalid(format!(
+                    "operand {} has {} axes but {} labels",
+                    i, ndim, letters.len()
+                ))
+            }
+        }
+    }
+    let ellipsis_ndim = ellipsis_ndims.iter().cloned().max().unwrap_or(0);
+    let all_ellipsis: Vec<usize> = (ELLIPSIS..ELLIPSIS + ellipsis_ndim).collect();
+    let inputs: Vec<Vec<usize>> = terms
+        .iter()
+        .zip(&ellipsis_ndims)
+        .map(|((letters, ellipsis), &n)| {
+            expand_term(letters, *ellipsis, &all_ellipsis[ellipsis_ndim - n..])
+        })
+        .collect();
+
+    let output = match output {
+        Some(term) => {
+            let (letters, ellipsis) = parse_term(term)?;
+            let covered = if ellipsis.is_some() { &all_ellipsis[..] } else { &[] };
+            let output = expand_term(&letters, ellipsis, covered);
+            for (i, &label) in output.iter().enumerate() {
+                let c = label as u8 as char;
+                if output[..i].contains(&label) {
+                    return invalid(format!("repeated output label {:?}", c));
+                }
+                if !inputs.iter().any(|labels| labels.contains(&label)) {
+                    return invalid(format!("output label {:?} is not in the inputs", c));
+                }
+            }
+            output
+        }
+        None => {
+            // The ellipsis axes and then the letters that appear once, in
+            // alphabetical order.
+            let mut once: Vec<usize> = (0..ELLIPSIS)
+                .filter(|l| inputs.iter().flatten().filter(|&x| x == l).count() == 1)
+                .collect();
+            let mut output = all_ellipsis;
+            output.append(&mut once);
+            output
+        }
+    };
+    Ok((inputs, output))
+}
+
+/// Return a view of `view` with one axis per distinct label of `labels`, in
+/// order of first appearance; repeated labels select the diagonal of their
+/// axes, and axes of length one are broadcast to the size of their label.
+fn operand_view<'a, A>(
+    view: ArrayViewD<'a, A>,
+    labels: &[usize],
+    sizes: &[usize],
+) -> (ArrayViewD<'a, A>, Vec<usize>) {
+    let mut unique = Vec::with_capacity(labels.len());
+    let mut strides: Vec<isize> = Vec::with_capacity(labels.len());
+    for (axis, &label) in labels.iter().enumerate() {
+        let stride = if view.len_of(Axis(axis)) == 1 { 0 } else { view.strides()[axis] };
+        match unique.iter().position(|&l| l == label) {
+            Some(i) => strides[i] += stride,
+            None => {
+                unique.push(label);
+                strides.push(stride);
+            }
+        }
+    }
+    let dim: Vec<usize> = unique.iter().map(|&l| sizes[l]).collect();
+    let strides: Vec<usize> = strides.iter().map(|&s| s as usize).collect();
+    // Safe because every index of the new view maps to an index of `view`:
+    // axes with the same label have the same length, and the index of a
+    // broadcast axis is always zero.
+    let view = unsafe { ArrayView::new_(view.as_ptr(), IxDyn(&dim), IxDyn(&strides)) };
+    (view, unique)
+}
+
+/// Sum the operand over the axes whose labels are in `drop`.
+fn sum_labels<A: LinalgScalar>(op: &mut (CowArray<'_, A, IxDyn>, Vec<usize>), drop: &[usize]) {
+    for axis in (0..op.1.len()).rev() {
+        if drop.contains(&op.1[axis]) {
+            op.0 = op.0.sum_axis(Axis(axis)).into();
+            op.1.remove(axis);
+        }
+    }
+}
+
+/// Permute the axes of `a` to the order of the concatenated `groups` of
+/// labels, and reshape it to a 3-D array with one axis per group.
+fn arrange<'a, A>(
+    a: &ArrayViewD<'a, A>,
+    labels: &[usize],
+    groups: [&[usize]; 3],
+) -> CowArray<'a, A, Ix3>
+where
+    A: Clone,
+{
+    let axis_of = |l: &usize| labels.iter().position(|x| x == l).unwrap();
+    let perm: Vec<usize> = groups.iter().flat_map(|g| g.iter().map(axis_of)).collect();
+    let size = |g: &[usize]| g.iter().map(|l| a.len_of(Axis(axis_of(l)))).product::<usize>();
+    let shape = (size(groups[0]), size(groups[1]), size(groups[2]));
+    let a = a.clone().permuted_axes(perm);
+    if a.is_standard_layout() {
+        a.into_shape(shape).unwrap().into()
+    } else {
+        a.as_standard_layout().into_owned().into_shape(shape).unwrap().into()
+    }
+}
+
+/// Contract `a` and `b` over their shared labels that don't satisfy `keep`,
+/// and return the result with the labels: shared labels that satisfy
+/// `keep`, the other labels of `a`, then the other labels of `b`.
+fn contract_pair<A, F>(
+    a: &ArrayViewD<'_, A>,
+    la: &[usize],
+    b: &ArrayViewD<'_, A>,
+    lb: &[usize],
+    keep: F,
+) -> (ArrayD<A>, Vec<usize>)
+where
+    A: LinalgScalar,
+    F: Fn(usize) -> bool,
+{
+    let (mut batch, mut con, mut fa) = (Vec::new(), Vec::new(), Vec::new());
+    for &l in la {
+        match (lb.contains(&l), keep(l)) {
+            (true, true) => batch.push(l),
+            (true, false) => con.push(l),
+            (false, _) => fa.push(l),
+        }
+    }
+    let fb: Vec<usize> = lb.iter().cloned().filter(|l| !la.contains(l)).collect();
+
+    // Batches of M × K times K × N matrix products
+    let a3 = arrange(a, la, [&batch, &fa, &con]);
+    let b3 = arrange(b, lb, [&batch, &con, &fb]);
+    let ((nbatch, m, k), n) = (a3.dim(), b3.len_of(Axis(2)));
+    let mut c = Array3::zeros((nbatch, m, n));
+    if k == 1 {
+        // elementwise and outer products
+        Zip::from(&mut c)
+            .and_broadcast(&a3)
+            .and_broadcast(&b3)
+            .for_each(|c, &a, &b| *c = a * b);
+    } else if k > 0 {
+        Zip::from(c.outer_iter_mut())
+            .and(a3.outer_iter())
+            .and(b3.outer_iter())
+            .for_each(|mut c, a, b| general_mat_mul(A::one(), &a, &b, A::zero(), &mut c));
+    }
+
+    let labels = [batch, fa, fb].concat();
+    let size_of = |l: &usize| match la.iter().position(|x| x == l) {
+        Some(axis) => a.len_of(Axis(axis)),
+        None => b.len_of(Axis(lb.iter().position(|x| x == l).unwrap())),
+    };
+    let shape: Vec<usize> = labels.iter().map(size_of).collect();
+    (c.into_shape(shape).unwrap(), labels)
+}
+
+/// Evaluate the Einstein summation convention on the operands.
+///
+/// The `subscripts` label the axes of each operand with ASCII letters, with
+/// the terms of the operands separated by commas, optionally followed by
+/// `->` and the labels of the output. For example, `"ij,jk->ik"` is matrix
+/// multiplication and `"bij,bjk->bik"` is batched matrix multiplication.
+///
+/// - Axes whose labels are not in the output are summed over, like `"ii->"`
+///   (the trace) or `"ij->i"` (the row sums).
+/// - A label repeated within one operand selects the diagonal of its axes,
+///   like `"ii->i"`.
+/// - An ellipsis `...` stands for the remaining axes of an operand. They are
+///   broadcast together between the operands, and are summed over if the
+///   output has no ellipsis.
+/// - Without `->`, the output has the ellipsis axes followed by the labels
+///   that appear exactly once, in alphabetical order.
+///
+/// Operands are contracted pairwise, choosing at each step the pair with
+/// the smallest result, and each pairwise contraction is computed as
+/// a batch of matrix products with [`general_mat_mul`].
+///
+/// **Errors** if the subscripts can't be parsed or don't match the
+/// dimensionality of the operands, or if the axes with the same label have
+/// different lengths.
+///
+/// ```
+/// use ndarray::{array, einsum};
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// let b = array![[0., 1.], [1., 0.]];
+///
+/// let c = einsum("ij,jk->ik", &[&a.view(), &b.view()]).unwrap();
+/// assert_eq!(c, a.dot(&b).into_dyn());
+///
+/// let trace = einsum("ii", &[&a.view()]).unwrap();
+/// assert_eq!(trace[[]], 5.);
+///
+/// let transposed = einsum("ij->ji", &[&a.view()]).unwrap();
+/// assert_eq!(transposed, a.t().into_dyn());
+/// ```
+pub fn einsum<A, D>(
+    subscripts: &str,
+    operands: &[&ArrayView<'_, A, D>],
+) -> Result<ArrayD<A>, LinalgError>
+where
+    A: LinalgScalar,
+    D: Dimension,
+{
+    let ndims: Vec<usize> = operands.iter().map(|a| a.ndim()).collect();
+    let (inputs, output) = parse_subscripts(subscripts, &ndims)?;
+
+    // The length of each label, broadcasting the axes of the ellipses.
+    let nlabels = inputs.iter().flatten().max().map_or(0, |&l| l + 1);
+    let mut sizes: Vec<Option<usize>> = vec![None; nlabels];
+    for (a, labels) in operands.iter().zip(&inputs) {
+        for (&len, &label) in a.shape().iter().zip(labels) {
+            sizes[label] = match sizes[label] {
+                None => Some(len),
+                Some(size) if size == len || (label >= ELLIPSIS && len == 1) => Some(size),
+                Some(1) if label >= ELLIPSIS => Some(len),
+                Some(_) => return Err(from_kind(ErrorKind::IncompatibleShape).into()),
+            };
+        }
+    }
+    let sizes: Vec<usize> = sizes.into_iter().map(|s| s.unwrap_or(0)).collect();
+
+    let mut ops: Vec<(CowArray<'_, A, IxDyn>, Vec<usize>)> = operands
+        .iter()
+        .zip(&inputs)
+        .map(|(a, labels)| {
+            let (view, labels) = operand_view((*a).clone().into_dyn(), labels, &sizes);
+            (view.into(), labels)
+        })
+        .collect();
+    loop {
+        // Sum over the labels that only one operand has, unless they're in
+        // the output.
+        for i in 0..ops.len() {
+            let drop: Vec<usize> = ops[i]
+                .1
+                .iter()
+                .cloned()
+                .filter(|l| {
+                    !output.contains(l)
+                        && !ops.iter().enumerate().any(|(j, op)| j != i && op.1.contains(l))
+                })
+                .collect();
+            sum_labels(&mut ops[i], &drop);
+        }
+        if ops.len() <= 1 {
+            break;
+        }
+
+        // Contract the pair with the smallest result.
+        let mut best = (usize::MAX, 0, 1);
+        for i in 0..ops.len() {
+            for j in i + 1..ops.len() {
+                let size = ops[i]
+                    .1
+                    .iter()
+                    .chain(ops[j].1.iter().filter(|l| !ops[i].1.contains(l)))
+                    .filter(|&&l| {
+                        let elsewhere = |(x, op): (usize, &(_, Vec<usize>))| {
+                            x != i && x != j && op.1.contains(&l)
+                        };
+                        output.contains(&l) || ops.iter().enumerate().any(elsewhere)
+                    })
+                    .fold(1usize, |acc, &l| acc.saturating_mul(sizes[l]));
+                if size < best.0 {
+                    best = (size, i, j);
+                }
+            }
+        }
+        let (_, i, j) = best;
+        let (b, lb) = ops.remove(j);
+        let (a, la) = ops.remove(i);
+        let (c, lc) = contract_pair(&a.view(), &la, &b.view(), &lb, |l| {
+            output.contains(&l) || ops.iter().any(|(_, other)| other.contains(&l))
+        });
+        ops.push((c.into(), lc));
+    }
+
+    let (result, labels) = ops.pop().expect("einsum has at least one operand");
+    let perm: Vec<usize> = output
+        .iter()
+        .map(|l| labels.iter().position(|x| x == l).unwrap())
+        .collect();
+    Ok(result.into_owned().permuted_axes(perm))
+}
+
+/// Compute the tensor dot product of `a` and `b` over the axes `a_axes` of
+/// `a` and `b_axes` of `b`.
+///
+/// The product is the sum over the paired axes (`a_axes[i]` with
+/// `b_axes[i]`) of the products of the elements. The result has the
+/// remaining axes of `a` followed by the remaining axes of `b`, in order.
+/// For example, for matrices `a` and `b`, `tensordot(&a, &b, &[Axis(1)],
+/// &[Axis(0)])` is their matrix product.
+///
+/// **Panics** if `a_axes` and `b_axes` have different lengths, if an axis
+/// is out of bounds or repeated, or if paired axes have different lengths.
+///
+/// ```
+/// use ndarray::{tensordot, Array, Axis};
+///
+/// let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + j * k) as f64);
+/// let b = Array::from_shape_fn((4, 3, 2), |(i, j, k)| (i * j + k) as f64);
+///
+/// // contract axes 0 and 1 of a with axes 1 and 0 of b
+/// let c = tensordot(&a, &b, &[Axis(0), Axis(1)], &[Axis(1), Axis(0)]);
+/// assert_eq!(c.shape(), &[5, 2]);
+/// let expected = Array::from_shape_fn((5, 2), |(k, l)| {
+///     (0..3).flat_map(|i| (0..4).map(move |j| (i, j)))
+///         .map(|(i, j)| a[[i, j, k]] * b[[j, i, l]])
+///         .sum::<f64>()
+/// });
+/// assert_eq!(c, expected.into_dyn());
+/// ```
+pub fn tensordot<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    a_axes: &[Axis],
+    b_axes: &[Axis],
+) -> ArrayD<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+    A: LinalgScalar,
+{
+    assert_eq!(
+        a_axes.len(),
+        b_axes.len(),
+        "tensordot: a_axes and b_axes must have the same length"
+    );
+    // Label the axes of a with 0..na, and the axes of b with na.. except for
+    // the contracted axes, which have the labels of their pairs in a.
+    let (na, nb) = (a.ndim(), b.ndim());
+    let la: Vec<usize> = (0..na).collect();
+    let mut lb: Vec<usize> = (na..na + nb).collect();
+    for (i, (&Axis(x), &Axis(y))) in a_axes.iter().zip(b_axes).enumerate() {
+        assert!(x < na && y < nb, "tensordot: axis out of bounds");
+        assert!(
+            !a_axes[..i].contains(&Axis(x)) && !b_axes[..i].contains(&Axis(y)),
+            "tensordot: repeated axis"
+        );
+        assert_eq!(
+            a.len_of(Axis(x)),
+            b.len_of(Axis(y)),
+            "tensordot: contracted axes must have the same length"
+        );
+        lb[y] = x;
+    }
+    contract_pair(&a.view().into_dyn(), &la, &b.view().into_dyn(), &lb, |_| false).0
+}
@@ -0,0 +1,79 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "std")]
+use std::error::Error;
+use alloc::string::String;
+use std::fmt;
+
+use crate::ShapeError;
+
+/// An error from a linear algebra routine in [`linalg`](crate::linalg).
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinalgError {
+    /// The matrix must be square but has the given number of rows and
+    /// columns.
+    NotSquare {
+        /// Number of rows
+        rows: usize,
+        /// Number of columns
+        cols: usize,
+    },
+    /// The shapes of the operands are incompatible.
+    Shape(ShapeError),
+    /// The matrix is singular (not invertible).
+    Singular,
+    /// The matrix is not positive definite.
+    NotPositiveDefinite,
+    /// An iterative algorithm did not converge.
+    NoConvergence,
+    /// The subscripts of [`einsum`](crate::linalg::einsum) are invalid, for
+    /// the given reason.
+    InvalidSubscripts(String),
+}
+
+impl From<ShapeError> for LinalgError {
+    fn from(error: ShapeError) -> Self {
+        LinalgError::Shape(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LinalgError {}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "LinalgError: matrix is not square: {} × {}", rows, cols)
+            }
+            LinalgError::Shape(error) => write!(f, "LinalgError: {}", error),
+            LinalgError::Singular => write!(f, "LinalgError: matrix is singular"),
+            LinalgError::NotPositiveDefinite => {
+                write!(f, "LinalgError: matrix is not positive definite")
+            }
+            LinalgError::NoConvergence => write!(f, "LinalgError: iteration did not converge"),
+            LinalgError::InvalidSubscripts(reason) => {
+                write!(f, "LinalgError: invalid einsum subscripts: {}", reason)
+            }
+        }
+    }
+}
+
+/// Return an error unless `(rows, cols)` is square.
+#[cfg(feature = "std")]
+pub(crate) fn check_square((rows, cols): (usize, usize)) -> Result<usize, LinalgError> {
+    if rows == cols {
+        Ok(rows)
+    } else {
+        Err(LinalgError::NotSquare { rows, cols })
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive, ToPrimitive, Zero};
+
+use crate::imp_prelude::*;
+
+use super::error::{check_square, LinalgError};
+use super::{MatrixNorm, Scalar};
+
+/// Coefficients of the numerators of the [m/m] Padé approximants of `eˣ`,
+/// for m = 3, 5, 7, 9.
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// The largest 1-norm for which the approximant of degree 3, 5, 7, 9 and 13
+/// is accurate to double precision, from Higham (2005).
+const THETA: [f64; 5] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068,
+    5.371920351148152,
+];
+
+/// Return `Σ cᵢ Mᵢ` over the `(cᵢ, Mᵢ)` of `terms`, plus `c₀ I`.
+fn linear_combination<A: Scalar>(n: usize, c0: f64, terms: &[(f64, &Array2<A>)]) -> Array2<A> {
+    let scalar = |c: f64| A::from_real(A::Real::from_f64(c).unwrap());
+    let mut sum = Array2::zeros((n, n));
+    for &(c, m) in terms {
+        sum.scaled_add(scalar(c), m);
+    }
+    sum.diag_mut().mapv_inplace(|x| x + scalar(c0));
+    sum
+}
+
+/// Return the odd and even parts `(U, V)` of the numerator of the Padé
+/// approximant with coefficients `b`, so that it is `V + U`, and the
+/// denominator is `V - U`.
+fn pade<A: Scalar>(a: &Array2<A>, a2: &Array2<A>, b: &[f64]) -> (Array2<A>, Array2<A>) {
+    let n = a.nrows();
+    if b.len() == PADE_13.len() {
+        // Evaluate the polynomials of degree 13 with 6 products.
+        let a4 = a2.dot(a2);
+        let a6 = a4.dot(a2);
+        let u_high = linear_combination(n, 0., &[(b[13], &a6), (b[11], &a4), (b[9], a2)]);
+        let u_low = linear_combination(n, b[1], &[(b[7], &a6), (b[5], &a4), (b[3], a2)]);
+        let u = a.dot(&(a6.dot(&u_high) + u_low));
+        let v_high = linear_combination(n, 0., &[(b[12], &a6), (b[10], &a4), (b[8], a2)]);
+        let v_low = linear_combination(n, b[0], &[(b[6], &a6), (b[4], &a4), (b[2], a2)]);
+        (u, a6.dot(&v_high) + v_low)
+    } else {
+        // Even powers A², A⁴, …
+        let mut powers = vec![a2.clone()];
+        while 2 * (powers.len() + 1) < b.len() {
+            let next = powers[powers.len() - 1].dot(a2);
+            powers.push(next);
+        }
+        let terms = |first: usize| -> Vec<(f64, &Array2<A>)> {
+            b[first..].iter().step_by(2).cloned().zip(&powers).collect()
+        };
+        let u = a.dot(&linear_combination(n, b[1], &terms(3)));
+        (u, linear_combination(n, b[0], &terms(2)))
+    }
+}
+
+/// # Matrix Exponential
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Compute the matrix exponential `e^A` of the square matrix.
+    ///
+    /// Use the scaling and squaring method with a Padé approximant of degree
+    /// at most 13 (Higham, 2005): the matrix is scaled by a power of two
+    /// until its 1-norm is small enough, the exponential of the scaled
+    /// matrix is approximated, and the result is squared back.
+    ///
+    /// **Errors** if the matrix is not square, or if the denominator of the
+    /// approximant is singular, which can happen if the matrix has
+    /// non-finite elements.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// // the rotation by one radian
+    /// let a = array![[0., -1.], [1., 0.]];
+    /// let (s, c) = 1f64.sin_cos();
+    /// assert_abs_diff_eq!(a.expm().unwrap(), array![[c, -s], [s, c]], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn expm(&self) -> Result<Array2<A>, LinalgError> {
+        check_square(self.dim())?;
+        let real = |x: f64| A::Real::from_f64(x).unwrap();
+        let norm = self.matrix_norm(MatrixNorm::One)?;
+        let mut a = self.to_owned();
+
+        let degree = THETA.iter().position(|&theta| norm <= real(theta));
+        let (b, squarings): (&[f64], i32) = match degree {
+            Some(0) => (&PADE_3, 0),
+            Some(1) => (&PADE_5, 0),
+            Some(2) => (&PADE_7, 0),
+            Some(3) => (&PADE_9, 0),
+            _ => {
+                let s = (norm / real(THETA[4])).log2().ceil();
+                let s = if s > A::Real::zero() { s.to_i32().unwrap_or(0) } else { 0 };
+                let scale = A::from_real(real(2.).powi(-s));
+                a.mapv_inplace(|x| x * scale);
+                (&PADE_13, s)
+            }
+        };
+        let a2 = a.dot(&a);
+        let (u, v) = pade(&a, &a2, b);
+        let p = &v + &u;
+        let q = v - u;
+
+        let mut e = q.solve(&p)?;
+        for _ in 0..squarings {
+            e = e.dot(&e);
+        }
+        Ok(e)
+    }
+}
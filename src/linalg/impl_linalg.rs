@@ -14,6 +14,10 @@ use crate::numeric_util;
 
 use crate::{LinalgScalar, Zip};
 
+use super::backend::{self, CustomGemm, GemmBackend, GemmFallback, GemmPath};
+#[cfg(feature = "std")]
+use super::{Diag, Scalar, Transpose, Triangle};
+
 use std::any::TypeId;
 use std::mem::MaybeUninit;
 use alloc::vec::Vec;
@@ -32,6 +36,8 @@ use std::mem::swap;
 use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
 use cblas_sys::{CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT};
+#[cfg(all(feature = "blas", feature = "std"))]
+use cblas_sys::{CBLAS_DIAG, CBLAS_TRANSPOSE, CBLAS_UPLO};
 
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
@@ -235,7 +241,9 @@ where
     ///
     /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
     /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// is otherwise used for `f32, f64` for all memory layouts. The matrix
+    /// multiplication backend can be selected at runtime with
+    /// [`set_gemm_backend`](crate::linalg::set_gemm_backend).
     ///
     /// ```
     /// use ndarray::arr2;
@@ -256,6 +264,49 @@ where
     {
         Dot::dot(self, rhs)
     }
+
+    /// Return the square matrix `self` raised to the power `n`.
+    ///
+    /// The power is computed by repeated squaring, with at most
+    /// 2 log₂(*n*) matrix multiplications. The zeroth power is the identity
+    /// matrix.
+    ///
+    /// **Panics** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let fib = array![[1, 1], [1, 0]];
+    /// assert_eq!(fib.matrix_power(10), array![[89, 55], [55, 34]]);
+    /// assert_eq!(fib.matrix_power(0), array![[1, 0], [0, 1]]);
+    /// ```
+    pub fn matrix_power(&self, mut n: u32) -> Array2<A>
+    where
+        A: LinalgScalar,
+    {
+        let (rows, cols) = self.dim();
+        if rows != cols {
+            panic!(
+                "ndarray: matrix_power requires a square matrix, but the shape is {:?}",
+                self.shape()
+            );
+        }
+        let mut base = self.to_owned();
+        let mut power: Option<Array2<A>> = None;
+        while n > 0 {
+            if n & 1 == 1 {
+                power = Some(match power {
+                    Some(power) => power.dot(&base),
+                    None => base.clone(),
+                });
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.dot(&base);
+            }
+        }
+        power.unwrap_or_else(|| Array2::eye(rows))
+    }
 }
 
 impl<A, S, S2> Dot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix2>
@@ -351,7 +402,9 @@ where
     ///
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
-    /// **Panics** if broadcasting isn’t possible.
+    /// **Panics** if broadcasting isn’t possible.<br>
+    /// *Note:* If enabled, uses blas `axpy` for elements of `f32, f64` when
+    /// both arrays are contiguous with the same memory layout.
     pub fn scaled_add<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
     where
         S: DataMut,
@@ -359,35 +412,175 @@ where
         A: LinalgScalar,
         E: Dimension,
     {
+        #[cfg(feature = "blas")]
+        {
+            if self.len() >= DOT_BLAS_CUTOFF
+                && self.shape() == rhs.shape()
+                && self.strides() == rhs.strides()
+                && blas_axpy(alpha, rhs, self)
+            {
+                return;
+            }
+        }
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
 }
 
+/// Compute `y ← α x + y` with blas `axpy`, if the element type is `f32` or
+/// `f64` and both arrays are contiguous; return whether it was done.
+///
+/// The arrays must have the same shape and strides.
+#[cfg(feature = "blas")]
+fn blas_axpy<A, S1, S2, D, E>(alpha: A, x: &ArrayBase<S1, E>, y: &mut ArrayBase<S2, D>) -> bool
+where
+    A: LinalgScalar,
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    D: Dimension,
+    E: Dimension,
+{
+    if y.len() > blas_index::max_value() as usize {
+        return false;
+    }
+    let x = match x.as_slice_memory_order() {
+        Some(x) => x,
+        None => return false,
+    };
+    let y = match y.as_slice_memory_order_mut() {
+        Some(y) => y,
+        None => return false,
+    };
+    macro_rules! axpy {
+        ($ty:ty, $axpy:ident) => {
+            if same_type::<A, $ty>() {
+                unsafe {
+                    blas_sys::$axpy(
+                        y.len() as blas_index,
+                        cast_as(&alpha),
+                        x.as_ptr() as *const $ty,
+                        1,
+                        y.as_mut_ptr() as *mut $ty,
+                        1,
+                    );
+                }
+                return true;
+            }
+        };
+    }
+    axpy!(f32, cblas_saxpy);
+    axpy!(f64, cblas_daxpy);
+    false
+}
+
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
 // the same instantiated implementation.
+fn mat_mul_impl<A>(
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    let result = match backend::current_backend() {
+        GemmBackend::Native => Ok(GemmPath::Native),
+        GemmBackend::Blas => mat_mul_blas(alpha, lhs, rhs, beta, c).map(|()| GemmPath::Blas),
+        GemmBackend::Custom(custom) => {
+            mat_mul_custom(&custom, alpha, lhs, rhs, beta, c).map(|()| GemmPath::Custom)
+        }
+    };
+    match result {
+        Ok(GemmPath::Native) => {
+            mat_mul_general(alpha, lhs, rhs, beta, c);
+            backend::record(GemmPath::Native, None);
+        }
+        Ok(path) => backend::record(path, None),
+        Err(reason) => {
+            mat_mul_general(alpha, lhs, rhs, beta, c);
+            backend::record(GemmPath::Native, Some(reason));
+        }
+    }
+}
+
+/// C ← α A B + β C with the user provided function for the element type.
+fn mat_mul_custom<A>(
+    custom: &CustomGemm,
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) -> Result<(), GemmFallback>
+where
+    A: LinalgScalar,
+{
+    macro_rules! custom_gemm {
+        ($ty:ty, $field:ident) => {
+            if same_type::<A, $ty>() {
+                let gemm = custom.$field.ok_or(GemmFallback::ElementType)?;
+                // `c` may be uninitialized when beta is zero, like in `.dot()`,
+                // and the function may read it
+                if beta.is_zero() {
+                    c.fill(A::zero());
+                }
+                // Safe because A and $ty are the same type
+                unsafe {
+                    gemm(
+                        cast_as(&alpha),
+                        lhs.view().into_raw_view().cast::<$ty>().deref_into_view(),
+                        rhs.view().into_raw_view().cast::<$ty>().deref_into_view(),
+                        cast_as(&beta),
+                        c.view_mut().into_raw_view_mut().cast::<$ty>().deref_into_view_mut(),
+                    );
+                }
+                return Ok(());
+            }
+        };
+    }
+    custom_gemm!(f32, f32);
+    custom_gemm!(f64, f64);
+    custom_gemm!(c32, c32);
+    custom_gemm!(c64, c64);
+    Err(GemmFallback::ElementType)
+}
+
 #[cfg(not(feature = "blas"))]
-use self::mat_mul_general as mat_mul_impl;
+fn mat_mul_blas<A>(
+    _alpha: A,
+    _lhs: &ArrayView2<'_, A>,
+    _rhs: &ArrayView2<'_, A>,
+    _beta: A,
+    _c: &mut ArrayViewMut2<'_, A>,
+) -> Result<(), GemmFallback> {
+    Err(GemmFallback::Unavailable)
+}
 
+/// C ← α A B + β C with blas `gemm`, if the element type, size and memory
+/// layout allow.
 #[cfg(feature = "blas")]
-fn mat_mul_impl<A>(
+fn mat_mul_blas<A>(
     alpha: A,
     lhs: &ArrayView2<'_, A>,
     rhs: &ArrayView2<'_, A>,
     beta: A,
     c: &mut ArrayViewMut2<'_, A>,
-) where
+) -> Result<(), GemmFallback>
+where
     A: LinalgScalar,
 {
-    // size cutoff for using BLAS
-    let cut = GEMM_BLAS_CUTOFF;
-    let ((mut m, a), (_, mut n)) = (lhs.dim(), rhs.dim());
-    if !(m > cut || n > cut || a > cut)
-        || !(same_type::<A, f32>()
+    if !(same_type::<A, f32>()
         || same_type::<A, f64>()
         || same_type::<A, c32>()
         || same_type::<A, c64>())
     {
-        return mat_mul_general(alpha, lhs, rhs, beta, c);
+        return Err(GemmFallback::ElementType);
+    }
+    // size cutoff for using BLAS
+    let cut = GEMM_BLAS_CUTOFF;
+    let ((mut m, a), (_, mut n)) = (lhs.dim(), rhs.dim());
+    if !(m > cut || n > cut || a > cut) {
+        return Err(GemmFallback::Small);
     }
     {
         // Use `c` for c-order and `f` for an f-order matrix
@@ -473,7 +666,7 @@ fn mat_mul_impl<A>(
                             c_stride,                        // ldc
                         );
                     }
-                    return;
+                    return Ok(());
                 }
             };
         }
@@ -483,7 +676,7 @@ fn mat_mul_impl<A>(
         gemm!(c32, cblas_cgemm);
         gemm!(c64, cblas_zgemm);
     }
-    mat_mul_general(alpha, lhs, rhs, beta, c)
+    Err(GemmFallback::Layout)
 }
 
 /// C ← α A B + β C
@@ -627,7 +820,8 @@ fn mat_mul_general<A>(
 /// ***Panics*** if array shapes are not compatible<br>
 /// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
 /// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// `f32, f64` for all memory layouts. The backend can be selected at runtime
+/// with [`set_gemm_backend`](crate::linalg::set_gemm_backend).
 pub fn general_mat_mul<A, S1, S2, S3>(
     alpha: A,
     a: &ArrayBase<S1, Ix2>,
@@ -658,7 +852,8 @@ pub fn general_mat_mul<A, S1, S2, S3>(
 ///
 /// ***Panics*** if array shapes are not compatible<br>
 /// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` when memory
-/// layout allows.
+/// layout allows. The backend can be selected at runtime with
+/// [`set_gemm_backend`](crate::linalg::set_gemm_backend).
 #[allow(clippy::collapsible_if)]
 pub fn general_mat_vec_mul<A, S1, S2, S3>(
     alpha: A,
@@ -699,74 +894,659 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
     let m2 = y.dim();
     if k != k2 || m != m2 {
         general_dot_shape_error(m, k, k2, 1, m2, 1);
+    }
+    let a = a.view();
+    let x = x.view();
+    let result = match backend::current_backend() {
+        GemmBackend::Native => Ok(GemmPath::Native),
+        GemmBackend::Blas => mat_vec_mul_blas(alpha, &a, &x, beta, y).map(|()| GemmPath::Blas),
+        GemmBackend::Custom(custom) => {
+            mat_vec_mul_custom(&custom, alpha, &a, &x, beta, y).map(|()| GemmPath::Custom)
+        }
+    };
+    match result {
+        Ok(GemmPath::Native) => {
+            mat_vec_mul_general(alpha, &a, &x, beta, y);
+            backend::record(GemmPath::Native, None);
+        }
+        Ok(path) => backend::record(path, None),
+        Err(reason) => {
+            mat_vec_mul_general(alpha, &a, &x, beta, y);
+            backend::record(GemmPath::Native, Some(reason));
+        }
+    }
+}
+
+/// y ← α A x + β y with the user provided matrix multiplication function for
+/// the element type, with `x` and `y` as matrices of one column.
+///
+/// ## Safety
+///
+/// The same as for `general_mat_vec_mul_impl`.
+unsafe fn mat_vec_mul_custom<A>(
+    custom: &CustomGemm,
+    alpha: A,
+    a: &ArrayView2<'_, A>,
+    x: &ArrayView1<'_, A>,
+    beta: A,
+    y: RawArrayViewMut<A, Ix1>,
+) -> Result<(), GemmFallback>
+where
+    A: LinalgScalar,
+{
+    if beta.is_zero() {
+        // y may be uninitialized, so write zeros before making a view of it
+        Zip::from(y).for_each(|elt| elt.write(A::zero()));
+    }
+    let x = x.view().insert_axis(Axis(1));
+    let mut y = y.deref_into_view_mut().insert_axis(Axis(1));
+    mat_mul_custom(custom, alpha, a, &x, beta, &mut y)
+}
+
+#[cfg(not(feature = "blas"))]
+unsafe fn mat_vec_mul_blas<A>(
+    _alpha: A,
+    _a: &ArrayView2<'_, A>,
+    _x: &ArrayView1<'_, A>,
+    _beta: A,
+    _y: RawArrayViewMut<A, Ix1>,
+) -> Result<(), GemmFallback> {
+    Err(GemmFallback::Unavailable)
+}
+
+/// y ← α A x + β y with blas `gemv`, if the element type and memory layout
+/// allow.
+///
+/// ## Safety
+///
+/// The same as for `general_mat_vec_mul_impl`.
+#[cfg(feature = "blas")]
+unsafe fn mat_vec_mul_blas<A>(
+    alpha: A,
+    a: &ArrayView2<'_, A>,
+    x: &ArrayView1<'_, A>,
+    beta: A,
+    y: RawArrayViewMut<A, Ix1>,
+) -> Result<(), GemmFallback>
+where
+    A: LinalgScalar,
+{
+    if !(same_type::<A, f32>() || same_type::<A, f64>()) {
+        return Err(GemmFallback::ElementType);
+    }
+    let (m, k) = a.dim();
+    macro_rules! gemv {
+        ($ty:ty, $gemv:ident) => {
+            if let Some(layout) = blas_layout::<$ty, _>(a) {
+                if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(&y) {
+                    // Determine stride between rows or columns. Note that the stride is
+                    // adjusted to at least `k` or `m` to handle the case of a matrix with a
+                    // trivial (length 1) dimension, since the stride for the trivial dimension
+                    // may be arbitrary.
+                    let a_trans = CblasNoTrans;
+                    let a_stride = match layout {
+                        CBLAS_LAYOUT::CblasRowMajor => {
+                            a.strides()[0].max(k as isize) as blas_index
+                        }
+                        CBLAS_LAYOUT::CblasColMajor => {
+                            a.strides()[1].max(m as isize) as blas_index
+                        }
+                    };
+
+                    // Low addr in memory pointers required for x, y
+                    let x_offset = offset_from_low_addr_ptr_to_logical_ptr(&x.dim, &x.strides);
+                    let x_ptr = x.ptr.as_ptr().sub(x_offset);
+                    let y_offset = offset_from_low_addr_ptr_to_logical_ptr(&y.dim, &y.strides);
+                    let y_ptr = y.ptr.as_ptr().sub(y_offset);
+
+                    let x_stride = x.strides()[0] as blas_index;
+                    let y_stride = y.strides()[0] as blas_index;
+
+                    blas_sys::$gemv(
+                        layout,
+                        a_trans,
+                        m as blas_index,            // m, rows of Op(a)
+                        k as blas_index,            // n, cols of Op(a)
+                        cast_as(&alpha),            // alpha
+                        a.ptr.as_ptr() as *const _, // a
+                        a_stride,                   // lda
+                        x_ptr as *const _,          // x
+                        x_stride,
+                        cast_as(&beta),             // beta
+                        y_ptr as *mut _,            // y
+                        y_stride,
+                    );
+                    return Ok(());
+                }
+            }
+        };
+    }
+    gemv!(f32, cblas_sgemv);
+    gemv!(f64, cblas_dgemv);
+    Err(GemmFallback::Layout)
+}
+
+/// y ← α A x + β y
+///
+/// ## Safety
+///
+/// The same as for `general_mat_vec_mul_impl`.
+unsafe fn mat_vec_mul_general<A>(
+    alpha: A,
+    a: &ArrayView2<'_, A>,
+    x: &ArrayView1<'_, A>,
+    beta: A,
+    y: RawArrayViewMut<A, Ix1>,
+) where
+    A: LinalgScalar,
+{
+    if beta.is_zero() {
+        // when beta is zero, c may be uninitialized
+        Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
+            elt.write(row.dot(x) * alpha);
+        });
     } else {
+        Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
+            *elt = *elt * beta + row.dot(x) * alpha;
+        });
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[cfg(feature = "std")]
+fn level2_shape_error(op: &str, a: &[usize], x: &[usize], y: &[usize]) -> ! {
+    panic!(
+        "ndarray: inputs of shape {:?}, {:?} and {:?} are not compatible for {}",
+        a, x, y, op
+    );
+}
+
+/// # BLAS Level 2 Operations
+///
+/// These methods require crate feature `std`.
+#[cfg(feature = "std")]
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Perform the rank-one update `self ← self + α x yᵀ`, also known as
+    /// `ger` in BLAS.
+    ///
+    /// **Panics** if `self` is not *M* × *N*, where *M* is the length of `x`
+    /// and *N* is the length of `y`.<br>
+    /// *Note:* If enabled, uses blas `ger` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., 0.], [0., 1.]];
+    /// a.ger(2., &array![1., 2.], &array![3., 4.]);
+    /// assert_eq!(a, array![[7., 8.], [12., 17.]]);
+    /// ```
+    pub fn ger<S2, S3>(&mut self, alpha: A, x: &ArrayBase<S2, Ix1>, y: &ArrayBase<S3, Ix1>)
+    where
+        S: DataMut,
+        S2: Data<Elem = A>,
+        S3: Data<Elem = A>,
+    {
+        let (m, n) = self.dim();
+        if x.len() != m || y.len() != n {
+            level2_shape_error("ger", self.shape(), x.shape(), y.shape());
+        }
+        if self.is_empty() {
+            return;
+        }
         #[cfg(feature = "blas")]
-        macro_rules! gemv {
-            ($ty:ty, $gemv:ident) => {
-                if let Some(layout) = blas_layout::<$ty, _>(&a) {
-                    if blas_compat_1d::<$ty, _>(&x) && blas_compat_1d::<$ty, _>(&y) {
-                        // Determine stride between rows or columns. Note that the stride is
-                        // adjusted to at least `k` or `m` to handle the case of a matrix with a
-                        // trivial (length 1) dimension, since the stride for the trivial dimension
-                        // may be arbitrary.
-                        let a_trans = CblasNoTrans;
-                        let a_stride = match layout {
-                            CBLAS_LAYOUT::CblasRowMajor => {
-                                a.strides()[0].max(k as isize) as blas_index
-                            }
-                            CBLAS_LAYOUT::CblasColMajor => {
-                                a.strides()[1].max(m as isize) as blas_index
-                            }
-                        };
-
-                        // Low addr in memory pointers required for x, y
-                        let x_offset = offset_from_low_addr_ptr_to_logical_ptr(&x.dim, &x.strides);
-                        let x_ptr = x.ptr.as_ptr().sub(x_offset);
-                        let y_offset = offset_from_low_addr_ptr_to_logical_ptr(&y.dim, &y.strides);
-                        let y_ptr = y.ptr.as_ptr().sub(y_offset);
-
-                        let x_stride = x.strides()[0] as blas_index;
-                        let y_stride = y.strides()[0] as blas_index;
-
-                        blas_sys::$gemv(
-                            layout,
-                            a_trans,
-                            m as blas_index,            // m, rows of Op(a)
-                            k as blas_index,            // n, cols of Op(a)
-                            cast_as(&alpha),            // alpha
-                            a.ptr.as_ptr() as *const _, // a
-                            a_stride,                   // lda
-                            x_ptr as *const _,          // x
-                            x_stride,
-                            cast_as(&beta),             // beta
-                            y_ptr as *mut _,            // y
-                            y_stride,
-                        );
+        macro_rules! ger {
+            ($ty:ty, $ger:ident) => {
+                if let Some((layout, lda)) = blas_2d_params::<$ty, _>(self) {
+                    if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(y) {
+                        unsafe {
+                            let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), m, x.strides()[0]);
+                            let (y_ptr, _, incy) = blas_1d_params(y.as_ptr(), n, y.strides()[0]);
+                            blas_sys::$ger(
+                                layout,
+                                m as blas_index,
+                                n as blas_index,
+                                cast_as(&alpha),
+                                x_ptr as *const $ty,
+                                incx,
+                                y_ptr as *const $ty,
+                                incy,
+                                self.as_mut_ptr() as *mut $ty,
+                                lda,
+                            );
+                        }
                         return;
                     }
                 }
             };
         }
         #[cfg(feature = "blas")]
-        gemv!(f32, cblas_sgemv);
+        ger!(f32, cblas_sger);
         #[cfg(feature = "blas")]
-        gemv!(f64, cblas_dgemv);
+        ger!(f64, cblas_dger);
 
-        /* general */
+        Zip::from(self.rows_mut())
+            .and(x)
+            .for_each(|mut row, &xi| row.scaled_add(alpha * xi, y));
+    }
 
-        if beta.is_zero() {
-            // when beta is zero, c may be uninitialized
-            Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
-                elt.write(row.dot(x) * alpha);
-            });
-        } else {
-            Zip::from(a.outer_iter()).and(y).for_each(|row, elt| {
-                *elt = *elt * beta + row.dot(x) * alpha;
+    /// Perform the symmetric rank-one update `self ← self + α x xᵀ`, also
+    /// known as `syr` in BLAS.
+    ///
+    /// Only the `uplo` triangle of `self` is read and updated.
+    ///
+    /// **Panics** if `self` is not *N* × *N*, where *N* is the length of
+    /// `x`.<br>
+    /// *Note:* If enabled, uses blas `syr` for elements of `f32, f64` when
+    /// memory layout allows.
+    pub fn syr<S2>(&mut self, alpha: A, x: &ArrayBase<S2, Ix1>, uplo: Triangle)
+    where
+        S: DataMut,
+        S2: Data<Elem = A>,
+    {
+        let n = x.len();
+        if self.dim() != (n, n) {
+            level2_shape_error("syr", self.shape(), x.shape(), x.shape());
+        }
+        if n == 0 {
+            return;
+        }
+        #[cfg(feature = "blas")]
+        macro_rules! syr {
+            ($ty:ty, $syr:ident) => {
+                if let Some((layout, lda)) = blas_2d_params::<$ty, _>(self) {
+                    if blas_compat_1d::<$ty, _>(x) {
+                        unsafe {
+                            let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), n, x.strides()[0]);
+                            blas_sys::$syr(
+                                layout,
+                                blas_uplo(uplo),
+                                n as blas_index,
+                                cast_as(&alpha),
+                                x_ptr as *const $ty,
+                                incx,
+                                self.as_mut_ptr() as *mut $ty,
+                                lda,
+                            );
+                        }
+                        return;
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        syr!(f32, cblas_ssyr);
+        #[cfg(feature = "blas")]
+        syr!(f64, cblas_dsyr);
+
+        for i in 0..n {
+            let (row, part) = match uplo {
+                Triangle::Upper => (s![i, i..], s![i..]),
+                Triangle::Lower => (s![i, ..=i], s![..=i]),
+            };
+            self.slice_mut(row).scaled_add(alpha * x[i], &x.slice(part));
+        }
+    }
+
+    /// Perform the symmetric rank-*K* update `self ← α A Aᵀ + β self`, also
+    /// known as `syrk` in BLAS, where `a` is *N* × *K*.
+    ///
+    /// Only the `uplo` triangle of `self` is read and updated; pass `a.t()`
+    /// to compute `α Aᵀ A + β self` instead. If `beta` is zero, `self` is
+    /// overwritten without being read.
+    ///
+    /// **Panics** if `self` is not *N* × *N*.<br>
+    /// *Note:* If enabled, uses blas `syrk` for elements of `f32, f64` when
+    /// memory layout allows.
+    pub fn syrk<S2>(&mut self, alpha: A, a: &ArrayBase<S2, Ix2>, beta: A, uplo: Triangle)
+    where
+        S: DataMut,
+        S2: Data<Elem = A>,
+    {
+        let n = a.nrows();
+        if self.dim() != (n, n) {
+            level2_shape_error("syrk", self.shape(), a.shape(), a.shape());
+        }
+        if n == 0 {
+            return;
+        }
+        #[cfg(feature = "blas")]
+        macro_rules! syrk {
+            ($ty:ty, $syrk:ident) => {
+                if let (Some((layout, ldc)), true) = (blas_2d_params::<$ty, _>(self), a.ncols() > 0) {
+                    // `a` is either in the same layout as `self`, or its
+                    // transpose is.
+                    let a_params = match blas_ld::<$ty, _>(a, layout) {
+                        Some(lda) => Some((CblasNoTrans, lda)),
+                        None => blas_ld::<$ty, _>(&a.t(), layout).map(|lda| (CblasTrans, lda)),
+                    };
+                    if let Some((trans, lda)) = a_params {
+                        unsafe {
+                            blas_sys::$syrk(
+                                layout,
+                                blas_uplo(uplo),
+                                trans,
+                                n as blas_index,
+                                a.ncols() as blas_index,
+                                cast_as(&alpha),
+                                a.as_ptr() as *const $ty,
+                                lda,
+                                cast_as(&beta),
+                                self.as_mut_ptr() as *mut $ty,
+                                ldc,
+                            );
+                        }
+                        return;
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        syrk!(f32, cblas_ssyrk);
+        #[cfg(feature = "blas")]
+        syrk!(f64, cblas_dsyrk);
+
+        for i in 0..n {
+            let cols = match uplo {
+                Triangle::Upper => i..n,
+                Triangle::Lower => 0..i + 1,
+            };
+            for j in cols {
+                let p = alpha * a.row(i).dot(&a.row(j));
+                let c = &mut self[[i, j]];
+                *c = if beta.is_zero() { p } else { beta * *c + p };
+            }
+        }
+    }
+
+    /// Perform the symmetric matrix-vector multiplication
+    /// `y ← α A x + β y`, also known as `symv` in BLAS.
+    ///
+    /// Only the `uplo` triangle of `self` is read. If `beta` is zero, `y` is
+    /// overwritten without being read.
+    ///
+    /// **Panics** if `self` is not *N* × *N*, where *N* is the length of `x`
+    /// and `y`.<br>
+    /// *Note:* If enabled, uses blas `symv` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    /// use ndarray::linalg::Triangle;
+    ///
+    /// // only the upper triangle is read
+    /// let a = array![[1., 2.], [0., 3.]];
+    /// let mut y = Array1::zeros(2);
+    /// a.symv(1., &array![1., 1.], 0., &mut y, Triangle::Upper);
+    /// assert_eq!(y, array![3., 5.]);
+    /// ```
+    pub fn symv<S2, S3>(
+        &self,
+        alpha: A,
+        x: &ArrayBase<S2, Ix1>,
+        beta: A,
+        y: &mut ArrayBase<S3, Ix1>,
+        uplo: Triangle,
+    ) where
+        S2: Data<Elem = A>,
+        S3: DataMut<Elem = A>,
+    {
+        let n = x.len();
+        if self.dim() != (n, n) || y.len() != n {
+            level2_shape_error("symv", self.shape(), x.shape(), y.shape());
+        }
+        if n == 0 {
+            return;
+        }
+        #[cfg(feature = "blas")]
+        macro_rules! symv {
+            ($ty:ty, $symv:ident) => {
+                if let Some((layout, lda)) = blas_2d_params::<$ty, _>(self) {
+                    if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(y) {
+                        unsafe {
+                            let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), n, x.strides()[0]);
+                            let y_stride = y.strides()[0];
+                            let (y_ptr, _, incy) = blas_1d_params(y.as_mut_ptr(), n, y_stride);
+                            blas_sys::$symv(
+                                layout,
+                                blas_uplo(uplo),
+                                n as blas_index,
+                                cast_as(&alpha),
+                                self.as_ptr() as *const $ty,
+                                lda,
+                                x_ptr as *const $ty,
+                                incx,
+                                cast_as(&beta),
+                                y_ptr as *mut $ty,
+                                incy,
+                            );
+                        }
+                        return;
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        symv!(f32, cblas_ssymv);
+        #[cfg(feature = "blas")]
+        symv!(f64, cblas_dsymv);
+
+        let upper = uplo == Triangle::Upper;
+        Zip::indexed(y).for_each(|i, yi| {
+            let p = (0..n).fold(A::zero(), |acc, j| {
+                let aij = if (i <= j) == upper { self[[i, j]] } else { self[[j, i]] };
+                acc + aij * x[j]
             });
+            *yi = if beta.is_zero() { alpha * p } else { beta * *yi + alpha * p };
+        });
+    }
+
+    /// Return `op(A)` of the triangular matrix `self`, which triangle of it
+    /// is used and whether its elements must be conjugated.
+    fn triangular_op(
+        &self,
+        uplo: Triangle,
+        trans: Transpose,
+    ) -> (ArrayView2<'_, A>, Triangle, bool) {
+        match trans {
+            Transpose::No => (self.view(), uplo, false),
+            Transpose::Transpose => (self.t(), uplo.flip(), false),
+            Transpose::Adjoint => (self.t(), uplo.flip(), true),
         }
     }
-}
 
+    /// Perform the triangular matrix-vector multiplication `x ← op(A) x`,
+    /// also known as `trmv` in BLAS.
+    ///
+    /// Only the `uplo` triangle of `self` is read, and its diagonal is
+    /// ignored if `diag` is `Diag::Unit`.
+    ///
+    /// **Panics** if `self` is not *N* × *N*, where *N* is the length of
+    /// `x`.<br>
+    /// *Note:* If enabled, uses blas `trmv` for elements of `f32, f64` when
+    /// memory layout allows.
+    pub fn trmv<S2>(&self, x: &mut ArrayBase<S2, Ix1>, uplo: Triangle, trans: Transpose, diag: Diag)
+    where
+        S2: DataMut<Elem = A>,
+    {
+        let n = x.len();
+        if self.dim() != (n, n) {
+            level2_shape_error("trmv", self.shape(), x.shape(), x.shape());
+        }
+        if n == 0 {
+            return;
+        }
+        #[cfg(feature = "blas")]
+        macro_rules! trmv {
+            ($ty:ty, $trmv:ident) => {
+                if self.blas_triangular::<$ty, _>(x, uplo, trans, diag, blas_sys::$trmv) {
+                    return;
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        trmv!(f32, cblas_strmv);
+        #[cfg(feature = "blas")]
+        trmv!(f64, cblas_dtrmv);
+
+        let (t, uplo, conj) = self.triangular_op(uplo, trans);
+        let tij = |i: usize, j: usize| if conj { t[[i, j]].conj() } else { t[[i, j]] };
+        let unit = diag == Diag::Unit;
+        // Each x[i] is computed from elements that are not yet updated.
+        let row = |x: &ArrayBase<S2, Ix1>, i: usize, others: core::ops::Range<usize>| {
+            let d = if unit { x[i] } else { tij(i, i) * x[i] };
+            others.fold(d, |acc, j| acc + tij(i, j) * x[j])
+        };
+        match uplo {
+            Triangle::Upper => {
+                for i in 0..n {
+                    x[i] = row(x, i, i + 1..n);
+                }
+            }
+            Triangle::Lower => {
+                for i in (0..n).rev() {
+                    x[i] = row(x, i, 0..i);
+                }
+            }
+        }
+    }
+
+    /// Solve the triangular system `op(A) y = x` in place of `x`, also known
+    /// as `trsv` in BLAS.
+    ///
+    /// Only the `uplo` triangle of `self` is read, and its diagonal is
+    /// ignored if `diag` is `Diag::Unit`. The matrix isn't checked for
+    /// singularity; see [`.solve_triangular()`](Self::solve_triangular) for
+    /// a version that does.
+    ///
+    /// **Panics** if `self` is not *N* × *N*, where *N* is the length of
+    /// `x`.<br>
+    /// *Note:* If enabled, uses blas `trsv` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::{Diag, Transpose, Triangle};
+    ///
+    /// let l = array![[2., 0.], [1., 4.]];
+    /// let mut x = array![2., 9.];
+    /// l.trsv(&mut x, Triangle::Lower, Transpose::No, Diag::NonUnit);
+    /// assert_eq!(x, array![1., 2.]);
+    /// l.trmv(&mut x, Triangle::Lower, Transpose::No, Diag::NonUnit);
+    /// assert_eq!(x, array![2., 9.]);
+    /// ```
+    pub fn trsv<S2>(&self, x: &mut ArrayBase<S2, Ix1>, uplo: Triangle, trans: Transpose, diag: Diag)
+    where
+        S2: DataMut<Elem = A>,
+    {
+        let n = x.len();
+        if self.dim() != (n, n) {
+            level2_shape_error("trsv", self.shape(), x.shape(), x.shape());
+        }
+        if n == 0 {
+            return;
+        }
+        #[cfg(feature = "blas")]
+        macro_rules! trsv {
+            ($ty:ty, $trsv:ident) => {
+                if self.blas_triangular::<$ty, _>(x, uplo, trans, diag, blas_sys::$trsv) {
+                    return;
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        trsv!(f32, cblas_strsv);
+        #[cfg(feature = "blas")]
+        trsv!(f64, cblas_dtrsv);
+
+        let (t, uplo, conj) = self.triangular_op(uplo, trans);
+        let tij = |i: usize, j: usize| if conj { t[[i, j]].conj() } else { t[[i, j]] };
+        let unit = diag == Diag::Unit;
+        // Substitute using the elements of x that are already solved.
+        let row = |x: &ArrayBase<S2, Ix1>, i: usize, solved: core::ops::Range<usize>| {
+            let s = solved.fold(x[i], |acc, j| acc - tij(i, j) * x[j]);
+            if unit { s } else { s / tij(i, i) }
+        };
+        match uplo {
+            Triangle::Lower => {
+                for i in 0..n {
+                    x[i] = row(x, i, 0..i);
+                }
+            }
+            Triangle::Upper => {
+                for i in (0..n).rev() {
+                    x[i] = row(x, i, i + 1..n);
+                }
+            }
+        }
+    }
+
+    /// Call the blas `trmv` or `trsv` function `f` if the element type is
+    /// `T` and memory layout allows; return whether it was called.
+    #[cfg(feature = "blas")]
+    #[allow(clippy::type_complexity)]
+    fn blas_triangular<T, S2>(
+        &self,
+        x: &mut ArrayBase<S2, Ix1>,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diag,
+        f: unsafe extern "C" fn(
+            CBLAS_LAYOUT,
+            CBLAS_UPLO,
+            CBLAS_TRANSPOSE,
+            CBLAS_DIAG,
+            blas_index,
+            *const T,
+            blas_index,
+            *mut T,
+            blas_index,
+        ),
+    ) -> bool
+    where
+        T: 'static,
+        S2: DataMut<Elem = A>,
+    {
+        let (layout, lda) = match blas_2d_params::<T, _>(self) {
+            Some(params) => params,
+            None => return false,
+        };
+        if !blas_compat_1d::<T, _>(x) {
+            return false;
+        }
+        let trans = match trans {
+            Transpose::No => CblasNoTrans,
+            // only called for real elements
+            Transpose::Transpose | Transpose::Adjoint => CblasTrans,
+        };
+        let diag = match diag {
+            Diag::NonUnit => CBLAS_DIAG::CblasNonUnit,
+            Diag::Unit => CBLAS_DIAG::CblasUnit,
+        };
+        let n = x.len();
+        unsafe {
+            let x_stride = x.strides()[0];
+            let (x_ptr, _, incx) = blas_1d_params(x.as_mut_ptr(), n, x_stride);
+            f(
+                layout,
+                blas_uplo(uplo),
+                trans,
+                diag,
+                n as blas_index,
+                self.as_ptr() as *const T,
+                lda,
+                x_ptr as *mut T,
+                incx,
+            );
+        }
+        true
+    }
+}
 
 /// Kronecker product of 2D matrices.
 ///
@@ -800,6 +1580,34 @@ where
     unsafe { out.assume_init() }
 }
 
+/// Outer product of two vectors.
+///
+/// The outer product of an *M*-element vector `a` and an *N*-element vector
+/// `b` is the *M* × *N* matrix with the elements `a[i] * b[j]`.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::outer;
+///
+/// let a = array![1, 2];
+/// let b = array![3, 4, 5];
+/// assert_eq!(outer(&a, &b), array![[3, 4, 5], [6, 8, 10]]);
+/// ```
+pub fn outer<A, S1, S2>(a: &ArrayBase<S1, Ix1>, b: &ArrayBase<S2, Ix1>) -> Array2<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let mut out = Array2::uninit((a.len(), b.len()));
+    Zip::from(out.rows_mut()).and(a).for_each(|row, &a| {
+        Zip::from(row).and(b).for_each(|out, &b| {
+            *out = MaybeUninit::new(a * b);
+        })
+    });
+    unsafe { out.assume_init() }
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool {
@@ -902,6 +1710,52 @@ fn is_blas_2d(dim: &Ix2, stride: &Ix2, order: MemoryOrder) -> bool {
     true
 }
 
+/// Return the leading dimension of `a` in `layout`, if it can be passed to
+/// BLAS with that layout.
+#[cfg(all(feature = "blas", feature = "std"))]
+fn blas_ld<A, S>(a: &ArrayBase<S, Ix2>, layout: CBLAS_LAYOUT) -> Option<blas_index>
+where
+    S: Data,
+    A: 'static,
+    S::Elem: 'static,
+{
+    if !same_type::<A, S::Elem>() {
+        return None;
+    }
+    let (m, n) = a.dim();
+    let (order, stride, len) = match layout {
+        CBLAS_LAYOUT::CblasRowMajor => (MemoryOrder::C, a.strides()[0], n),
+        CBLAS_LAYOUT::CblasColMajor => (MemoryOrder::F, a.strides()[1], m),
+    };
+    if !is_blas_2d(&a.dim, &a.strides, order) {
+        return None;
+    }
+    // The stride may be arbitrary if the matrix has a single row or column.
+    Some(stride.max(len as isize) as blas_index)
+}
+
+/// Return the layout and leading dimension of `a`, if it can be passed to
+/// BLAS.
+#[cfg(all(feature = "blas", feature = "std"))]
+fn blas_2d_params<A, S>(a: &ArrayBase<S, Ix2>) -> Option<(CBLAS_LAYOUT, blas_index)>
+where
+    S: Data,
+    A: 'static,
+    S::Elem: 'static,
+{
+    let layout = blas_layout::<A, _>(a)?;
+    let ld = blas_ld::<A, _>(a, layout)?;
+    Some((layout, ld))
+}
+
+#[cfg(all(feature = "blas", feature = "std"))]
+fn blas_uplo(uplo: Triangle) -> CBLAS_UPLO {
+    match uplo {
+        Triangle::Upper => CBLAS_UPLO::CblasUpper,
+        Triangle::Lower => CBLAS_UPLO::CblasLower,
+    }
+}
+
 #[cfg(feature = "blas")]
 fn blas_layout<A, S>(a: &ArrayBase<S, Ix2>) -> Option<CBLAS_LAYOUT>
 where
@@ -0,0 +1,474 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, One, Zero};
+
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Zip};
+
+use super::error::LinalgError;
+use super::{general_mat_vec_mul, Scalar};
+
+/// A linear map of vectors, the operand of the iterative solvers [`cg`],
+/// [`bicgstab`] and [`gmres`].
+///
+/// The solvers only need to compute products of the operator with vectors,
+/// so it doesn't have to be stored as a matrix. It is implemented by:
+///
+/// - two-dimensional arrays, with [`general_mat_vec_mul`],
+/// - [`CsrMat`](crate::sparse::CsrMat) sparse matrices,
+/// - closures `Fn(ArrayView1<A>) -> Array1<A>` returning the product with
+///   their argument.
+pub trait LinearOperator<A> {
+    /// Compute the product `y = A x`.
+    ///
+    /// **Panics** if the lengths of `x` and `y` don't match the operator.
+    fn apply(&self, x: ArrayView1<'_, A>, y: ArrayViewMut1<'_, A>);
+}
+
+impl<A, S> LinearOperator<A> for ArrayBase<S, Ix2>
+where
+    A: LinalgScalar,
+    S: Data<Elem = A>,
+{
+    fn apply(&self, x: ArrayView1<'_, A>, mut y: ArrayViewMut1<'_, A>) {
+        general_mat_vec_mul(A::one(), self, &x, A::zero(), &mut y);
+    }
+}
+
+impl<A, F> LinearOperator<A> for F
+where
+    A: Clone,
+    F: Fn(ArrayView1<'_, A>) -> Array1<A>,
+{
+    fn apply(&self, x: ArrayView1<'_, A>, mut y: ArrayViewMut1<'_, A>) {
+        let n = x.len();
+        let ax = self(x);
+        if ax.len() != y.len() {
+            panic!(
+                "ndarray: linear operator returned a vector of length {} for an input of \
+                 length {}, expected {}",
+                ax.len(),
+                n,
+                y.len()
+            );
+        }
+        y.assign(&ax);
+    }
+}
+
+/// Options of the iterative solvers [`cg`], [`bicgstab`] and [`gmres`].
+///
+/// The iteration stops when the norm of the residual `‖b - A x‖` is at most
+/// `max(rtol ‖b‖, atol)`.
+///
+/// ```
+/// use ndarray::linalg::IterOptions;
+///
+/// let options = IterOptions::<f64> { rtol: 1e-10, max_iter: 50, ..Default::default() };
+/// ```
+#[derive(Clone, Copy)]
+pub struct IterOptions<'a, A: Scalar> {
+    /// The tolerance relative to the norm of the right hand side; the default
+    /// is the square root of the machine epsilon.
+    pub rtol: A::Real,
+    /// The absolute tolerance; the default is zero.
+    pub atol: A::Real,
+    /// The maximum number of iterations; the default is 1000.
+    pub max_iter: usize,
+    /// The number of iterations of [`gmres`] between restarts; the default
+    /// is 30.
+    pub restart: usize,
+    /// An optional preconditioner `M`, an approximation of `A⁻¹` which is
+    /// cheap to apply; the default is none.
+    ///
+    /// [`cg`] needs it to be Hermitian positive definite.
+    pub preconditioner: Option<&'a dyn LinearOperator<A>>,
+}
+
+impl<A: Scalar> Default for IterOptions<'_, A> {
+    fn default() -> Self {
+        IterOptions {
+            rtol: A::Real::epsilon().sqrt(),
+            atol: A::Real::zero(),
+            max_iter: 1000,
+            restart: 30,
+            preconditioner: None,
+        }
+    }
+}
+
+/// The result of an iterative solver.
+#[derive(Clone, Debug)]
+pub struct IterSolution<A: Scalar> {
+    /// The solution `x`.
+    pub x: Array1<A>,
+    /// The number of iterations.
+    pub iterations: usize,
+    /// The norm of the residual `‖b - A x‖` initially and after each
+    /// iteration. For [`gmres`], the norms within a restart cycle are the
+    /// estimates maintained by the method.
+    pub residuals: Vec<A::Real>,
+}
+
+/// The state shared by the solvers.
+struct Solver<'a, 'o, A: Scalar, Op: ?Sized> {
+    op: &'a Op,
+    options: &'a IterOptions<'o, A>,
+    tol: A::Real,
+    residuals: Vec<A::Real>,
+}
+
+impl<'a, 'o, A, Op> Solver<'a, 'o, A, Op>
+where
+    A: Scalar,
+    Op: LinearOperator<A> + ?Sized,
+{
+    fn new<S>(op: &'a Op, b: &ArrayBase<S, Ix1>, options: &'a IterOptions<'o, A>) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        let tol = Float::max(options.rtol * norm(b), options.atol);
+        Solver {
+            op,
+            options,
+            tol,
+            residuals: Vec::new(),
+        }
+    }
+
+    /// Return `A x`.
+    fn apply(&self, x: &Array1<A>) -> Array1<A> {
+        let mut y = Array1::zeros(x.len());
+        self.op.apply(x.view(), y.view_mut());
+        y
+    }
+
+    /// Return `M x`, or `x` without a preconditioner.
+    fn precondition(&self, x: &Array1<A>) -> Array1<A> {
+        match self.options.preconditioner {
+            Some(m) => {
+                let mut y = Array1::zeros(x.len());
+                m.apply(x.view(), y.view_mut());
+                y
+            }
+            None => x.clone(),
+        }
+    }
+
+    /// Record the norm of the residual and return `true` if it is small
+    /// enough.
+    fn converged(&mut self, residual: A::Real) -> bool {
+        self.residuals.push(residual);
+        residual <= self.tol
+    }
+
+    fn solution(self, x: Array1<A>) -> Result<IterSolution<A>, LinalgError> {
+        Ok(IterSolution {
+            x,
+            iterations: self.residuals.len() - 1,
+            residuals: self.residuals,
+        })
+    }
+}
+
+/// Return the inner product `Σ conj(uᵢ) vᵢ`.
+fn dotc<A: Scalar>(u: &Array1<A>, v: &Array1<A>) -> A {
+    u.iter().zip(v).fold(A::zero(), |acc, (&u, &v)| acc + u.conj() * v)
+}
+
+/// Return the Euclidean norm.
+fn norm<A, S>(v: &ArrayBase<S, Ix1>) -> A::Real
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    v.iter().fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt()
+}
+
+/// Solve `A x = b` with the conjugate gradient method, where `A` is
+/// Hermitian positive definite.
+///
+/// Each iteration applies the operator once, and the preconditioner once if
+/// there is one.
+///
+/// **Errors** with [`LinalgError::NotPositiveDefinite`] if the method finds
+/// that `A` is not positive definite, and with [`LinalgError::NoConvergence`]
+/// if it doesn't converge within `options.max_iter` iterations.<br>
+/// **Panics** if the operator doesn't match the length of `b`.
+///
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// use ndarray::array;
+/// use ndarray::linalg::{cg, IterOptions};
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[4., 1.], [1., 3.]];
+/// let b = array![1., 2.];
+/// let solution = cg(&a, &b, &IterOptions::default()).unwrap();
+/// assert_abs_diff_eq!(a.dot(&solution.x), b, epsilon = 1e-7);
+/// // in exact arithmetic, the method converges in at most n iterations
+/// assert!(solution.iterations <= 2);
+/// # }
+/// ```
+pub fn cg<A, Op, S>(
+    op: &Op,
+    b: &ArrayBase<S, Ix1>,
+    options: &IterOptions<'_, A>,
+) -> Result<IterSolution<A>, LinalgError>
+where
+    A: Scalar,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    let mut solver = Solver::new(op, b, options);
+    let mut x = Array1::zeros(b.len());
+    let mut r = b.to_owned();
+    if solver.converged(norm(&r)) {
+        return solver.solution(x);
+    }
+    let mut z = solver.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dotc(&r, &z);
+    for _ in 0..options.max_iter {
+        let ap = solver.apply(&p);
+        let pap = dotc(&p, &ap);
+        if pap.re() <= A::Real::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let alpha = rz / pap;
+        x.scaled_add(alpha, &p);
+        r.scaled_add(-alpha, &ap);
+        if solver.converged(norm(&r)) {
+            return solver.solution(x);
+        }
+        z = solver.precondition(&r);
+        let rz_next = dotc(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p.zip_mut_with(&z, |p, &z| *p = z + beta * *p);
+    }
+    Err(LinalgError::NoConvergence)
+}
+
+/// Solve `A x = b` with the stabilized biconjugate gradient method, for a
+/// general square `A`.
+///
+/// Each iteration applies the operator twice, and the preconditioner twice if
+/// there is one.
+///
+/// **Errors** with [`LinalgError::NoConvergence`] if the method breaks down or
+/// doesn't converge within `options.max_iter` iterations.<br>
+/// **Panics** if the operator doesn't match the length of `b`.
+///
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// use ndarray::{array, ArrayView1};
+/// use ndarray::linalg::{bicgstab, IterOptions};
+/// use approx::assert_abs_diff_eq;
+///
+/// // the operator can be given as a closure
+/// let a = array![[3., 1., 0.], [-1., 4., 1.], [0., 2., 5.]];
+/// let op = |x: ArrayView1<f64>| a.dot(&x);
+/// let b = array![1., 2., 3.];
+/// let solution = bicgstab(&op, &b, &IterOptions::default()).unwrap();
+/// assert_abs_diff_eq!(a.dot(&solution.x), b, epsilon = 1e-7);
+/// # }
+/// ```
+pub fn bicgstab<A, Op, S>(
+    op: &Op,
+    b: &ArrayBase<S, Ix1>,
+    options: &IterOptions<'_, A>,
+) -> Result<IterSolution<A>, LinalgError>
+where
+    A: Scalar,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    let mut solver = Solver::new(op, b, options);
+    let n = b.len();
+    let mut x = Array1::zeros(n);
+    let mut r = b.to_owned();
+    if solver.converged(norm(&r)) {
+        return solver.solution(x);
+    }
+    let r0 = r.clone();
+    let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+    let mut p = Array1::zeros(n);
+    let mut v = Array1::zeros(n);
+    for _ in 0..options.max_iter {
+        let rho_next = dotc(&r0, &r);
+        if rho_next.is_zero() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        Zip::from(&mut p)
+            .and(&r)
+            .and(&v)
+            .for_each(|p, &r, &v| *p = r + beta * (*p - omega * v));
+        let p_hat = solver.precondition(&p);
+        v = solver.apply(&p_hat);
+        let r0v = dotc(&r0, &v);
+        if r0v.is_zero() {
+            break;
+        }
+        alpha = rho / r0v;
+        x.scaled_add(alpha, &p_hat);
+        // r is now the intermediate residual s = r - α v
+        r.scaled_add(-alpha, &v);
+        let s_norm = norm(&r);
+        if s_norm <= solver.tol {
+            solver.converged(s_norm);
+            return solver.solution(x);
+        }
+        let s_hat = solver.precondition(&r);
+        let t = solver.apply(&s_hat);
+        let tt = dotc(&t, &t);
+        omega = if tt.is_zero() { A::zero() } else { dotc(&t, &r) / tt };
+        x.scaled_add(omega, &s_hat);
+        r.scaled_add(-omega, &t);
+        if solver.converged(norm(&r)) {
+            return solver.solution(x);
+        }
+        if omega.is_zero() {
+            break;
+        }
+    }
+    Err(LinalgError::NoConvergence)
+}
+
+/// Solve `A x = b` with the generalized minimal residual method, for a
+/// general square `A`.
+///
+/// The method is restarted every `options.restart` iterations to bound its
+/// memory use, which is `options.restart + 1` vectors of the length of `b`.
+/// A preconditioner is applied on the right, so that the residual minimized
+/// within a cycle is the one of the original system. Each iteration applies
+/// the operator once, and the preconditioner once if there is one.
+///
+/// **Errors** with [`LinalgError::NoConvergence`] if the method doesn't
+/// converge within `options.max_iter` iterations.<br>
+/// **Panics** if the operator doesn't match the length of `b`, or if
+/// `options.restart` is zero.
+///
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// use ndarray::array;
+/// use ndarray::linalg::{gmres, IterOptions};
+/// use approx::assert_abs_diff_eq;
+///
+/// let a = array![[2., -1., 0.], [1., 2., -1.], [0., 1., 2.]];
+/// let b = array![1., 0., 1.];
+/// let solution = gmres(&a, &b, &IterOptions::default()).unwrap();
+/// assert_abs_diff_eq!(a.dot(&solution.x), b, epsilon = 1e-7);
+/// // the residual norm decreases monotonically
+/// assert!(solution.residuals.windows(2).all(|r| r[1] <= r[0]));
+/// # }
+/// ```
+pub fn gmres<A, Op, S>(
+    op: &Op,
+    b: &ArrayBase<S, Ix1>,
+    options: &IterOptions<'_, A>,
+) -> Result<IterSolution<A>, LinalgError>
+where
+    A: Scalar,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    assert!(options.restart > 0, "ndarray: gmres restart length must be positive");
+    let mut solver = Solver::new(op, b, options);
+    let n = b.len();
+    let m = options.restart;
+    let mut x = Array1::zeros(n);
+    let mut r = b.to_owned();
+    let mut beta = norm(&r);
+    if solver.converged(beta) {
+        return solver.solution(x);
+    }
+    let mut iterations = 0;
+    while iterations < options.max_iter {
+        // Arnoldi process on the Krylov space of A M and r, with the Hessenberg
+        // matrix reduced to upper triangular form by Givens rotations as it is
+        // built.
+        let mut basis = Vec::with_capacity(m + 1);
+        basis.push(r.mapv(|x| x / A::from_real(beta)));
+        let mut h = Array2::zeros((m + 1, m));
+        let mut rotations: Vec<(A::Real, A)> = Vec::with_capacity(m);
+        let mut g = Array1::zeros(m + 1);
+        g[0] = A::from_real(beta);
+        let mut k = 0;
+        while k < m && iterations < options.max_iter {
+            let mut w = solver.apply(&solver.precondition(&basis[k]));
+            for (i, v) in basis.iter().enumerate() {
+                let hik = dotc(v, &w);
+                h[[i, k]] = hik;
+                w.scaled_add(-hik, v);
+            }
+            let w_norm = norm(&w);
+            h[[k + 1, k]] = A::from_real(w_norm);
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (hi, hj) = (h[[i, k]], h[[i + 1, k]]);
+                h[[i, k]] = A::from_real(c) * hi + s * hj;
+                h[[i + 1, k]] = A::from_real(c) * hj - s.conj() * hi;
+            }
+            let (c, s, diag) = givens(h[[k, k]], w_norm);
+            h[[k, k]] = diag;
+            h[[k + 1, k]] = A::zero();
+            rotations.push((c, s));
+            g[k + 1] = -s.conj() * g[k];
+            g[k] = A::from_real(c) * g[k];
+            k += 1;
+            iterations += 1;
+            if solver.converged(g[k].abs()) || w_norm.is_zero() {
+                break;
+            }
+            basis.push(w.mapv(|x| x / A::from_real(w_norm)));
+        }
+
+        // Solve the triangular system H y = g by back substitution, and update
+        // x with M V y.
+        let mut y = g.slice(s![..k]).to_owned();
+        for i in (0..k).rev() {
+            let sum = (i + 1..k).fold(y[i], |acc, j| acc - h[[i, j]] * y[j]);
+            if h[[i, i]].is_zero() {
+                return Err(LinalgError::NoConvergence);
+            }
+            y[i] = sum / h[[i, i]];
+        }
+        let mut update = Array1::zeros(n);
+        for (v, &yi) in basis.iter().zip(&y) {
+            update.scaled_add(yi, v);
+        }
+        x.scaled_add(A::one(), &solver.precondition(&update));
+
+        r = b.to_owned();
+        r.scaled_add(-A::one(), &solver.apply(&x));
+        beta = norm(&r);
+        if beta <= solver.tol {
+            return solver.solution(x);
+        }
+    }
+    Err(LinalgError::NoConvergence)
+}
+
+/// Return the Givens rotation `(c, s)` which maps `(a, b)` to `(d, 0)` by
+/// `d = c a + s b` and `0 = c b - conj(s) a`, and `d`.
+fn givens<A: Scalar>(a: A, b: A::Real) -> (A::Real, A, A) {
+    let a_abs = a.abs();
+    if b.is_zero() {
+        (A::Real::one(), A::zero(), a)
+    } else if a_abs.is_zero() {
+        (A::Real::zero(), A::one(), A::from_real(b))
+    } else {
+        let r = a_abs.hypot(b);
+        let phase = a / A::from_real(a_abs);
+        (a_abs / r, phase * A::from_real(b / r), phase * A::from_real(r))
+    }
+}
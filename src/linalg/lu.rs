@@ -0,0 +1,322 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, Zero};
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_mul;
+
+use super::error::{check_square, LinalgError};
+use super::triangular::{solve_lower_inplace, solve_upper_inplace, swap_rows};
+use super::Scalar;
+
+/// Number of columns factorized at a time before the trailing submatrix is
+/// updated with a matrix multiplication.
+const LU_BLOCK: usize = 64;
+
+/// LU decomposition with partial pivoting of a square matrix, `P A = L U`.
+///
+/// `L` is unit lower triangular, `U` is upper triangular and `P` is a
+/// permutation matrix. Create it with [`ArrayBase::lu`].
+///
+/// The factorization of a singular matrix succeeds, but has a zero on the
+/// diagonal of `U`; solving with it returns [`LinalgError::Singular`].
+#[derive(Clone, Debug)]
+pub struct LU<A> {
+    /// `L` below the diagonal and `U` on and above it
+    lu: Array2<A>,
+    /// Row `k` was swapped with row `pivots[k]` in step `k`
+    pivots: Vec<usize>,
+    /// Whether the number of row swaps is odd
+    odd_swaps: bool,
+}
+
+impl<A: Scalar> LU<A> {
+    fn factorize(mut lu: Array2<A>) -> Self {
+        let n = lu.nrows();
+        let mut pivots = Vec::with_capacity(n);
+        let mut odd_swaps = false;
+        let mut j0 = 0;
+        while j0 < n {
+            let j1 = n.min(j0 + LU_BLOCK);
+
+            // Unblocked factorization of the panel of columns j0..j1.
+            for k in j0..j1 {
+                let p = k + argmax_abs(&lu.slice(s![k.., k]));
+                pivots.push(p);
+                if p != k {
+                    swap_rows(&mut lu.slice_mut(s![.., j0..j1]), k, p);
+                    odd_swaps = !odd_swaps;
+                }
+                let pivot = lu[[k, k]];
+                if pivot.is_zero() {
+                    continue;
+                }
+                let (pivot_row, mut below) = lu.slice_mut(s![k.., k..j1]).split_at(Axis(0), 1);
+                let pivot_row = pivot_row.index_axis_move(Axis(0), 0);
+                for mut row in below.outer_iter_mut() {
+                    let l = row[0] / pivot;
+                    row[0] = l;
+                    row.slice_mut(s![1..]).scaled_add(-l, &pivot_row.slice(s![1..]));
+                }
+            }
+
+            // Apply the panel's row swaps to the columns outside of the panel.
+            for (k, &p) in pivots.iter().enumerate().skip(j0) {
+                swap_rows(&mut lu.slice_mut(s![.., ..j0]), k, p);
+                swap_rows(&mut lu.slice_mut(s![.., j1..]), k, p);
+            }
+
+            if j1 < n {
+                let (top, bottom) = lu.view_mut().split_at(Axis(0), j1);
+                let (top_left, top_right) = top.split_at(Axis(1), j1);
+                let (bottom_left, mut a22) = bottom.split_at(Axis(1), j1);
+                // U₁₂ ← L₁₁⁻¹ A₁₂
+                let mut u12 = top_right.slice_move(s![j0.., ..]);
+                solve_lower_inplace(&top_left.slice(s![j0.., j0..]), &mut u12, true);
+                // A₂₂ ← A₂₂ - L₂₁ U₁₂
+                let l21 = bottom_left.slice_move(s![.., j0..]);
+                general_mat_mul(-A::one(), &l21, &u12, A::one(), &mut a22);
+            }
+            j0 = j1;
+        }
+        LU { lu, pivots, odd_swaps }
+    }
+
+    /// Return the unit lower triangular factor `L`.
+    pub fn l(&self) -> Array2<A> {
+        let n = self.lu.nrows();
+        Array2::from_shape_fn((n, n), |(i, j)| {
+            if i > j {
+                self.lu[[i, j]]
+            } else if i == j {
+                A::one()
+            } else {
+                A::zero()
+            }
+        })
+    }
+
+    /// Return the upper triangular factor `U`.
+    pub fn u(&self) -> Array2<A> {
+        let n = self.lu.nrows();
+        Array2::from_shape_fn((n, n), |(i, j)| if i <= j { self.lu[[i, j]] } else { A::zero() })
+    }
+
+    /// Return the row permutation as a vector `perm`, such that row `i` of
+    /// `P A` is row `perm[i]` of `A`.
+    pub fn permutation(&self) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..self.pivots.len()).collect();
+        for (k, &p) in self.pivots.iter().enumerate() {
+            perm.swap(k, p);
+        }
+        perm
+    }
+
+    /// Return `true` if the matrix is singular, which is when `U` has a zero
+    /// on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        self.lu.diag().iter().any(|x| x.is_zero())
+    }
+
+    /// Solve the system `A x = b`, where `b` is either a vector or a matrix
+    /// with one right hand side per column.
+    ///
+    /// **Errors** if `A` is singular or if the first axis of `b` doesn't
+    /// match `A`.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let n = self.lu.nrows();
+        if b.ndim() == 0 || b.ndim() > 2 || b.len_of(Axis(0)) != n {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let mut x = b.as_standard_layout().into_owned();
+        let k = x.len().checked_div(n).unwrap_or(0);
+        let mut x2 = x
+            .view_mut()
+            .into_shape((n, k))
+            .expect("standard layout array can be reshaped");
+        self.solve_inplace(&mut x2);
+        Ok(x)
+    }
+
+    fn solve_inplace(&self, x: &mut ArrayViewMut2<'_, A>) {
+        for (k, &p) in self.pivots.iter().enumerate() {
+            swap_rows(x, k, p);
+        }
+        solve_lower_inplace(&self.lu.view(), x, true);
+        solve_upper_inplace(&self.lu.view(), x, false);
+    }
+
+    /// Return the inverse of the matrix.
+    ///
+    /// **Errors** if `A` is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let mut x = Array2::eye(self.lu.nrows());
+        self.solve_inplace(&mut x.view_mut());
+        Ok(x)
+    }
+
+    /// Return the determinant of the matrix.
+    pub fn det(&self) -> A {
+        let det = self.lu.diag().iter().fold(A::one(), |acc, &x| acc * x);
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Return the sign and the natural logarithm of the absolute value of the
+    /// determinant, `(sign, logabsdet)`, such that `det = sign * exp(logabsdet)`.
+    ///
+    /// The sign is a number of modulus one (`±1` for real matrices). This
+    /// doesn't overflow or underflow for large matrices, unlike
+    /// [`.det()`](Self::det). For a singular matrix the result is
+    /// `(0, -∞)`.
+    pub fn slogdet(&self) -> (A, A::Real) {
+        let mut sign = if self.odd_swaps { -A::one() } else { A::one() };
+        let mut logabsdet = A::Real::zero();
+        for &x in self.lu.diag() {
+            let abs = x.abs();
+            if abs.is_zero() {
+                return (A::zero(), A::Real::neg_infinity());
+            }
+            sign = sign * (x / A::from_real(abs));
+            logabsdet += abs.ln();
+        }
+        (sign, logabsdet)
+    }
+}
+
+/// Return the index of the element with the largest modulus, or zero if the
+/// vector is empty.
+fn argmax_abs<A: Scalar>(v: &ArrayView1<'_, A>) -> usize {
+    let mut best = 0;
+    let mut best_abs = A::Real::neg_infinity();
+    for (i, &x) in v.iter().enumerate() {
+        let abs = x.abs();
+        if abs > best_abs {
+            best = i;
+            best_abs = abs;
+        }
+    }
+    best
+}
+
+/// # LU Decomposition and Linear Systems
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Compute the LU decomposition with partial pivoting of the square
+    /// matrix.
+    ///
+    /// The factorization is blocked, with the trailing submatrix updates done
+    /// by [`general_mat_mul`], so it benefits from the same optimizations
+    /// as matrix multiplication.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::{array, Axis};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let lu = a.lu().unwrap();
+    /// assert_eq!(lu.permutation(), vec![1, 0]);
+    /// assert_abs_diff_eq!(lu.l().dot(&lu.u()), a.select(Axis(0), &[1, 0]), epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn lu(&self) -> Result<LU<A>, LinalgError> {
+        check_square(self.dim())?;
+        Ok(LU::factorize(self.to_owned()))
+    }
+
+    /// Solve the linear system `A x = b`, where `b` is either a vector or a
+    /// matrix with one right hand side per column.
+    ///
+    /// To solve several systems with the same matrix, compute
+    /// [`.lu()`](Self::lu) once and use [`LU::solve`].
+    ///
+    /// **Errors** if the matrix is not square, if it is singular, or if the
+    /// first axis of `b` doesn't match it.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[3., 1.], [1., 2.]];
+    /// let b = array![9., 8.];
+    /// assert_abs_diff_eq!(a.solve(&b).unwrap(), array![2., 3.], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn solve<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.lu()?.solve(b)
+    }
+
+    /// Return the inverse of the matrix.
+    ///
+    /// **Errors** if the matrix is not square or if it is singular.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[4., 7.], [2., 6.]];
+    /// assert_abs_diff_eq!(a.inv().unwrap(), array![[0.6, -0.7], [-0.2, 0.4]], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.lu()?.inv()
+    }
+
+    /// Return the determinant of the matrix.
+    ///
+    /// **Errors** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// assert_abs_diff_eq!(a.det().unwrap(), -2., epsilon = 1e-12);
+    /// ```
+    pub fn det(&self) -> Result<A, LinalgError> {
+        Ok(self.lu()?.det())
+    }
+
+    /// Return the sign and the natural logarithm of the absolute value of the
+    /// determinant; see [`LU::slogdet`].
+    ///
+    /// **Errors** if the matrix is not square.
+    pub fn slogdet(&self) -> Result<(A, A::Real), LinalgError> {
+        Ok(self.lu()?.slogdet())
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::broadcast::co_broadcast;
+use crate::imp_prelude::*;
+use crate::iter::{ExactChunks, ExactChunksMut};
+use crate::linalg::general_mat_mul;
+use crate::{LinalgScalar, Zip};
+
+/// The operands and the result of [`ArrayBase::matmul`].
+pub(crate) struct MatMulOperands<'a, 'b, A> {
+    /// Shape batch₁ × *M* × *K*
+    a: ArrayViewD<'a, A>,
+    /// Shape batch₂ × *K* × *N*
+    b: ArrayViewD<'b, A>,
+    /// Shape broadcast(batch₁, batch₂) × *M* × *N*
+    c: ArrayD<A>,
+    /// The shape of the result, without the promoted axes of 1-D operands
+    shape: IxDyn,
+}
+
+/// Batches of operands and results, as produced by [`MatMulOperands::zip`].
+pub(crate) type MatMulZip<'s, A> = Zip<
+    (
+        ExactChunks<'s, A, IxDyn>,
+        ExactChunks<'s, A, IxDyn>,
+        ExactChunksMut<'s, A, IxDyn>,
+    ),
+    IxDyn,
+>;
+
+impl<'a, 'b, A: LinalgScalar> MatMulOperands<'a, 'b, A> {
+    /// **Panics** if an operand is zero-dimensional, or if the shapes are
+    /// not compatible.
+    pub(crate) fn new(mut a: ArrayViewD<'a, A>, mut b: ArrayViewD<'b, A>) -> Self {
+        let (a_ndim, b_ndim) = (a.ndim(), b.ndim());
+        if a_ndim == 0 || b_ndim == 0 {
+            panic!("ndarray: matmul is not defined for zero-dimensional operands");
+        }
+        if a_ndim == 1 {
+            a.insert_axis_inplace(Axis(0));
+        }
+        if b_ndim == 1 {
+            b.insert_axis_inplace(Axis(1));
+        }
+        let (na, nb) = (a.ndim(), b.ndim());
+        let (m, k) = (a.shape()[na - 2], a.shape()[na - 1]);
+        let (k2, n) = (b.shape()[nb - 2], b.shape()[nb - 1]);
+        let batch: IxDyn = co_broadcast(&IxDyn(&a.shape()[..na - 2]), &IxDyn(&b.shape()[..nb - 2]))
+            .ok()
+            .filter(|_| k == k2)
+            .unwrap_or_else(|| matmul_shape_error(a.shape(), b.shape()));
+
+        let mut shape = batch.slice().to_vec();
+        shape.extend_from_slice(&[m, n]);
+        let c = ArrayD::zeros(shape.clone());
+        if b_ndim == 1 {
+            shape.pop();
+        }
+        if a_ndim == 1 {
+            shape.remove(shape.len() - 1 - (b_ndim > 1) as usize);
+        }
+        MatMulOperands { a, b, c, shape: IxDyn(&shape) }
+    }
+
+    /// Return whether there are no matrix products to compute, because the
+    /// result is empty or the inner dimension *K* is zero.
+    pub(crate) fn is_trivial(&self) -> bool {
+        self.a.is_empty() || self.b.is_empty() || self.c.is_empty()
+    }
+
+    /// Return a `Zip` of each batch of the operands, broadcast together, and
+    /// the result; each item is a batch with its leading axes of length one.
+    ///
+    /// Must not be called if the operands are trivial.
+    pub(crate) fn zip(&mut self) -> MatMulZip<'_, A> {
+        let batch = &self.c.shape()[..self.c.ndim() - 2];
+        let shape_and_chunk = |op: &[usize]| {
+            let matrix = &op[op.len() - 2..];
+            let shape = IxDyn(&[batch, matrix].concat());
+            let chunk = IxDyn(&[&vec![1; batch.len()][..], matrix].concat());
+            (shape, chunk)
+        };
+        let (sa, ca) = shape_and_chunk(self.a.shape());
+        let (sb, cb) = shape_and_chunk(self.b.shape());
+        let (_, cc) = shape_and_chunk(self.c.shape());
+        let a = self.a.broadcast(sa).unwrap();
+        let b = self.b.broadcast(sb).unwrap();
+        Zip::from(ExactChunks::new(a, ca))
+            .and(ExactChunks::new(b, cb))
+            .and(ExactChunksMut::new(self.c.view_mut(), cc))
+    }
+
+    /// Return the result, with the shape it has in NumPy.
+    pub(crate) fn into_output(self) -> ArrayD<A> {
+        self.c
+            .into_shape(self.shape)
+            .expect("result has standard layout")
+    }
+}
+
+/// Compute the matrix product of one batch; see [`MatMulOperands::zip`].
+pub(crate) fn mat_mul_batch<A>(a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, c: ArrayViewMutD<'_, A>)
+where
+    A: LinalgScalar,
+{
+    fn matrix<S: RawData>(mut a: ArrayBase<S, IxDyn>) -> ArrayBase<S, Ix2> {
+        while a.ndim() > 2 {
+            a = a.remove_axis(Axis(0));
+        }
+        a.into_dimensionality().unwrap()
+    }
+    general_mat_mul(A::one(), &matrix(a), &matrix(b), A::zero(), &mut matrix(c));
+}
+
+#[cold]
+#[inline(never)]
+fn matmul_shape_error(a: &[usize], b: &[usize]) -> ! {
+    panic!(
+        "ndarray: inputs of shape {:?} and {:?} are not compatible for matrix multiplication",
+        a, b
+    );
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Perform matrix multiplication of `self` and `rhs` with the semantics of
+    /// the `@` operator of NumPy.
+    ///
+    /// - If both arrays are at least two-dimensional, they are treated as
+    ///   stacks of matrices in the last two axes. The leading batch axes are
+    ///   broadcast together, and the matrices are multiplied batch by batch.
+    /// - A one-dimensional `self` is promoted to a matrix by prepending an
+    ///   axis of length one, and a one-dimensional `rhs` by appending one;
+    ///   the promoted axes are removed from the result.
+    ///
+    /// Return the result with dynamic dimensionality; if `self` has shape
+    /// (batch₁, *M*, *K*) and `rhs` has shape (batch₂, *K*, *N*), the result
+    /// has the shape (broadcast(batch₁, batch₂), *M*, *N*). Each product is
+    /// computed with [`general_mat_mul`], and so with BLAS if it's enabled.
+    /// See [`.par_matmul()`](Self::par_matmul) for a parallel version.
+    ///
+    /// **Panics** if an operand is zero-dimensional, if the batch axes can't
+    /// be broadcast together or the inner dimensions don't match, or if the
+    /// number of elements in the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((2, 2, 3), |(b, i, j)| (b * 6 + i * 3 + j) as f64);
+    /// let b = array![[1., 0.], [0., 1.], [1., 1.]];
+    ///
+    /// // a stack of two 2 × 3 matrices times one 3 × 2 matrix
+    /// let c = a.matmul(&b);
+    /// assert_eq!(c.shape(), &[2, 2, 2]);
+    /// assert_eq!(c.index_axis(Axis(0), 1), a.index_axis(Axis(0), 1).dot(&b).into_dyn());
+    ///
+    /// // a 1-D operand is a row or column vector
+    /// assert_eq!(array![1., 2., 3.].matmul(&b), array![4., 5.].into_dyn());
+    /// assert_eq!(a.matmul(&array![1., 1., 1.]).shape(), &[2, 2]);
+    /// ```
+    pub fn matmul<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> ArrayD<A>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+        A: LinalgScalar,
+    {
+        let mut operands = MatMulOperands::new(self.view().into_dyn(), rhs.view().into_dyn());
+        if !operands.is_trivial() {
+            operands.zip().for_each(mat_mul_batch);
+        }
+        operands.into_output()
+    }
+}
@@ -8,9 +8,53 @@
 
 //! Linear algebra.
 
+pub use self::backend::{CustomGemm, GemmBackend, GemmFallback, GemmFn, GemmStats};
+#[cfg(feature = "std")]
+pub use self::backend::{
+    gemm_backend, gemm_stats, reset_gemm_stats, set_gemm_backend, with_gemm_backend,
+};
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::Dot;
 pub use self::impl_linalg::kron;
+pub use self::impl_linalg::outer;
+#[cfg(feature = "rayon")]
+pub(crate) use self::matmul::{mat_mul_batch, MatMulOperands};
+pub use self::einsum::{einsum, tensordot};
+pub use self::error::LinalgError;
+#[cfg(feature = "std")]
+pub use self::iterative::{bicgstab, cg, gmres, IterOptions, IterSolution, LinearOperator};
+#[cfg(feature = "std")]
+pub use self::lu::LU;
+#[cfg(feature = "std")]
+pub use self::qr::QR;
+#[cfg(feature = "std")]
+pub use self::scalar::Scalar;
+#[cfg(feature = "std")]
+pub use self::svd::MatrixNorm;
+#[cfg(feature = "std")]
+pub use self::triangular::{Diag, Side, Transpose, Triangle};
 
 mod impl_linalg;
+mod backend;
+#[cfg(feature = "std")]
+mod cholesky;
+#[cfg(feature = "std")]
+mod eigh;
+mod einsum;
+mod error;
+#[cfg(feature = "std")]
+mod expm;
+#[cfg(feature = "std")]
+mod iterative;
+#[cfg(feature = "std")]
+mod lu;
+mod matmul;
+#[cfg(feature = "std")]
+mod qr;
+#[cfg(feature = "std")]
+mod scalar;
+#[cfg(feature = "std")]
+mod svd;
+#[cfg(feature = "std")]
+mod triangular;
@@ -0,0 +1,296 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, Zero};
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_vec_mul;
+
+use super::error::LinalgError;
+use super::triangular::{solve_lower_inplace, solve_upper_inplace};
+use super::Scalar;
+
+/// QR decomposition of an *M* × *N* matrix, `A = Q R`, computed with
+/// Householder reflections.
+///
+/// `Q` is unitary (orthogonal, for real numbers) and `R` is upper
+/// triangular. Create it with [`ArrayBase::qr`].
+#[derive(Clone, Debug)]
+pub struct QR<A> {
+    /// `R` on and above the diagonal, the Householder vectors below it
+    qr: Array2<A>,
+    /// The scalar factors of the Householder reflections
+    tau: Vec<A>,
+}
+
+impl<A: Scalar> QR<A> {
+    fn factorize(qr: Array2<A>) -> Self {
+        let k = qr.nrows().min(qr.ncols());
+        let mut this = QR { qr, tau: Vec::with_capacity(k) };
+        for j in 0..k {
+            let t = make_reflector(this.qr.slice_mut(s![j.., j]));
+            this.tau.push(t);
+            if !t.is_zero() {
+                let v = this.reflector(j);
+                apply_reflector(&v.view(), t.conj(), this.qr.slice_mut(s![j.., j + 1..]));
+            }
+        }
+        this
+    }
+
+    /// Return the Householder vector of reflection `j`, with an implicit one
+    /// as its first element.
+    fn reflector(&self, j: usize) -> Array1<A> {
+        let mut v = self.qr.slice(s![j.., j]).to_owned();
+        v[0] = A::one();
+        v
+    }
+
+    /// Apply `Qᴴ` to `c` from the left.
+    fn apply_q_adjoint(&self, mut c: ArrayViewMut2<'_, A>) {
+        for (j, &t) in self.tau.iter().enumerate() {
+            if !t.is_zero() {
+                let v = self.reflector(j);
+                apply_reflector(&v.view(), t.conj(), c.slice_mut(s![j.., ..]));
+            }
+        }
+    }
+
+    /// Apply `Q` to `c` from the left.
+    fn apply_q(&self, mut c: ArrayViewMut2<'_, A>) {
+        for (j, &t) in self.tau.iter().enumerate().rev() {
+            if !t.is_zero() {
+                let v = self.reflector(j);
+                apply_reflector(&v.view(), t, c.slice_mut(s![j.., ..]));
+            }
+        }
+    }
+
+    /// Return the *M* × *K* matrix `Q` of the thin decomposition, where *K*
+    /// is the smaller of *M* and *N*.
+    pub fn q(&self) -> Array2<A> {
+        let (m, n) = self.qr.dim();
+        let mut q = Array2::eye(m).slice_move(s![.., ..m.min(n)]);
+        self.apply_q(q.view_mut());
+        q
+    }
+
+    /// Return the *K* × *N* upper triangular `R` of the thin decomposition,
+    /// where *K* is the smaller of *M* and *N*.
+    pub fn r(&self) -> Array2<A> {
+        let (m, n) = self.qr.dim();
+        self.r_rows(m.min(n))
+    }
+
+    /// Return the *M* × *M* unitary matrix `Q` of the full decomposition.
+    pub fn q_full(&self) -> Array2<A> {
+        let mut q = Array2::eye(self.qr.nrows());
+        self.apply_q(q.view_mut());
+        q
+    }
+
+    /// Return the *M* × *N* upper triangular `R` of the full decomposition.
+    pub fn r_full(&self) -> Array2<A> {
+        self.r_rows(self.qr.nrows())
+    }
+
+    fn r_rows(&self, rows: usize) -> Array2<A> {
+        let n = self.qr.ncols();
+        Array2::from_shape_fn((rows, n), |(i, j)| {
+            if i <= j && i < self.qr.nrows() {
+                self.qr[[i, j]]
+            } else {
+                A::zero()
+            }
+        })
+    }
+
+    /// Solve the linear least squares problem `min ‖A x - b‖₂`, where `b` is
+    /// either a vector or a matrix with one right hand side per column.
+    ///
+    /// If *M* < *N*, the system is underdetermined and the solution with the
+    /// smallest norm is returned instead; this requires the decomposition of
+    /// `Aᴴ` rather than `A`, so use [`ArrayBase::lstsq`] for that case.
+    ///
+    /// **Errors** if the first axis of `b` doesn't have length *M*, or if
+    /// `A` doesn't have full rank (`R` has a zero on its diagonal).
+    pub fn lstsq<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let (m, n) = self.qr.dim();
+        if m < n {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (mut c, k) = rhs_matrix(b, m)?;
+        self.check_rank()?;
+        self.apply_q_adjoint(c.view_mut());
+        let mut x = c.slice_move(s![..n, ..]);
+        solve_upper_inplace(&self.qr.slice(s![..n, ..]), &mut x.view_mut(), false);
+        Ok(reshape_solution(x, b, n, k))
+    }
+
+    /// Return the minimum norm solution of `Aᴴ x = b` using this
+    /// decomposition of `A`, which must have at least as many rows as
+    /// columns.
+    fn lstsq_adjoint<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let (m, n) = self.qr.dim();
+        debug_assert!(m >= n);
+        let (y, k) = rhs_matrix(b, n)?;
+        self.check_rank()?;
+        // Aᴴ = Rᴴ Qᴴ, so solve Rᴴ y = b and return x = Q y.
+        let rh = self.qr.slice(s![..n, ..]).t().mapv(A::conj);
+        let mut x = Array2::zeros((m, k));
+        x.slice_mut(s![..n, ..]).assign(&y);
+        solve_lower_inplace(&rh.view(), &mut x.slice_mut(s![..n, ..]), false);
+        self.apply_q(x.view_mut());
+        Ok(reshape_solution(x, b, m, k))
+    }
+
+    fn check_rank(&self) -> Result<(), LinalgError> {
+        if self.qr.diag().iter().any(|x| x.is_zero()) {
+            Err(LinalgError::Singular)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Turn `x` into the reflector that maps it to a multiple of the first unit
+/// vector, `Hᴴ x = β e₁` with `H = I - τ v vᴴ`. Store `β` in `x[0]` and the
+/// Householder vector `v` (with an implicit first element of one) in
+/// `x[1..]`, and return `τ`.
+fn make_reflector<A: Scalar>(mut x: ArrayViewMut1<'_, A>) -> A {
+    let alpha = x[0];
+    let tail_sq = x.slice(s![1..]).fold(A::Real::zero(), |acc, &y| acc + y.abs_sqr());
+    if tail_sq.is_zero() && alpha.im().is_zero() {
+        return A::zero();
+    }
+    let norm = (alpha.abs_sqr() + tail_sq).sqrt();
+    let beta = if alpha.re() >= A::Real::zero() { -norm } else { norm };
+    let beta_a = A::from_real(beta);
+    let scale = alpha - beta_a;
+    x.slice_mut(s![1..]).mapv_inplace(|y| y / scale);
+    x[0] = beta_a;
+    (beta_a - alpha) / beta_a
+}
+
+/// Apply `H = I - τ v vᴴ` to `c` from the left.
+fn apply_reflector<A: Scalar>(v: &ArrayView1<'_, A>, tau: A, mut c: ArrayViewMut2<'_, A>) {
+    // w = cᵀ conj(v), so that wᵀ = vᴴ c
+    let v_conj = v.mapv(A::conj);
+    let mut w = Array1::zeros(c.ncols());
+    general_mat_vec_mul(A::one(), &c.t(), &v_conj, A::zero(), &mut w);
+    for (mut row, &vi) in c.outer_iter_mut().zip(v) {
+        row.scaled_add(-tau * vi, &w);
+    }
+}
+
+/// Copy `b` into a matrix with one right hand side per column, and return
+/// it with the number of right hand sides.
+fn rhs_matrix<A, S, D>(b: &ArrayBase<S, D>, rows: usize) -> Result<(Array2<A>, usize), LinalgError>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    if b.ndim() == 0 || b.ndim() > 2 || b.len_of(Axis(0)) != rows {
+        return Err(from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let k = if b.ndim() == 1 { 1 } else { b.len_of(Axis(1)) };
+    let c = Array2::from_shape_vec((rows, k), b.iter().cloned().collect())
+        .expect("b has rows × k elements");
+    Ok((c, k))
+}
+
+/// Reshape the solution `x` (with `rows` rows) to the dimensionality of `b`.
+fn reshape_solution<A, S, D>(x: Array2<A>, b: &ArrayBase<S, D>, rows: usize, k: usize) -> Array<A, D>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut dim = b.raw_dim();
+    dim[0] = rows;
+    if dim.ndim() == 2 {
+        dim[1] = k;
+    }
+    Array::from_shape_vec(dim, x.iter().cloned().collect()).expect("solution has the shape of b")
+}
+
+/// # QR Decomposition and Least Squares
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Compute the QR decomposition of the matrix with Householder
+    /// reflections.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[3., 1.], [4., 2.], [0., 1.]];
+    /// let qr = a.qr();
+    /// let (q, r) = (qr.q(), qr.r());
+    /// assert_eq!(q.dim(), (3, 2));
+    /// assert_eq!(r.dim(), (2, 2));
+    /// assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(q.t().dot(&q), ndarray::Array2::eye(2), epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn qr(&self) -> QR<A> {
+        QR::factorize(self.to_owned())
+    }
+
+    /// Solve the linear least squares problem `min ‖A x - b‖₂` using the QR
+    /// decomposition, where `b` is either a vector or a matrix with one right
+    /// hand side per column.
+    ///
+    /// If the system is underdetermined (fewer rows than columns), return
+    /// the solution with the smallest norm.
+    ///
+    /// **Errors** if the first axis of `b` doesn't match the rows of the
+    /// matrix, or if the matrix doesn't have full rank.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// // fit y = c₀ + c₁ t to three points
+    /// let a = array![[1., 0.], [1., 1.], [1., 2.]];
+    /// let y = array![1., 2., 4.];
+    /// let c = a.lstsq(&y).unwrap();
+    /// assert_abs_diff_eq!(c, array![5. / 6., 1.5], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn lstsq<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let (m, n) = self.dim();
+        if m >= n {
+            self.qr().lstsq(b)
+        } else {
+            self.t().mapv(A::conj).qr().lstsq_adjoint(b)
+        }
+    }
+}
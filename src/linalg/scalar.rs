@@ -0,0 +1,143 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+use num_traits::{Float, FromPrimitive};
+use std::fmt;
+use std::ops::Neg;
+
+use crate::{LinalgScalar, NdFloat, ScalarOperand};
+
+/// Element types supported by the matrix factorizations in
+/// [`linalg`](crate::linalg): `f32`, `f64`, `Complex<f32>` and `Complex<f64>`.
+///
+/// This trait can only be implemented by those four types.
+pub trait Scalar:
+    LinalgScalar + ScalarOperand + Neg<Output = Self> + fmt::Debug + Send + Sync
+{
+    /// The real type of the components of `Self`.
+    type Real: NdFloat + FromPrimitive;
+
+    /// Return the modulus (absolute value).
+    fn abs(self) -> Self::Real;
+
+    /// Return the squared modulus.
+    fn abs_sqr(self) -> Self::Real;
+
+    /// Return the complex conjugate; real numbers are returned unchanged.
+    fn conj(self) -> Self;
+
+    /// Return the real component.
+    fn re(self) -> Self::Real;
+
+    /// Return the imaginary component, which is zero for real numbers.
+    fn im(self) -> Self::Real;
+
+    /// Create a value from its real component.
+    fn from_real(re: Self::Real) -> Self;
+
+    /// Return the principal square root.
+    fn sqrt(self) -> Self;
+
+    private_decl! {}
+}
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl Scalar for $t {
+            type Real = $t;
+
+            #[inline]
+            fn abs(self) -> $t {
+                Float::abs(self)
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self * self
+            }
+
+            #[inline]
+            fn conj(self) -> $t {
+                self
+            }
+
+            #[inline]
+            fn re(self) -> $t {
+                self
+            }
+
+            #[inline]
+            fn im(self) -> $t {
+                0.
+            }
+
+            #[inline]
+            fn from_real(re: $t) -> $t {
+                re
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                Float::sqrt(self)
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+macro_rules! impl_complex {
+    ($t:ty) => {
+        impl Scalar for Complex<$t> {
+            type Real = $t;
+
+            #[inline]
+            fn abs(self) -> $t {
+                self.norm()
+            }
+
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self.norm_sqr()
+            }
+
+            #[inline]
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            #[inline]
+            fn re(self) -> $t {
+                self.re
+            }
+
+            #[inline]
+            fn im(self) -> $t {
+                self.im
+            }
+
+            #[inline]
+            fn from_real(re: $t) -> Self {
+                Complex::new(re, 0.)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                Complex::sqrt(self)
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+impl_complex!(f32);
+impl_complex!(f64);
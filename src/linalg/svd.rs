@@ -0,0 +1,294 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive, Zero};
+
+use crate::imp_prelude::*;
+
+use super::eigh::{sort_indices, Rotation, MAX_SWEEPS};
+use super::error::LinalgError;
+use super::Scalar;
+
+/// A matrix norm, for [`ArrayBase::matrix_norm`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatrixNorm {
+    /// The Frobenius norm, the square root of the sum of the squared moduli
+    /// of the elements.
+    Fro,
+    /// The nuclear norm, the sum of the singular values.
+    Nuc,
+    /// The largest sum of the moduli of the elements of a column.
+    One,
+    /// The largest sum of the moduli of the elements of a row.
+    Inf,
+    /// The spectral norm, the largest singular value.
+    Two,
+}
+
+/// The output of [`ArrayBase::svd`]: `(U, singular values, Vᴴ)`.
+type SvdOutput<A> = (Option<Array2<A>>, Array1<<A as Scalar>::Real>, Option<Array2<A>>);
+
+/// Compute the thin singular value decomposition of `a`, which must have at
+/// least as many rows as columns, with the one-sided Jacobi method.
+///
+/// Return `U` if `compute_u`, the singular values in descending order and
+/// `V` (not `Vᴴ`) if `compute_v`.
+fn jacobi_svd<A: Scalar>(
+    mut u: Array2<A>,
+    compute_u: bool,
+    compute_v: bool,
+) -> Result<SvdOutput<A>, LinalgError> {
+    let (m, n) = u.dim();
+    debug_assert!(m >= n);
+    let mut v = if compute_v { Some(Array2::eye(n)) } else { None };
+    let eps = A::Real::epsilon();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                // Orthogonalize columns p and q by diagonalizing their Gram
+                // matrix [[alpha, gamma], [conj(gamma), beta]].
+                let (up, uq) = (u.column(p), u.column(q));
+                let alpha = up.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+                let beta = uq.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr());
+                let gamma = up.iter().zip(uq).fold(A::zero(), |acc, (&x, &y)| acc + x.conj() * y);
+                if gamma.is_zero() || gamma.abs() <= eps * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let rot = Rotation::new(alpha, beta, gamma);
+                rot.apply_right(&mut u.view_mut(), p, q);
+                if let Some(v) = &mut v {
+                    rot.apply_right(&mut v.view_mut(), p, q);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence);
+    }
+
+    let sigma: Vec<A::Real> = u
+        .columns()
+        .into_iter()
+        .map(|col| col.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt())
+        .collect();
+    let order = sort_indices(&sigma, |x, y| y.partial_cmp(x));
+    let sigma: Array1<A::Real> = order.iter().map(|&i| sigma[i]).collect();
+    let u = if compute_u {
+        let mut u = u.select(Axis(1), &order);
+        for (mut col, &s) in u.columns_mut().into_iter().zip(&sigma) {
+            if !s.is_zero() {
+                let s = A::from_real(s);
+                col.mapv_inplace(|x| x / s);
+            }
+        }
+        let rank = sigma.iter().take_while(|s| !s.is_zero()).count();
+        complete_orthonormal(&mut u, rank);
+        Some(u)
+    } else {
+        None
+    };
+    Ok((u, sigma, v.map(|v| v.select(Axis(1), &order))))
+}
+
+/// Replace columns `rank..` of `u` with unit vectors orthogonal to all
+/// columns before them, given that the first `rank` columns are
+/// orthonormal.
+fn complete_orthonormal<A: Scalar>(u: &mut Array2<A>, rank: usize) {
+    let m = u.nrows();
+    for j in rank..u.ncols() {
+        // Orthogonalize the unit vector with the largest residual, which has
+        // a squared norm of at least 1 - j / m.
+        let mut best = Array1::zeros(m);
+        let mut best_norm = A::Real::zero();
+        for k in 0..m {
+            let mut x = Array1::zeros(m);
+            x[k] = A::one();
+            // twice is enough to orthogonalize in floating point
+            for _ in 0..2 {
+                for col in u.columns().into_iter().take(j) {
+                    let proj = col.iter().zip(&x).fold(A::zero(), |acc, (&c, &x)| acc + c.conj() * x);
+                    x.scaled_add(-proj, &col);
+                }
+            }
+            let norm = x.fold(A::Real::zero(), |acc, x| acc + x.abs_sqr()).sqrt();
+            if norm > best_norm {
+                best = x;
+                best_norm = norm;
+            }
+        }
+        let norm = A::from_real(best_norm);
+        u.column_mut(j).assign(&best.mapv(|x| x / norm));
+    }
+}
+
+/// # Singular Value Decomposition
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Compute the thin singular value decomposition `A = U Σ Vᴴ` of the
+    /// *M* × *N* matrix with the one-sided Jacobi method.
+    ///
+    /// Return `(u, s, vt)`, where `s` holds the *K* = min(*M*, *N*)
+    /// singular values in descending order, `u` is the *M* × *K* matrix of
+    /// left singular vectors if `compute_u` is true, and `vt` is the
+    /// *K* × *N* matrix `Vᴴ` of right singular vectors if `compute_vt` is
+    /// true. Both have orthonormal columns (respectively rows).
+    ///
+    /// **Errors** if the iteration doesn't converge, which can happen if the
+    /// matrix has non-finite elements.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[3., 0.], [4., 5.]];
+    /// let (u, s, vt) = a.svd(true, true).unwrap();
+    /// let (u, vt) = (u.unwrap(), vt.unwrap());
+    /// assert_abs_diff_eq!(s, array![45f64.sqrt(), 5f64.sqrt()], epsilon = 1e-12);
+    /// assert_abs_diff_eq!((&u * &s).dot(&vt), a, epsilon = 1e-12);
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn svd(
+        &self,
+        compute_u: bool,
+        compute_vt: bool,
+    ) -> Result<(Option<Array2<A>>, Array1<A::Real>, Option<Array2<A>>), LinalgError> {
+        let adjoint = |a: Array2<A>| a.reversed_axes().mapv_into(A::conj);
+        if self.nrows() >= self.ncols() {
+            let (u, s, v) = jacobi_svd(self.to_owned(), compute_u, compute_vt)?;
+            Ok((u, s, v.map(adjoint)))
+        } else {
+            // Aᴴ = U Σ Vᴴ, so A = V Σ Uᴴ
+            let (u, s, v) = jacobi_svd(self.t().mapv(A::conj), compute_vt, compute_u)?;
+            Ok((v, s, u.map(adjoint)))
+        }
+    }
+
+    /// Return the default tolerance below which singular values are treated
+    /// as zero, given the largest singular value.
+    fn rank_tolerance(&self, sigma_max: A::Real) -> A::Real {
+        let size = A::Real::from_usize(self.nrows().max(self.ncols())).unwrap();
+        size * A::Real::epsilon() * sigma_max
+    }
+
+    /// Return the Moore–Penrose pseudoinverse of the matrix, computed from
+    /// its singular value decomposition.
+    ///
+    /// Singular values smaller than `max(M, N) · ε · σ_max` are treated as
+    /// zero, where `ε` is the machine epsilon.
+    ///
+    /// **Errors** if the singular value decomposition fails.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[1., 2.], [2., 4.], [0., 0.]];
+    /// let p = a.pinv().unwrap();
+    /// assert_abs_diff_eq!(a.dot(&p).dot(&a), a, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(p, array![[0.04, 0.08, 0.], [0.08, 0.16, 0.]], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn pinv(&self) -> Result<Array2<A>, LinalgError> {
+        let (u, s, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let tol = self.rank_tolerance(s.first().cloned().unwrap_or_else(Zero::zero));
+        // A⁺ = V Σ⁺ Uᴴ
+        let mut v = vt.reversed_axes().mapv_into(A::conj);
+        for (mut col, &s) in v.columns_mut().into_iter().zip(&s) {
+            let inv = if s > tol { A::from_real(s.recip()) } else { A::zero() };
+            col.mapv_inplace(|x| x * inv);
+        }
+        Ok(v.dot(&u.reversed_axes().mapv_into(A::conj)))
+    }
+
+    /// Return the rank of the matrix, the number of singular values greater
+    /// than `max(M, N) · ε · σ_max`, where `ε` is the machine epsilon.
+    ///
+    /// **Errors** if the singular value decomposition fails.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[1., 2.], [2., 4.]].matrix_rank().unwrap(), 1);
+    /// assert_eq!(array![[1., 2.], [3., 4.]].matrix_rank().unwrap(), 2);
+    /// ```
+    pub fn matrix_rank(&self) -> Result<usize, LinalgError> {
+        let s = self.svd(false, false)?.1;
+        let tol = self.rank_tolerance(s.first().cloned().unwrap_or_else(Zero::zero));
+        Ok(s.iter().filter(|&&s| s > tol).count())
+    }
+
+    /// Return the norm `ord` of the matrix; see [`MatrixNorm`]. An empty
+    /// matrix has norm zero.
+    ///
+    /// **Errors** if a singular value decomposition is needed and fails.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::MatrixNorm;
+    ///
+    /// let a = array![[1., -2.], [3., 4.]];
+    /// assert_eq!(a.matrix_norm(MatrixNorm::One).unwrap(), 6.);
+    /// assert_eq!(a.matrix_norm(MatrixNorm::Inf).unwrap(), 7.);
+    /// assert_eq!(a.matrix_norm(MatrixNorm::Fro).unwrap(), 30f64.sqrt());
+    /// ```
+    pub fn matrix_norm(&self, ord: MatrixNorm) -> Result<A::Real, LinalgError> {
+        let zero = A::Real::zero();
+        let max_lane_sum = |axis| {
+            self.lanes(axis)
+                .into_iter()
+                .map(|lane| lane.fold(zero, |acc, x| acc + x.abs()))
+                .fold(zero, A::Real::max)
+        };
+        Ok(match ord {
+            MatrixNorm::Fro => self.fold(zero, |acc, x| acc + x.abs_sqr()).sqrt(),
+            MatrixNorm::Nuc => self.svd(false, false)?.1.sum(),
+            MatrixNorm::One => max_lane_sum(Axis(0)),
+            MatrixNorm::Inf => max_lane_sum(Axis(1)),
+            MatrixNorm::Two => self.svd(false, false)?.1.first().cloned().unwrap_or(zero),
+        })
+    }
+
+    /// Return the condition number of the matrix in the spectral norm, the
+    /// ratio of its largest to its smallest singular value.
+    ///
+    /// The condition number of a singular matrix is infinite.
+    ///
+    /// **Errors** if the singular value decomposition fails.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// assert_abs_diff_eq!(array![[2., 0.], [0., 0.5]].cond().unwrap(), 4., epsilon = 1e-12);
+    /// assert!(array![[1f64, 1.], [1., 1.]].cond().unwrap().is_infinite());
+    /// ```
+    pub fn cond(&self) -> Result<A::Real, LinalgError> {
+        let s = self.svd(false, false)?.1;
+        match (s.first(), s.last()) {
+            (Some(&max), Some(&min)) => Ok(max / min),
+            _ => Ok(A::Real::nan()),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_vec_mul;
+use crate::CowArray;
+
+use super::error::{check_square, LinalgError};
+use super::Scalar;
+
+/// Solve `L X = B` in place of `b`, where `L` is the lower triangle of `l`.
+///
+/// If `unit` is true, the diagonal of `l` is assumed to be all ones and is
+/// not read. The caller must check that the diagonal is nonzero otherwise.
+pub(crate) fn solve_lower_inplace<A: Scalar>(
+    l: &ArrayView2<'_, A>,
+    b: &mut ArrayViewMut2<'_, A>,
+    unit: bool,
+) {
+    let n = l.nrows();
+    debug_assert_eq!(b.nrows(), n);
+    for i in 0..n {
+        let (done, rest) = b.view_mut().split_at(Axis(0), i);
+        let mut row = rest.index_axis_move(Axis(0), 0);
+        // row ← row - Xᵀ[.., ..i] L[i, ..i]
+        general_mat_vec_mul(-A::one(), &done.t(), &l.slice(s![i, ..i]), A::one(), &mut row);
+        if !unit {
+            let d = l[[i, i]];
+            row.mapv_inplace(|x| x / d);
+        }
+    }
+}
+
+/// Solve `U X = B` in place of `b`, where `U` is the upper triangle of `u`.
+///
+/// If `unit` is true, the diagonal of `u` is assumed to be all ones and is
+/// not read. The caller must check that the diagonal is nonzero otherwise.
+pub(crate) fn solve_upper_inplace<A: Scalar>(
+    u: &ArrayView2<'_, A>,
+    b: &mut ArrayViewMut2<'_, A>,
+    unit: bool,
+) {
+    let n = u.nrows();
+    debug_assert_eq!(b.nrows(), n);
+    for i in (0..n).rev() {
+        let (rest, done) = b.view_mut().split_at(Axis(0), i + 1);
+        let mut row = rest.index_axis_move(Axis(0), i);
+        // row ← row - Xᵀ[i+1.., ..] U[i, i+1..]
+        general_mat_vec_mul(-A::one(), &done.t(), &u.slice(s![i, i + 1..]), A::one(), &mut row);
+        if !unit {
+            let d = u[[i, i]];
+            row.mapv_inplace(|x| x / d);
+        }
+    }
+}
+
+/// Swap rows `i` and `j` of `a`.
+pub(crate) fn swap_rows<A>(a: &mut ArrayViewMut2<'_, A>, i: usize, j: usize) {
+    if i != j {
+        let (ri, rj) = a.multi_slice_mut((s![i, ..], s![j, ..]));
+        crate::Zip::from(ri).and(rj).for_each(std::mem::swap);
+    }
+}
+
+/// Which triangle of a matrix is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Triangle {
+    /// The upper triangle, on and above the diagonal.
+    Upper,
+    /// The lower triangle, on and below the diagonal.
+    Lower,
+}
+
+impl Triangle {
+    pub(crate) fn flip(self) -> Self {
+        match self {
+            Triangle::Upper => Triangle::Lower,
+            Triangle::Lower => Triangle::Upper,
+        }
+    }
+}
+
+/// Which side of the unknown the triangular matrix is on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// Solve `op(A) X = B`.
+    Left,
+    /// Solve `X op(A) = B`.
+    Right,
+}
+
+/// The operation `op(A)` applied to a matrix before solving.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Transpose {
+    /// `op(A) = A`
+    No,
+    /// `op(A) = Aᵀ`
+    Transpose,
+    /// `op(A) = Aᴴ`, the conjugate transpose.
+    Adjoint,
+}
+
+/// Whether the diagonal of a triangular matrix is read or assumed to be
+/// all ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diag {
+    /// Use the diagonal of the matrix.
+    NonUnit,
+    /// Assume ones on the diagonal without reading it.
+    Unit,
+}
+
+/// # Triangular Systems
+///
+/// These methods require crate feature `std`.
+impl<A, S> ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    /// Solve a linear system with the triangular matrix `self`, where `b` is
+    /// either a vector or a matrix.
+    ///
+    /// Only the `uplo` triangle of `self` is read, and its diagonal is
+    /// ignored if `diag` is `Diag::Unit`. With `Side::Left` the system is
+    /// `op(A) X = B`, where each column of `B` is a right hand side; with
+    /// `Side::Right` it is `X op(A) = B`, where each row of `B` is a right
+    /// hand side. A vector `b` is treated as a single right hand side.
+    ///
+    /// **Errors** if the matrix is not square, if the shape of `b` doesn't
+    /// match, or if the diagonal has a zero and `diag` is `Diag::NonUnit`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::linalg::{Diag, Side, Transpose, Triangle};
+    ///
+    /// let l = array![[2., 0.], [1., 4.]];
+    /// let x = l.solve_triangular(&array![4., 10.], Side::Left, Triangle::Lower,
+    ///                            Transpose::No, Diag::NonUnit).unwrap();
+    /// assert_eq!(x, array![2., 2.]);
+    /// ```
+    pub fn solve_triangular<S2, D>(
+        &self,
+        b: &ArrayBase<S2, D>,
+        side: Side,
+        uplo: Triangle,
+        trans: Transpose,
+        diag: Diag,
+    ) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let n = check_square(self.dim())?;
+        let axis = match (side, b.ndim()) {
+            (_, 1) | (Side::Left, 2) => Axis(0),
+            (Side::Right, 2) => Axis(1),
+            _ => return Err(from_kind(ErrorKind::IncompatibleShape).into()),
+        };
+        if b.len_of(axis) != n {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if diag == Diag::NonUnit && self.diag().iter().any(|x| x.is_zero()) {
+            return Err(LinalgError::Singular);
+        }
+
+        // Reduce to solving T X = B with T = op(A) for the left side, and to
+        // Tᵀ Xᵀ = Bᵀ for the right side.
+        let (mut t, mut uplo) = match trans {
+            Transpose::No => (self.view(), uplo),
+            _ => (self.t(), uplo.flip()),
+        };
+        if side == Side::Right {
+            t = t.reversed_axes();
+            uplo = uplo.flip();
+        }
+        let conj = trans == Transpose::Adjoint;
+        let t = if conj { CowArray::from(t.mapv(A::conj)) } else { CowArray::from(t) };
+
+        let mut x = b.as_standard_layout().into_owned();
+        let k = x.len().checked_div(n).unwrap_or(0);
+        let shape = if axis == Axis(0) { (n, k) } else { (k, n) };
+        let mut x2 = x
+            .view_mut()
+            .into_shape(shape)
+            .expect("standard layout array can be reshaped");
+        if axis == Axis(1) {
+            x2 = x2.reversed_axes();
+        }
+        let unit = diag == Diag::Unit;
+        match uplo {
+            Triangle::Lower => solve_lower_inplace(&t.view(), &mut x2, unit),
+            Triangle::Upper => solve_upper_inplace(&t.view(), &mut x2, unit),
+        }
+        Ok(x)
+    }
+}
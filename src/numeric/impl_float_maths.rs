@@ -0,0 +1,230 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Element-wise methods for Float
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+
+macro_rules! unary_ops {
+    ($($(#[$meta:meta])* fn $id:ident / $id_inplace:ident)+) => {
+        $(
+        $(#[$meta])*
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id(&self) -> Array<A, D> {
+            self.mapv(A::$id)
+        }
+
+        $(#[$meta])*
+        ///
+        /// The array is modified in place.
+        pub fn $id_inplace(&mut self)
+        where
+            S: DataMut,
+        {
+            self.mapv_inplace(A::$id)
+        }
+        )+
+    };
+}
+
+macro_rules! binary_ops {
+    ($($(#[$meta:meta])* fn $id:ident / $id_inplace:ident ($ty:ty))+) => {
+        $(
+        $(#[$meta])*
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id(&self, rhs: $ty) -> Array<A, D> {
+            self.mapv(|v| A::$id(v, rhs))
+        }
+
+        $(#[$meta])*
+        ///
+        /// The array is modified in place.
+        pub fn $id_inplace(&mut self, rhs: $ty)
+        where
+            S: DataMut,
+        {
+            self.mapv_inplace(|v| A::$id(v, rhs))
+        }
+        )+
+    };
+}
+
+macro_rules! boolean_ops {
+    ($($(#[$meta:meta])* fn $id:ident)+) => {
+        $(
+        $(#[$meta])*
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id(&self) -> Array<bool, D> {
+            self.mapv(A::$id)
+        }
+        )+
+    };
+}
+
+fn replace_non_finite<A: Float>(v: A, nan: A, posinf: A, neginf: A) -> A {
+    if v.is_nan() {
+        nan
+    } else if v == A::infinity() {
+        posinf
+    } else if v == A::neg_infinity() {
+        neginf
+    } else {
+        v
+    }
+}
+
+/// # Element-wise methods for float arrays
+///
+/// Element-wise math functions for any array type that contains floating
+/// point numbers. Each method that returns a new array has an `_inplace`
+/// variant which overwrites the elements of the array instead.
+///
+/// Like [`.mapv()`](ArrayBase::mapv) and
+/// [`.mapv_inplace()`](ArrayBase::mapv_inplace), which they are built on,
+/// these methods operate directly on the underlying slice when the array is
+/// contiguous in memory, so that the compiler can vectorize the loop.
+///
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// use ndarray::array;
+/// use approx::assert_abs_diff_eq;
+///
+/// let mut a = array![[1., 4.], [9., 16.]];
+/// assert_eq!(a.sqrt(), array![[1., 2.], [3., 4.]]);
+///
+/// a.ln_inplace();
+/// a.exp_inplace();
+/// assert_abs_diff_eq!(a, array![[1., 4.], [9., 16.]], epsilon = 1e-12);
+/// # }
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    boolean_ops! {
+        /// Return `true` for each element that is `NaN` (not a number).
+        fn is_nan
+        /// Return `true` for each element that is neither infinite nor `NaN`.
+        fn is_finite
+    }
+
+    unary_ops! {
+        /// The largest integer less than or equal to each element.
+        fn floor / floor_inplace
+        /// The smallest integer greater than or equal to each element.
+        fn ceil / ceil_inplace
+        /// The nearest integer to each element, rounding half-way cases away
+        /// from zero.
+        fn round / round_inplace
+        /// The absolute value of each element.
+        fn abs / abs_inplace
+        /// The sign of each element: `1.0` for positive numbers and `+0.0`,
+        /// `-1.0` for negative numbers and `-0.0`, and `NaN` for `NaN`.
+        fn signum / signum_inplace
+        /// `e^x` of each element (the exponential function).
+        fn exp / exp_inplace
+        /// The natural logarithm of each element.
+        fn ln / ln_inplace
+        /// The base 2 logarithm of each element.
+        fn log2 / log2_inplace
+        /// The square root of each element.
+        fn sqrt / sqrt_inplace
+        /// The sine of each element (in radians).
+        fn sin / sin_inplace
+        /// The cosine of each element (in radians).
+        fn cos / cos_inplace
+        /// The tangent of each element (in radians).
+        fn tan / tan_inplace
+        /// The arcsine of each element, in radians.
+        fn asin / asin_inplace
+        /// The arccosine of each element, in radians.
+        fn acos / acos_inplace
+        /// The arctangent of each element, in radians.
+        fn atan / atan_inplace
+        /// The hyperbolic sine of each element.
+        fn sinh / sinh_inplace
+        /// The hyperbolic cosine of each element.
+        fn cosh / cosh_inplace
+        /// The hyperbolic tangent of each element.
+        fn tanh / tanh_inplace
+    }
+
+    binary_ops! {
+        /// Integer power of each element.
+        ///
+        /// This function is generally faster than using float power.
+        fn powi / powi_inplace(i32)
+        /// Float power of each element.
+        fn powf / powf_inplace(A)
+    }
+
+    /// Limit the values of each element to the interval `[min, max]`,
+    /// like NumPy's `clip`.
+    ///
+    /// `NaN` elements are left as `NaN`.
+    ///
+    /// **Panics** if `min > max`, or if either bound is `NaN`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-2., 0.5, 3.];
+    /// assert_eq!(a.clip(0., 1.), array![0., 0.5, 1.]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn clip(&self, min: A, max: A) -> Array<A, D> {
+        assert!(min <= max, "min must be less than or equal to max");
+        self.mapv(|v| num_traits::clamp(v, min, max))
+    }
+
+    /// Limit the values of each element to the interval `[min, max]`,
+    /// like NumPy's `clip`.
+    ///
+    /// `NaN` elements are left as `NaN`. The array is modified in place.
+    ///
+    /// **Panics** if `min > max`, or if either bound is `NaN`.
+    pub fn clip_inplace(&mut self, min: A, max: A)
+    where
+        S: DataMut,
+    {
+        assert!(min <= max, "min must be less than or equal to max");
+        self.mapv_inplace(|v| num_traits::clamp(v, min, max))
+    }
+
+    /// Replace `NaN` with `nan`, `+∞` with `posinf` and `-∞` with `neginf`
+    /// in each element, like NumPy's `nan_to_num`.
+    ///
+    /// NumPy's defaults are zero and the largest finite values,
+    /// `A::max_value()` and `A::min_value()`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![f64::NAN, f64::INFINITY, -1., f64::NEG_INFINITY];
+    /// assert_eq!(a.nan_to_num(0., f64::MAX, f64::MIN), array![0., f64::MAX, -1., f64::MIN]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn nan_to_num(&self, nan: A, posinf: A, neginf: A) -> Array<A, D> {
+        self.mapv(|v| replace_non_finite(v, nan, posinf, neginf))
+    }
+
+    /// Replace `NaN` with `nan`, `+∞` with `posinf` and `-∞` with `neginf`
+    /// in each element, like NumPy's `nan_to_num`.
+    ///
+    /// The array is modified in place.
+    pub fn nan_to_num_inplace(&mut self, nan: A, posinf: A, neginf: A)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(|v| replace_non_finite(v, nan, posinf, neginf))
+    }
+}
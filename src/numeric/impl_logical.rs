@@ -0,0 +1,145 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::{IntoDimension, Zip};
+
+/// # Logical Methods for Arrays
+///
+/// These methods are available for arrays of `bool`, for example the result
+/// of mapping a predicate over an array with `.mapv()`.
+impl<S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = bool>,
+    D: Dimension,
+{
+    /// Return `true` if any element of the array is `true`.
+    ///
+    /// Returns `false` if the array is empty. Stops at the first `true`
+    /// element found.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2], [3, 4]];
+    /// assert!(a.mapv(|x| x < 0).any());
+    /// assert!(!a.mapv(|x| x > 4).any());
+    /// ```
+    pub fn any(&self) -> bool {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return slc.iter().any(|&x| x);
+        }
+        !Zip::from(self).all(|&x| !x)
+    }
+
+    /// Return `true` if all elements of the array are `true`.
+    ///
+    /// Returns `true` if the array is empty. Stops at the first `false`
+    /// element found.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2], [3, 4]];
+    /// assert!(a.mapv(|x| x != 0).all());
+    /// assert!(!a.mapv(|x| x > 0).all());
+    /// ```
+    pub fn all(&self) -> bool {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return slc.iter().all(|&x| x);
+        }
+        Zip::from(self).all(|&x| x)
+    }
+
+    /// Return the number of `true` elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[true, false, true],
+    ///                [false, false, true]];
+    /// assert_eq!(a.count_nonzero(), 3);
+    /// assert_eq!(a.t().count_nonzero(), 3);
+    /// ```
+    pub fn count_nonzero(&self) -> usize {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return slc.iter().map(|&x| x as usize).sum();
+        }
+        self.fold(0, |count, &x| count + x as usize)
+    }
+
+    /// Return whether any element is `true` along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[true, false, false],
+    ///                [false, false, true]];
+    /// assert_eq!(a.any_axis(Axis(0)), array![true, false, true]);
+    /// assert_eq!(a.any_axis(Axis(1)), array![true, true]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn any_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.any())
+    }
+
+    /// Return whether all elements are `true` along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[true, false, true],
+    ///                [true, true, true]];
+    /// assert_eq!(a.all_axis(Axis(0)), array![true, false, true]);
+    /// assert_eq!(a.all_axis(Axis(1)), array![false, true]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn all_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane.all())
+    }
+
+    /// Return the indices of the `true` elements, as one index array per
+    /// axis.
+    ///
+    /// The indices are in logical order (the rightmost index varies the
+    /// fastest), so the `n`th `true` element is at the index formed by taking
+    /// the `n`th entry of each of the returned arrays. This is the same as
+    /// NumPy's `nonzero`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[false, true, false],
+    ///                [true, false, true]];
+    /// let idx = a.nonzero();
+    /// assert_eq!(idx, vec![array![0, 1, 1], array![1, 0, 2]]);
+    /// ```
+    pub fn nonzero(&self) -> Vec<Array1<usize>> {
+        let mut result = vec![Vec::new(); self.ndim()];
+        for (index, &x) in self.indexed_iter() {
+            if x {
+                let index = index.into_dimension();
+                for (axis_indices, &i) in result.iter_mut().zip(index.slice()) {
+                    axis_indices.push(i);
+                }
+            }
+        }
+        result.into_iter().map(Array1::from).collect()
+    }
+}
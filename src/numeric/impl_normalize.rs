@@ -0,0 +1,164 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+/// A vector norm, used by [`ArrayBase::normalize`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// The sum of the absolute values.
+    L1,
+    /// The Euclidean norm, the square root of the sum of squares.
+    L2,
+    /// The maximum absolute value.
+    Linf,
+}
+
+/// Return the maximum of the lane, ignoring `NaN`, or `-∞` if it is empty.
+fn lane_max<A: NdFloat>(lane: &ArrayView1<'_, A>) -> A {
+    lane.fold(A::neg_infinity(), |m, &x| if x > m { x } else { m })
+}
+
+/// Return the value to subtract from the lane before exponentiating, which
+/// is the maximum if it is finite and zero otherwise.
+fn lane_shift<A: NdFloat>(lane: &ArrayView1<'_, A>) -> A {
+    let max = lane_max(lane);
+    if max.is_finite() {
+        max
+    } else {
+        A::zero()
+    }
+}
+
+fn lane_logsumexp<A: NdFloat>(lane: &ArrayView1<'_, A>) -> A {
+    let shift = lane_shift(lane);
+    let sum = lane.fold(A::zero(), |sum, &x| sum + (x - shift).exp());
+    shift + sum.ln()
+}
+
+fn lane_norm<A: NdFloat>(lane: &ArrayView1<'_, A>, norm: Norm) -> A {
+    match norm {
+        Norm::L1 => lane.fold(A::zero(), |sum, &x| sum + x.abs()),
+        Norm::Linf => lane.fold(A::zero(), |m, &x| m.max(x.abs())),
+        Norm::L2 => {
+            // Scale by the largest magnitude so that squaring can't overflow
+            // or underflow.
+            let scale = lane.fold(A::zero(), |m, &x| m.max(x.abs()));
+            if scale == A::zero() || !scale.is_finite() {
+                return scale;
+            }
+            let sum_sq = lane.fold(A::zero(), |sum, &x| {
+                let y = x / scale;
+                y.mul_add(y, sum)
+            });
+            scale * sum_sq.sqrt()
+        }
+    }
+}
+
+/// # Numerically Stable Normalization
+///
+/// These methods subtract the maximum of each lane before exponentiating, so
+/// that they neither overflow for large inputs nor lose all precision for
+/// very negative inputs.
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: NdFloat,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    /// Return `ln(∑ exp(xᵢ))` along `axis`.
+    ///
+    /// The logarithm of the sum of an empty lane is `-∞`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::{array, Axis};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[1000., 1000.], [0., f64::ln(3.)]];
+    /// let lse = a.logsumexp(Axis(1));
+    /// assert_abs_diff_eq!(lse, array![1000. + f64::ln(2.), f64::ln(4.)], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn logsumexp(&self, axis: Axis) -> Array<A, D::Smaller> {
+        Zip::from(self.lanes(axis)).map_collect(|lane| lane_logsumexp(&lane))
+    }
+
+    /// Return the softmax `exp(xᵢ) / ∑ exp(xⱼ)` of each lane along `axis`.
+    ///
+    /// Each lane of the result sums to one.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// # #[cfg(feature = "approx")] {
+    /// use ndarray::{array, Axis};
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![[1000., 1000.], [0., f64::ln(3.)]];
+    /// let p = a.softmax(Axis(1));
+    /// assert_abs_diff_eq!(p, array![[0.5, 0.5], [0.25, 0.75]], epsilon = 1e-12);
+    /// # }
+    /// ```
+    pub fn softmax(&self, axis: Axis) -> Array<A, D> {
+        let mut result = self.to_owned();
+        Zip::from(result.lanes_mut(axis)).for_each(|mut lane| {
+            let shift = lane_shift(&lane.view());
+            lane.mapv_inplace(|x| (x - shift).exp());
+            let sum = lane.sum();
+            lane.mapv_inplace(|x| x / sum);
+        });
+        result
+    }
+
+    /// Return the logarithm of the softmax of each lane along `axis`,
+    /// computed as `xᵢ - ln(∑ exp(xⱼ))`.
+    ///
+    /// This is more accurate than taking the logarithm of
+    /// [`.softmax()`](Self::softmax) when some probabilities are tiny.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn log_softmax(&self, axis: Axis) -> Array<A, D> {
+        let mut result = self.to_owned();
+        Zip::from(result.lanes_mut(axis)).for_each(|mut lane| {
+            let lse = lane_logsumexp(&lane.view());
+            lane.mapv_inplace(|x| x - lse);
+        });
+        result
+    }
+
+    /// Return a copy of the array with each lane along `axis` divided by
+    /// its `norm`.
+    ///
+    /// Lanes with a norm of zero are left unchanged. The L2 norm is computed
+    /// with scaling so that it doesn't overflow or underflow.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, Norm};
+    ///
+    /// let a = array![[3., 4.], [0., 0.]];
+    /// assert_eq!(a.normalize(Norm::L2, Axis(1)), array![[0.6, 0.8], [0., 0.]]);
+    /// assert_eq!(a.normalize(Norm::L1, Axis(0)), array![[1., 1.], [0., 0.]]);
+    /// ```
+    pub fn normalize(&self, norm: Norm, axis: Axis) -> Array<A, D> {
+        let mut result = self.to_owned();
+        Zip::from(result.lanes_mut(axis)).for_each(|mut lane| {
+            let n = lane_norm(&lane.view(), norm);
+            if n != A::zero() {
+                lane.mapv_inplace(|x| x / n);
+            }
+        });
+        result
+    }
+}
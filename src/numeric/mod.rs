@@ -1 +1,13 @@
 mod impl_numeric;
+#[cfg(feature = "std")]
+mod impl_float_maths;
+mod impl_logical;
+#[cfg(feature = "std")]
+mod impl_normalize;
+#[cfg(feature = "std")]
+mod running_stats;
+
+#[cfg(feature = "std")]
+pub use self::impl_normalize::Norm;
+#[cfg(feature = "std")]
+pub use self::running_stats::RunningStats;
@@ -0,0 +1,222 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::ShapeBuilder;
+
+/// One-pass accumulator for the count, mean, variance, minimum and maximum of
+/// a stream of samples.
+///
+/// Each sample is an array of the accumulator's shape, and the statistics are
+/// tracked elementwise. Samples are added one at a time with
+/// [`.update()`](Self::update) or as a batch of samples stacked along an axis
+/// with [`.update_batch()`](Self::update_batch), so a dataset never has to be
+/// in memory all at once.
+///
+/// The mean and variance are computed using the [Welford one-pass
+/// algorithm](https://www.jstor.org/stable/1266577). Two accumulators over
+/// disjoint parts of a dataset can be combined with [`.merge()`](Self::merge)
+/// (Chan et al.'s parallel algorithm), which makes `RunningStats` suitable as
+/// the accumulator of a parallel fold such as `Zip::par_fold`.
+///
+/// ```
+/// use ndarray::{array, Axis, RunningStats};
+///
+/// let a = array![[1., 2.],
+///                [3., 4.],
+///                [5., 6.]];
+///
+/// let mut stats = RunningStats::new(2);
+/// stats.update_batch(&a.slice(ndarray::s![..2, ..]), Axis(0));
+/// stats.update_batch(&a.slice(ndarray::s![2.., ..]), Axis(0));
+///
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!(stats.mean().unwrap(), array![3., 4.]);
+/// assert_eq!(stats.var(1.), array![4., 4.]);
+/// assert_eq!(stats.min().unwrap(), array![1., 2.]);
+/// assert_eq!(stats.max().unwrap(), array![5., 6.]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RunningStats<A, D: Dimension> {
+    count: usize,
+    mean: Array<A, D>,
+    sum_sq: Array<A, D>,
+    min: Array<A, D>,
+    max: Array<A, D>,
+}
+
+impl<A, D> RunningStats<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Create an empty accumulator for samples of the given shape.
+    ///
+    /// Use the shape `()` to accumulate statistics of scalar samples.
+    pub fn new<Sh>(shape: Sh) -> Self
+    where
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        let mean = Array::zeros(shape);
+        RunningStats {
+            count: 0,
+            sum_sq: mean.clone(),
+            min: Array::from_elem(mean.raw_dim(), A::infinity()),
+            max: Array::from_elem(mean.raw_dim(), A::neg_infinity()),
+            mean,
+        }
+    }
+
+    /// Add a single sample.
+    ///
+    /// **Panics** if the shape of `sample` does not match the accumulator, or
+    /// if `A::from_usize()` fails for the new count.
+    pub fn update<S>(&mut self, sample: &ArrayBase<S, D>)
+    where
+        S: Data<Elem = A>,
+    {
+        assert_eq!(
+            self.mean.shape(),
+            sample.shape(),
+            "ndarray: sample shape must match the shape of the accumulator"
+        );
+        self.count += 1;
+        let count = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        azip!((mean in &mut self.mean, sum_sq in &mut self.sum_sq,
+               min in &mut self.min, max in &mut self.max, &x in sample) {
+            let delta = x - *mean;
+            *mean = *mean + delta / count;
+            *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
+            if x < *min {
+                *min = x;
+            }
+            if x > *max {
+                *max = x;
+            }
+        });
+    }
+
+    /// Add every subview of `batch` along `axis` as a sample.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the shape of the subviews
+    /// does not match the accumulator, or if `A::from_usize()` fails for the
+    /// new count.
+    pub fn update_batch<S, E>(&mut self, batch: &ArrayBase<S, E>, axis: Axis)
+    where
+        S: Data<Elem = A>,
+        E: RemoveAxis<Smaller = D>,
+    {
+        for sample in batch.axis_iter(axis) {
+            self.update(&sample);
+        }
+    }
+
+    /// Combine the samples seen by `other` into `self`.
+    ///
+    /// The result is the same (up to rounding) as if all samples of `other`
+    /// had been added to `self` with [`.update()`](Self::update).
+    ///
+    /// **Panics** if the shapes of the two accumulators don't match, or if
+    /// `A::from_usize()` fails for the new count.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.mean.shape(),
+            other.mean.shape(),
+            "ndarray: shapes of merged accumulators must match"
+        );
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.clone_from(other);
+            return;
+        }
+        let count = self.count + other.count;
+        let n_self = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        let n_other = A::from_usize(other.count).expect("Converting count to `A` must not fail.");
+        let n = A::from_usize(count).expect("Converting count to `A` must not fail.");
+        azip!((mean in &mut self.mean, sum_sq in &mut self.sum_sq,
+               &mean_b in &other.mean, &sum_sq_b in &other.sum_sq) {
+            let delta = mean_b - *mean;
+            *mean = *mean + delta * (n_other / n);
+            *sum_sq = *sum_sq + sum_sq_b + delta * delta * (n_self * n_other / n);
+        });
+        azip!((min in &mut self.min, max in &mut self.max,
+               &min_b in &other.min, &max_b in &other.max) {
+            if min_b < *min {
+                *min = min_b;
+            }
+            if max_b > *max {
+                *max = max_b;
+            }
+        });
+        self.count = count;
+    }
+
+    /// Return the number of samples seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Return the elementwise mean of the samples, or `None` if no samples
+    /// have been added.
+    pub fn mean(&self) -> Option<ArrayView<'_, A, D>> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean.view())
+        }
+    }
+
+    /// Return the elementwise variance of the samples.
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom", as in
+    /// [`ArrayBase::var`].
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the number of
+    /// samples.
+    pub fn var(&self, ddof: A) -> Array<A, D> {
+        let n = A::from_usize(self.count).expect("Converting count to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof > n),
+            "`ddof` must not be less than zero or greater than the number of samples",
+        );
+        let dof = n - ddof;
+        self.sum_sq.mapv(|s| s / dof)
+    }
+
+    /// Return the elementwise standard deviation of the samples.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the number of
+    /// samples.
+    pub fn std(&self, ddof: A) -> Array<A, D> {
+        self.var(ddof).mapv_into(|x| x.sqrt())
+    }
+
+    /// Return the elementwise minimum of the samples, or `None` if no samples
+    /// have been added.
+    pub fn min(&self) -> Option<ArrayView<'_, A, D>> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min.view())
+        }
+    }
+
+    /// Return the elementwise maximum of the samples, or `None` if no samples
+    /// have been added.
+    pub fn max(&self) -> Option<ArrayView<'_, A, D>> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max.view())
+        }
+    }
+}
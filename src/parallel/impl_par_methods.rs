@@ -1,5 +1,14 @@
-use crate::{Array, ArrayBase, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
-use crate::AssignElem;
+use crate::{Array, ArrayBase, Axis, Data, DataMut, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{ArrayD, AssignElem, LinalgScalar, RemoveAxis, RunningStats};
+use crate::linalg::{mat_mul_batch, MatMulOperands};
+use crate::sparse::{sparse_dot_shape_error, CsrMat};
+use crate::{Array1, Ix1};
+use crate::impl_scatter::{check_scatter_shape, max_assign, segment_bounds, segment_dim};
+use crate::impl_sort::{argsort_lane, sort_lane};
+use crate::impl_take::resolve_all;
+use crate::{ArrayView1, IndexMode, RawData, Slice, TakeIndex};
+use core::cmp::Ordering;
+use num_traits::{Bounded, Float, FromPrimitive, Zero};
 
 use crate::parallel::prelude::*;
 use crate::parallel::par::ParallelSplits;
@@ -45,6 +54,350 @@ where
     }
 }
 
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+    A: Float + FromPrimitive + Send + Sync,
+{
+    /// Parallel accumulation of [`RunningStats`] over the subviews along `axis`.
+    ///
+    /// The subviews are split into tasks that are accumulated in parallel with
+    /// `Zip::par_fold`, and the partial results are combined with
+    /// [`RunningStats::merge`].
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((1000, 3), |(i, j)| (i * 3 + j) as f64);
+    /// let stats = a.par_running_stats(Axis(0));
+    ///
+    /// assert_eq!(stats.count(), 1000);
+    /// assert_eq!(stats.mean().unwrap(), a.mean_axis(Axis(0)).unwrap());
+    /// ```
+    pub fn par_running_stats(&self, axis: Axis) -> RunningStats<A, D::Smaller> {
+        let dim = self.raw_dim().remove_axis(axis);
+        Zip::from(self.axis_iter(axis)).par_fold(
+            || RunningStats::new(dim.clone()),
+            |mut stats, sample| {
+                stats.update(&sample);
+                stats
+            },
+            |mut stats, other| {
+                stats.merge(&other);
+                stats
+            },
+        )
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: LinalgScalar + Send + Sync,
+{
+    /// Parallel version of [`.matmul()`](ArrayBase::matmul).
+    ///
+    /// The matrix products of the batches are computed in parallel.
+    ///
+    /// **Panics** if an operand is zero-dimensional, if the batch axes can't
+    /// be broadcast together or the inner dimensions don't match, or if the
+    /// number of elements in the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_shape_fn((16, 4, 3), |(b, i, j)| (b + i * j) as f64);
+    /// let b = Array::from_shape_fn((3, 5), |(i, j)| (i + j) as f64);
+    /// assert_eq!(a.par_matmul(&b), a.matmul(&b));
+    /// ```
+    pub fn par_matmul<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> ArrayD<A>
+    where
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let mut operands = MatMulOperands::new(self.view().into_dyn(), rhs.view().into_dyn());
+        if !operands.is_trivial() {
+            operands.zip().par_for_each(mat_mul_batch);
+        }
+        operands.into_output()
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A> CsrMat<A>
+where
+    A: LinalgScalar + Send + Sync,
+{
+    /// Perform the sparse matrix-vector product `self · x`, computing the
+    /// rows in parallel; see [`.dot()`](CsrMat::dot).
+    ///
+    /// **Panics** if the length of `x` is not the number of columns.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    /// use ndarray::sparse::CsrMat;
+    ///
+    /// let a = CsrMat::from_dense(&Array::from_shape_fn((64, 32), |(i, j)| (i % (j + 1)) as f64));
+    /// let x = Array::linspace(0., 1., 32);
+    /// assert_eq!(a.par_dot(&x), a.dot(&x));
+    /// ```
+    pub fn par_dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let (m, n) = self.shape();
+        if n != x.len() {
+            sparse_dot_shape_error(m, n, x.len(), 1);
+        }
+        let x = x.view();
+        let mut y = Array1::zeros(m);
+        Zip::indexed(&mut y).par_for_each(|i, y| *y = self.row_dot(i, &x));
+        y
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: RemoveAxis,
+    A: Clone + Send + Sync,
+{
+    /// Parallel version of [`.scatter_add()`](ArrayBase::scatter_add).
+    ///
+    /// The subviews of `src` are split into tasks that each accumulate into
+    /// a partial array of zeros, and the partial arrays are added together
+    /// and then to the array.
+    ///
+    /// **Panics** if `axis` is out of bounds, if an index is out of bounds, or
+    /// if the shape of `src` doesn’t match.
+    ///
+    /// ```
+    /// use ndarray::{Array, Array1, Axis};
+    ///
+    /// let bins: Vec<usize> = (0..1000).map(|i| i * i % 7).collect();
+    /// let mut counts = Array1::<u32>::zeros(7);
+    /// counts.par_scatter_add(Axis(0), &bins, &Array::ones(bins.len()));
+    /// let mut expected = Array1::zeros(7);
+    /// expected.scatter_add(Axis(0), &bins, &Array::ones(bins.len()));
+    /// assert_eq!(counts, expected);
+    /// ```
+    pub fn par_scatter_add<I, S2>(&mut self, axis: Axis, indices: &[I], src: &ArrayBase<S2, D>)
+    where
+        A: Zero,
+        S: DataMut,
+        S2: Data<Elem = A>,
+        I: TakeIndex,
+    {
+        let indices = resolve_all(indices, axis.index(), self.len_of(axis), IndexMode::Raise);
+        check_scatter_shape(&self.raw_dim(), &src.raw_dim(), axis, indices.len());
+        let dim = self.raw_dim();
+        let partial = Zip::from(src.axis_iter(axis))
+            .and(ArrayView1::from(&indices))
+            .par_fold(
+                || Array::zeros(dim.clone()),
+                |mut partial: Array<A, D>, sub, &index| {
+                    partial
+                        .index_axis_mut(axis, index)
+                        .zip_mut_with(&sub, |x, y| *x = x.clone() + y.clone());
+                    partial
+                },
+                |mut partial, other| {
+                    partial.zip_mut_with(&other, |x, y| *x = x.clone() + y.clone());
+                    partial
+                },
+            );
+        self.zip_mut_with(&partial, |x, y| *x = x.clone() + y.clone());
+    }
+
+    /// Parallel version of [`.segment_sum()`](ArrayBase::segment_sum).
+    ///
+    /// Since the segment ids are sorted, each segment is a range of subviews,
+    /// and the segments are reduced in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `segment_ids`
+    /// is not the length of the axis, if it is not sorted, or if the result
+    /// would have more than `isize::MAX` elements.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((1000, 3), |(i, j)| (i + j) as f64);
+    /// let ids: Vec<usize> = (0..1000).map(|i| i / 7).collect();
+    /// assert_eq!(a.par_segment_sum(Axis(0), &ids), a.segment_sum(Axis(0), &ids));
+    /// ```
+    pub fn par_segment_sum(&self, axis: Axis, segment_ids: &[usize]) -> Array<A, D>
+    where
+        A: Zero,
+        S: Data,
+    {
+        let bounds = segment_bounds(segment_ids, axis, &self.raw_dim());
+        let out = Array::zeros(segment_dim(&self.raw_dim(), axis, &bounds));
+        self.par_segment_reduce(axis, &bounds, out, |x, y| *x = x.clone() + y.clone())
+    }
+
+    /// Parallel version of [`.segment_max()`](ArrayBase::segment_max).
+    ///
+    /// Since the segment ids are sorted, each segment is a range of subviews,
+    /// and the segments are reduced in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `segment_ids`
+    /// is not the length of the axis, if it is not sorted, or if the result
+    /// would have more than `isize::MAX` elements.
+    pub fn par_segment_max(&self, axis: Axis, segment_ids: &[usize]) -> Array<A, D>
+    where
+        A: PartialOrd + Bounded,
+        S: Data,
+    {
+        let bounds = segment_bounds(segment_ids, axis, &self.raw_dim());
+        let dim = segment_dim(&self.raw_dim(), axis, &bounds);
+        let out = Array::from_elem(dim, A::min_value());
+        self.par_segment_reduce(axis, &bounds, out, max_assign)
+    }
+
+    /// Fold the subviews of each segment into the subview of `out`, in
+    /// parallel over the segments. The subview of a non-empty segment starts
+    /// from its first subview, and that of an empty one is left unchanged.
+    fn par_segment_reduce<F>(
+        &self,
+        axis: Axis,
+        bounds: &[usize],
+        mut out: Array<A, D>,
+        f: F,
+    ) -> Array<A, D>
+    where
+        S: Data,
+        F: Fn(&mut A, &A) + Sync + Send,
+    {
+        let view = self.view();
+        let segments = bounds.len() - 1;
+        Zip::from(out.axis_iter_mut(axis))
+            .and(ArrayView1::from(&bounds[..segments]))
+            .and(ArrayView1::from(&bounds[1..]))
+            .par_for_each(|mut out, &start, &end| {
+                let segment = view.slice_axis(axis, Slice::from(start..end));
+                let mut subs = segment.axis_iter(axis);
+                if let Some(first) = subs.next() {
+                    out.assign(&first);
+                    for sub in subs {
+                        out.zip_mut_with(&sub, &f);
+                    }
+                }
+            });
+        out
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+    A: Send + Sync,
+{
+    /// Parallel version of [`.sort_axis()`](ArrayBase::sort_axis).
+    ///
+    /// The lanes are sorted in parallel, and each lane is sorted by one
+    /// thread.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let mut a = Array::from_shape_fn((100, 50), |(i, j)| (i * 37 + j * 11) % 23);
+    /// let mut b = a.clone();
+    /// a.par_sort_axis(Axis(0));
+    /// b.sort_axis(Axis(0));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn par_sort_axis(&mut self, axis: Axis)
+    where
+        A: Ord,
+        S: DataMut,
+    {
+        self.par_sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Parallel version of [`.sort_axis_by()`](ArrayBase::sort_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_sort_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        S: DataMut,
+        F: Fn(&A, &A) -> Ordering + Sync + Send,
+    {
+        Zip::from(self.lanes_mut(axis)).par_for_each(|lane| sort_lane(lane, true, &compare));
+    }
+
+    /// Parallel version of
+    /// [`.sort_axis_unstable()`](ArrayBase::sort_axis_unstable).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_sort_axis_unstable(&mut self, axis: Axis)
+    where
+        A: Ord,
+        S: DataMut,
+    {
+        self.par_sort_axis_unstable_by(axis, Ord::cmp)
+    }
+
+    /// Parallel version of
+    /// [`.sort_axis_unstable_by()`](ArrayBase::sort_axis_unstable_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_sort_axis_unstable_by<F>(&mut self, axis: Axis, compare: F)
+    where
+        S: DataMut,
+        F: Fn(&A, &A) -> Ordering + Sync + Send,
+    {
+        Zip::from(self.lanes_mut(axis)).par_for_each(|lane| sort_lane(lane, false, &compare));
+    }
+
+    /// Parallel version of [`.argsort_axis()`](ArrayBase::argsort_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where
+        A: Ord,
+        S: Data,
+    {
+        self.par_argsort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Parallel version of
+    /// [`.argsort_axis_by()`](ArrayBase::argsort_axis_by).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_argsort_axis_by<F>(&self, axis: Axis, compare: F) -> Array<usize, D>
+    where
+        S: Data,
+        F: Fn(&A, &A) -> Ordering + Sync + Send,
+    {
+        let mut out = Array::zeros(self.raw_dim());
+        Zip::from(out.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .par_for_each(|out, lane| argsort_lane(out, lane, &compare));
+        out
+    }
+}
+
 // Zip
 
 const COLLECT_MAX_SPLITS: usize = 10;
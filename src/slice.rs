@@ -7,10 +7,11 @@
 // except according to those terms.
 use crate::dimension::slices_intersect;
 use crate::error::{ErrorKind, ShapeError};
-use crate::{ArrayViewMut, DimAdd, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+use crate::{ArrayViewMut, DimAdd, DimSub, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
 use alloc::vec::Vec;
 use std::convert::TryFrom;
 use std::fmt;
+use std::iter;
 use std::marker::PhantomData;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
@@ -79,7 +80,7 @@ impl Slice {
 #[derive(Clone, Copy, Debug)]
 pub struct NewAxis;
 
-/// A slice (range with step), an index, or a new axis token.
+/// A slice (range with step), an index, a new axis token, or an ellipsis.
 ///
 /// See also the [`s![]`](s!) macro for a convenient way to create a
 /// `SliceInfo<[SliceInfoElem; n], Din, Dout>`.
@@ -107,6 +108,11 @@ pub struct NewAxis;
 /// `SliceInfoElem::NewAxis` is a new axis of length 1. It can also be created
 /// with `SliceInfoElem::from(NewAxis)`. The Python equivalent is
 /// `[np.newaxis]`. The macro equivalent is `s![NewAxis]`.
+///
+/// `SliceInfoElem::Ellipsis` is the full range of each axis which the other
+/// elements don't index, for example `[..., 0]` selects index 0 of the last
+/// axis of an array of any dimension. The Python equivalent is `[...]`. The
+/// macro equivalent is `s![...]`.
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum SliceInfoElem {
     /// A range with step size. `end` is an exclusive index. Negative `start`
@@ -125,6 +131,9 @@ pub enum SliceInfoElem {
     Index(isize),
     /// A new axis of length 1.
     NewAxis,
+    /// Full slices of the axes which the other elements don't index. There
+    /// can be at most one ellipsis.
+    Ellipsis,
 }
 
 copy_and_clone! {SliceInfoElem}
@@ -144,6 +153,11 @@ impl SliceInfoElem {
     pub fn is_new_axis(&self) -> bool {
         matches!(self, SliceInfoElem::NewAxis)
     }
+
+    /// Returns `true` if `self` is an `Ellipsis` value.
+    pub fn is_ellipsis(&self) -> bool {
+        matches!(self, SliceInfoElem::Ellipsis)
+    }
 }
 
 impl fmt::Display for SliceInfoElem {
@@ -163,6 +177,7 @@ impl fmt::Display for SliceInfoElem {
                 }
             }
             SliceInfoElem::NewAxis => write!(f, stringify!(NewAxis))?,
+            SliceInfoElem::Ellipsis => write!(f, "...")?,
         }
         Ok(())
     }
@@ -301,9 +316,15 @@ pub unsafe trait SliceArg<D: Dimension>: AsRef<[SliceInfoElem]> {
     type OutDim: Dimension;
 
     /// Returns the number of axes in the input array.
+    ///
+    /// If the number is only known at runtime, because of an ellipsis, this
+    /// is the number of axes which the other elements index.
     fn in_ndim(&self) -> usize;
 
     /// Returns the number of axes in the output array.
+    ///
+    /// If the number is only known at runtime, because of an ellipsis, this
+    /// is the number of axes which the other elements produce.
     fn out_ndim(&self) -> usize;
 
     private_decl! {}
@@ -379,11 +400,13 @@ unsafe impl SliceArg<IxDyn> for [SliceInfoElem] {
     type OutDim = IxDyn;
 
     fn in_ndim(&self) -> usize {
-        self.iter().filter(|s| !s.is_new_axis()).count()
+        self.iter()
+            .filter(|s| !s.is_new_axis() && !s.is_ellipsis())
+            .count()
     }
 
     fn out_ndim(&self) -> usize {
-        self.iter().filter(|s| !s.is_index()).count()
+        self.iter().filter(|s| !s.is_index() && !s.is_ellipsis()).count()
     }
 
     private_impl! {}
@@ -422,6 +445,26 @@ where
     Din: Dimension,
     Dout: Dimension,
 {
+    match indices.iter().filter(|s| s.is_ellipsis()).count() {
+        0 => {}
+        1 => {
+            // The length of the ellipsis is known if the input dimension is.
+            let ellipsis_len = match Din::NDIM {
+                Some(in_ndim) => in_ndim.checked_sub(indices.in_ndim()),
+                None if Dout::NDIM.is_none() => return Ok(()),
+                None => None,
+            };
+            let ellipsis_len =
+                ellipsis_len.ok_or_else(|| ShapeError::from_kind(ErrorKind::IncompatibleShape))?;
+            if let Some(out_ndim) = Dout::NDIM {
+                if out_ndim != indices.out_ndim() + ellipsis_len {
+                    return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+                }
+            }
+            return Ok(());
+        }
+        _ => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)),
+    }
     if let Some(in_ndim) = Din::NDIM {
         if in_ndim != indices.in_ndim() {
             return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
@@ -491,7 +534,7 @@ where
     ///
     /// If `Din` is a fixed-size dimension type, then this is equivalent to
     /// `Din::NDIM.unwrap()`. Otherwise, the value is calculated by iterating
-    /// over the `SliceInfoElem` elements.
+    /// over the `SliceInfoElem` elements, not counting an ellipsis.
     pub fn in_ndim(&self) -> usize {
         if let Some(ndim) = Din::NDIM {
             ndim
@@ -506,7 +549,7 @@ where
     ///
     /// If `Dout` is a fixed-size dimension type, then this is equivalent to
     /// `Dout::NDIM.unwrap()`. Otherwise, the value is calculated by iterating
-    /// over the `SliceInfoElem` elements.
+    /// over the `SliceInfoElem` elements, not counting an ellipsis.
     pub fn out_ndim(&self) -> usize {
         if let Some(ndim) = Dout::NDIM {
             ndim
@@ -634,6 +677,169 @@ where
     }
 }
 
+/// Slicing information with an ellipsis, created by the [`s![]`](s!) macro.
+///
+/// `In` and `Out` are the dimensions that the elements other than the
+/// ellipsis consume and produce. It can slice an array with a fixed
+/// dimension `D` of at least `In` axes, which results in a fixed dimension,
+/// or an array with dimension `IxDyn`, which results in `IxDyn`.
+///
+/// ```
+/// use ndarray::{s, Array, Ix2, Ix3};
+///
+/// let a = Array::<f64, _>::zeros((2, 3, 4, 5, 6));
+/// let b = a.slice(s![..., 0]);
+/// assert_eq!(b.shape(), &[2, 3, 4, 5]);
+/// let c = a.slice(s![1, ..., 2..4, 0]);
+/// let _: ndarray::ArrayView<_, Ix3> = c;
+/// assert_eq!(c.shape(), &[3, 4, 2]);
+///
+/// // the ellipsis may also be empty
+/// let d = Array::<f64, Ix2>::zeros((2, 3)).into_dyn();
+/// assert_eq!(d.slice(s![0, ..., 1]).shape(), &[]);
+/// ```
+#[derive(Debug)]
+pub struct EllipsisSliceInfo<T, In: Dimension, Out: Dimension> {
+    in_dim: PhantomData<In>,
+    out_dim: PhantomData<Out>,
+    indices: T,
+}
+
+impl<T, In, Out> EllipsisSliceInfo<T, In, Out>
+where
+    T: AsRef<[SliceInfoElem]>,
+    In: Dimension,
+    Out: Dimension,
+{
+    /// Returns a new `EllipsisSliceInfo` instance.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `indices` has exactly one ellipsis, that
+    /// `in_dim` and `out_dim` are consistent with the other elements, and
+    /// that `indices.as_ref()` always returns the same value when called
+    /// multiple times.
+    #[doc(hidden)]
+    pub unsafe fn new_unchecked(
+        indices: T,
+        in_dim: PhantomData<In>,
+        out_dim: PhantomData<Out>,
+    ) -> Self {
+        debug_assert_eq!(indices.as_ref().iter().filter(|s| s.is_ellipsis()).count(), 1);
+        debug_assert_eq!(In::NDIM, Some(indices.as_ref().in_ndim()));
+        debug_assert_eq!(Out::NDIM, Some(indices.as_ref().out_ndim()));
+        EllipsisSliceInfo {
+            in_dim,
+            out_dim,
+            indices,
+        }
+    }
+}
+
+impl<T, In, Out> Deref for EllipsisSliceInfo<T, In, Out>
+where
+    In: Dimension,
+    Out: Dimension,
+{
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.indices
+    }
+}
+
+impl<T, In, Out> AsRef<[SliceInfoElem]> for EllipsisSliceInfo<T, In, Out>
+where
+    T: AsRef<[SliceInfoElem]>,
+    In: Dimension,
+    Out: Dimension,
+{
+    fn as_ref(&self) -> &[SliceInfoElem] {
+        self.indices.as_ref()
+    }
+}
+
+impl<T, In, Out> Copy for EllipsisSliceInfo<T, In, Out>
+where
+    T: Copy,
+    In: Dimension,
+    Out: Dimension,
+{
+}
+
+impl<T, In, Out> Clone for EllipsisSliceInfo<T, In, Out>
+where
+    T: Clone,
+    In: Dimension,
+    Out: Dimension,
+{
+    fn clone(&self) -> Self {
+        EllipsisSliceInfo {
+            in_dim: PhantomData,
+            out_dim: PhantomData,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+unsafe impl<T, In, Out, D> SliceArg<D> for EllipsisSliceInfo<T, In, Out>
+where
+    T: AsRef<[SliceInfoElem]>,
+    In: Dimension,
+    Out: Dimension,
+    D: Dimension + DimSub<In>,
+    <D as DimSub<In>>::Output: DimAdd<Out>,
+{
+    type OutDim = <<D as DimSub<In>>::Output as DimAdd<Out>>::Output;
+
+    fn in_ndim(&self) -> usize {
+        D::NDIM.unwrap_or_else(|| self.indices.as_ref().in_ndim())
+    }
+
+    fn out_ndim(&self) -> usize {
+        Self::OutDim::NDIM.unwrap_or_else(|| self.indices.as_ref().out_ndim())
+    }
+
+    private_impl! {}
+}
+
+/// Return the number of axes which the ellipsis of `indices` stands for,
+/// when slicing an array with `ndim` axes, or zero if there is none.
+///
+/// **Panics** if `indices` has more than one ellipsis, or if it doesn't
+/// match the number of axes.
+pub(crate) fn ellipsis_len(indices: &[SliceInfoElem], ndim: usize) -> usize {
+    let in_ndim = indices.in_ndim();
+    match indices.iter().filter(|s| s.is_ellipsis()).count() {
+        0 => {
+            assert_eq!(
+                in_ndim, ndim,
+                "The input dimension of `info` must match the array to be sliced.",
+            );
+            0
+        }
+        1 => {
+            assert!(
+                in_ndim <= ndim,
+                "The input dimension of `info` must not exceed the array to be sliced.",
+            );
+            ndim - in_ndim
+        }
+        _ => panic!("`info` must not have more than one ellipsis."),
+    }
+}
+
+/// Return the elements of `indices`, with the ellipsis replaced by
+/// `ellipsis_len` full slices.
+pub(crate) fn expand_ellipsis(
+    indices: &[SliceInfoElem],
+    ellipsis_len: usize,
+) -> impl Iterator<Item = SliceInfoElem> + Clone + '_ {
+    indices.iter().flat_map(move |&elem| match elem {
+        SliceInfoElem::Ellipsis => iter::repeat(SliceInfoElem::from(..)).take(ellipsis_len),
+        elem => iter::repeat(elem).take(1),
+    })
+}
+
 /// Trait for determining dimensionality of input and output for [`s!`] macro.
 #[doc(hidden)]
 pub trait SliceNextDim {
@@ -703,9 +909,12 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 ///   multiplying the step size by *step*, to use for slicing that axis.
 /// * *new-axis*: a [`NewAxis`] instance that represents the creation of a new axis.
 ///   (Except for [`.slice_collapse()`], which panics on [`NewAxis`] elements.)
+/// * `...`: an ellipsis, which stands for full slices of the axes which the
+///   other elements don't index. There can be at most one ellipsis.
 ///
-/// The number of *elem*, not including *new-axis*, must match the
-/// number of axes in the array. *index*, *range*, *slice*, *step*, and
+/// The number of *elem*, not including *new-axis* and the ellipsis, must
+/// match the number of axes in the array, or be at most the number of axes
+/// if there is an ellipsis. *index*, *range*, *slice*, *step*, and
 /// *new-axis* can be expressions. *index* must be of type `isize`, `usize`, or
 /// `i32`. *range* must be of type `Range<I>`, `RangeTo<I>`, `RangeFrom<I>`, or
 /// `RangeFull` where `I` is `isize`, `usize`, or `i32`. *step* must be a type
@@ -727,6 +936,11 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 ///
 /// See also [*Slicing*](crate::ArrayBase#slicing).
 ///
+/// With an ellipsis, the result is an [`EllipsisSliceInfo`] instead of a
+/// [`SliceInfo`]. For an array of a fixed dimension, the length of the
+/// ellipsis and the dimension of the result are known at compile time; for
+/// an array of dimension `IxDyn`, they are determined when slicing.
+///
 /// # Example
 ///
 /// ```
@@ -742,6 +956,26 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 /// # fn main() { let _ = laplacian; }
 /// ```
 ///
+/// # Ellipsis
+///
+/// ```
+/// use ndarray::{s, Array, NewAxis};
+///
+/// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+/// assert_eq!(a.slice(s![..., 1]), a.slice(s![.., .., 1]));
+/// assert_eq!(a.slice(s![1, ...]).shape(), &[3, 4]);
+/// assert_eq!(a.slice(s![1, ..., NewAxis, 2]), a.slice(s![1, .., NewAxis, 2]));
+/// ```
+///
+/// A second ellipsis is an error:
+///
+/// ```compile_fail
+/// use ndarray::{s, Array3};
+///
+/// let a = Array3::<f64>::zeros((2, 3, 4));
+/// a.slice(s![..., 0, ...]);
+/// ```
+///
 /// # Negative *step*
 ///
 /// The behavior of negative *step* arguments is most easily understood with
@@ -773,15 +1007,33 @@ impl_slicenextdim!((), NewAxis, Ix0, Ix1);
 /// ```
 #[macro_export]
 macro_rules! s(
+    // an ellipsis, final item
+    (@parse SliceInfo $in_dim:expr, $out_dim:expr, [$($stack:tt)*] ...) => {
+        $crate::s![@parse EllipsisSliceInfo $in_dim, $out_dim,
+                   [$($stack)* $crate::SliceInfoElem::Ellipsis]]
+    };
+    // an ellipsis, final item, trailing comma
+    (@parse SliceInfo $in_dim:expr, $out_dim:expr, [$($stack:tt)*] ... ,) => {
+        $crate::s![@parse SliceInfo $in_dim, $out_dim, [$($stack)*] ...]
+    };
+    // an ellipsis
+    (@parse SliceInfo $in_dim:expr, $out_dim:expr, [$($stack:tt)*] ..., $($t:tt)*) => {
+        $crate::s![@parse EllipsisSliceInfo $in_dim, $out_dim,
+                   [$($stack)* $crate::SliceInfoElem::Ellipsis,] $($t)*]
+    };
+    // a second ellipsis
+    (@parse EllipsisSliceInfo $in_dim:expr, $out_dim:expr, [$($stack:tt)*] ... $($t:tt)*) => {
+        compile_error!("Multiple ellipses in s![] call.")
+    };
     // convert a..b;c into @convert(a..b, c), final item
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr) => {
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr) => {
         match $r {
             r => {
                 let in_dim = $crate::SliceNextDim::next_in_dim(&r, $in_dim);
                 let out_dim = $crate::SliceNextDim::next_out_dim(&r, $out_dim);
                 #[allow(unsafe_code)]
                 unsafe {
-                    $crate::SliceInfo::new_unchecked(
+                    $crate::$kind::new_unchecked(
                         [$($stack)* $crate::s!(@convert r, $s)],
                         in_dim,
                         out_dim,
@@ -791,14 +1043,14 @@ macro_rules! s(
         }
     };
     // convert a..b into @convert(a..b), final item
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr) => {
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr) => {
         match $r {
             r => {
                 let in_dim = $crate::SliceNextDim::next_in_dim(&r, $in_dim);
                 let out_dim = $crate::SliceNextDim::next_out_dim(&r, $out_dim);
                 #[allow(unsafe_code)]
                 unsafe {
-                    $crate::SliceInfo::new_unchecked(
+                    $crate::$kind::new_unchecked(
                         [$($stack)* $crate::s!(@convert r)],
                         in_dim,
                         out_dim,
@@ -808,18 +1060,19 @@ macro_rules! s(
         }
     };
     // convert a..b;c into @convert(a..b, c), final item, trailing comma
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr ,) => {
-        $crate::s![@parse $in_dim, $out_dim, [$($stack)*] $r;$s]
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr ,) => {
+        $crate::s![@parse $kind $in_dim, $out_dim, [$($stack)*] $r;$s]
     };
     // convert a..b into @convert(a..b), final item, trailing comma
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr ,) => {
-        $crate::s![@parse $in_dim, $out_dim, [$($stack)*] $r]
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr ,) => {
+        $crate::s![@parse $kind $in_dim, $out_dim, [$($stack)*] $r]
     };
     // convert a..b;c into @convert(a..b, c)
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr, $($t:tt)*) => {
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*]
+     $r:expr;$s:expr, $($t:tt)*) => {
         match $r {
             r => {
-                $crate::s![@parse
+                $crate::s![@parse $kind
                    $crate::SliceNextDim::next_in_dim(&r, $in_dim),
                    $crate::SliceNextDim::next_out_dim(&r, $out_dim),
                    [$($stack)* $crate::s!(@convert r, $s),]
@@ -829,10 +1082,10 @@ macro_rules! s(
         }
     };
     // convert a..b into @convert(a..b)
-    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr, $($t:tt)*) => {
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr, $($t:tt)*) => {
         match $r {
             r => {
-                $crate::s![@parse
+                $crate::s![@parse $kind
                    $crate::SliceNextDim::next_in_dim(&r, $in_dim),
                    $crate::SliceNextDim::next_out_dim(&r, $out_dim),
                    [$($stack)* $crate::s!(@convert r),]
@@ -841,16 +1094,12 @@ macro_rules! s(
             }
         }
     };
-    // empty call, i.e. `s![]`
-    (@parse ::core::marker::PhantomData::<$crate::Ix0>, ::core::marker::PhantomData::<$crate::Ix0>, []) => {
+    // no more items, i.e. `s![]` or after a final ellipsis
+    (@parse $kind:ident $in_dim:expr, $out_dim:expr, [$($stack:tt)*]) => {
         {
             #[allow(unsafe_code)]
             unsafe {
-                $crate::SliceInfo::new_unchecked(
-                    [],
-                    ::core::marker::PhantomData::<$crate::Ix0>,
-                    ::core::marker::PhantomData::<$crate::Ix0>,
-                )
+                $crate::$kind::new_unchecked([$($stack)*], $in_dim, $out_dim)
             }
         }
     };
@@ -867,7 +1116,7 @@ macro_rules! s(
         )
     };
     ($($t:tt)*) => {
-        $crate::s![@parse
+        $crate::s![@parse SliceInfo
               ::core::marker::PhantomData::<$crate::Ix0>,
               ::core::marker::PhantomData::<$crate::Ix0>,
               []
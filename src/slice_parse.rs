@@ -0,0 +1,276 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of slice specifications from text.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::convert::TryFrom;
+use std::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{IxDyn, SliceInfo, SliceInfoElem};
+
+/// An error from parsing a slice specification with [`parse_slice`].
+///
+/// The elements of the specification are numbered from zero.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseSliceError {
+    /// The element is empty.
+    Empty {
+        /// The number of the element
+        element: usize,
+    },
+    /// The element is not an index, a range, `...` or `NewAxis`, or one of
+    /// its numbers is out of the range of `isize`.
+    Invalid {
+        /// The number of the element
+        element: usize,
+        /// The text of the element
+        text: String,
+    },
+    /// The step of the range is zero.
+    ZeroStep {
+        /// The number of the element
+        element: usize,
+    },
+    /// The element is an ellipsis, after a previous one.
+    MultipleEllipsis {
+        /// The number of the element
+        element: usize,
+    },
+    /// The specification indexes or slices more axes than the array has.
+    TooManyAxes {
+        /// The number of axes indexed or sliced
+        count: usize,
+        /// The number of axes of the array
+        ndim: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseSliceError {}
+
+impl fmt::Display for ParseSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSliceError::Empty { element } => {
+                write!(f, "ParseSliceError: element {} is empty", element)
+            }
+            ParseSliceError::Invalid { element, text } => {
+                write!(f, "ParseSliceError: element {} is invalid: {:?}", element, text)
+            }
+            ParseSliceError::ZeroStep { element } => {
+                write!(f, "ParseSliceError: element {} has a step of zero", element)
+            }
+            ParseSliceError::MultipleEllipsis { element } => {
+                write!(f, "ParseSliceError: element {} is a second ellipsis", element)
+            }
+            ParseSliceError::TooManyAxes { count, ndim } => write!(
+                f,
+                "ParseSliceError: {} axes are indexed but the array has {}",
+                count, ndim
+            ),
+        }
+    }
+}
+
+/// An element of a specification, before the ellipsis is expanded.
+enum Parsed {
+    Elem(SliceInfoElem),
+    Ellipsis,
+}
+
+fn parse_int(text: &str) -> Option<isize> {
+    text.trim().parse().ok()
+}
+
+/// Parse an optional bound of a range, which is absent if it is empty.
+fn parse_bound(text: &str) -> Option<Option<isize>> {
+    if text.trim().is_empty() {
+        Some(None)
+    } else {
+        parse_int(text).map(Some)
+    }
+}
+
+/// Parse `start:end:step`, where each part and the second colon are
+/// optional, like the slices of NumPy.
+fn parse_colon_range(text: &str) -> Option<(isize, Option<isize>, isize)> {
+    let mut parts = text.split(':');
+    let start = parse_bound(parts.next()?)?;
+    let end = parse_bound(parts.next()?)?;
+    let step = match parts.next() {
+        Some(step) => parse_bound(step)?,
+        None => None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    let step = step.unwrap_or(1);
+    if step >= 0 {
+        return Some((start.unwrap_or(0), end, step));
+    }
+    // a negative step takes the elements from `start` down to, but not
+    // including, `end`, which are those from `end + 1` to `start` in reverse
+    let empty = match (start, end) {
+        // no element is after the last one
+        (_, Some(-1)) => true,
+        // `end` is at or after `start` if both count from the same side
+        (Some(start), Some(end)) => (start < 0) == (end < 0) && end >= start,
+        _ => false,
+    };
+    if empty {
+        return Some((0, Some(0), step));
+    }
+    let first = match end {
+        Some(end) => end.checked_add(1)?,
+        None => 0,
+    };
+    let last = match start {
+        Some(-1) | None => None,
+        Some(start) => Some(start.checked_add(1)?),
+    };
+    Some((first, last, step))
+}
+
+/// Parse `start..end;step` or `start..=end;step`, where each part is
+/// optional, like the ranges of [`s![]`](crate::s!).
+fn parse_dot_range(text: &str) -> Option<(isize, Option<isize>, isize)> {
+    let (range, step) = match text.find(';') {
+        Some(i) => (&text[..i], parse_int(&text[i + 1..])?),
+        None => (text, 1),
+    };
+    let i = range.find("..")?;
+    let start = parse_bound(&range[..i])?;
+    let end = &range[i + 2..];
+    let end = if let Some(end) = end.strip_prefix('=') {
+        match parse_int(end)? {
+            -1 => None,
+            end => Some(end.checked_add(1)?),
+        }
+    } else {
+        parse_bound(end)?
+    };
+    Some((start.unwrap_or(0), end, step))
+}
+
+fn parse_elem(text: &str, element: usize) -> Result<Parsed, ParseSliceError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseSliceError::Empty { element });
+    }
+    if text == "..." {
+        return Ok(Parsed::Ellipsis);
+    }
+    if text == "NewAxis" {
+        return Ok(Parsed::Elem(SliceInfoElem::NewAxis));
+    }
+    if let Some(index) = parse_int(text) {
+        return Ok(Parsed::Elem(SliceInfoElem::Index(index)));
+    }
+    let range = if text.contains(':') {
+        parse_colon_range(text)
+    } else {
+        parse_dot_range(text)
+    };
+    match range {
+        Some((_, _, 0)) => Err(ParseSliceError::ZeroStep { element }),
+        Some((start, end, step)) => Ok(Parsed::Elem(SliceInfoElem::Slice { start, end, step })),
+        None => Err(ParseSliceError::Invalid { element, text: text.to_string() }),
+    }
+}
+
+/// Parse a slice specification for an array with `ndim` axes.
+///
+/// The specification is a comma separated list of elements, each of which
+/// is one of:
+///
+/// - an index, like `3` or `-1`, which counts from the back of the axis if
+///   it is negative;
+/// - a range `start:end:step`, where each part and the second colon are
+///   optional, like `1:10:2`, `:` or `::-1`;
+/// - a range `start..end;step` or `start..=end;step` in the syntax of
+///   [`s![]`](crate::s!), which is also the syntax of the `Display`
+///   implementation of [`SliceInfoElem`];
+/// - `NewAxis`, for a new axis of length one;
+/// - `...`, an ellipsis, which stands for full slices of the axes which the
+///   other elements don’t index.
+///
+/// The two kinds of range differ for a negative step. Like in NumPy, the
+/// range `start:end:step` takes the elements from `start` down to, but not
+/// including, `end`: `4:1:-1` selects the elements at 4, 3 and 2, `-1::-1`
+/// the whole axis in reverse order and `1:4:-1` nothing. Like in
+/// [`s![]`](crate::s!), the range `start..end;step` selects the elements
+/// from `start` to `end` first, and then takes them in reverse order:
+/// `1..4;-1` selects the elements at 3, 2 and 1. Without an ellipsis, the
+/// axes after those indexed are sliced fully, like in NumPy.
+///
+/// The result can be used with [`.slice()`](crate::ArrayBase::slice) and the
+/// other slicing methods of an array of dimension `IxDyn` with `ndim` axes;
+/// see [`.into_dyn()`](crate::ArrayBase::into_dyn).
+///
+/// **Errors** if an element is invalid, if the specification has more than
+/// one ellipsis, or if it indexes more than `ndim` axes; see
+/// [`ParseSliceError`].
+///
+/// ```
+/// use ndarray::{parse_slice, Array, ParseSliceError};
+///
+/// let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k).into_dyn();
+/// let info = parse_slice("1:4:2, ..., NewAxis, -1", a.ndim()).unwrap();
+/// let b = a.slice(&info);
+/// assert_eq!(b.shape(), &[2, 5, 1]);
+/// assert_eq!(b[[1, 2, 0]], 325);
+///
+/// assert_eq!(
+///     parse_slice("0, ..., 1, ...", 3).unwrap_err(),
+///     ParseSliceError::MultipleEllipsis { element: 3 },
+/// );
+/// ```
+pub fn parse_slice(
+    spec: &str,
+    ndim: usize,
+) -> Result<SliceInfo<Vec<SliceInfoElem>, IxDyn, IxDyn>, ParseSliceError> {
+    let mut parsed = Vec::new();
+    let mut ellipsis = false;
+    if !spec.trim().is_empty() {
+        for (element, text) in spec.split(',').enumerate() {
+            let elem = parse_elem(text, element)?;
+            if let Parsed::Ellipsis = elem {
+                if ellipsis {
+                    return Err(ParseSliceError::MultipleEllipsis { element });
+                }
+                ellipsis = true;
+            }
+            parsed.push(elem);
+        }
+    }
+    if !ellipsis {
+        parsed.push(Parsed::Ellipsis);
+    }
+    let count = parsed
+        .iter()
+        .filter(|elem| matches!(elem, Parsed::Elem(elem) if !elem.is_new_axis()))
+        .count();
+    if count > ndim {
+        return Err(ParseSliceError::TooManyAxes { count, ndim });
+    }
+    let mut elems = Vec::with_capacity(parsed.len() + ndim - count);
+    for elem in parsed {
+        match elem {
+            Parsed::Elem(elem) => elems.push(elem),
+            Parsed::Ellipsis => elems.extend((count..ndim).map(|_| SliceInfoElem::from(..))),
+        }
+    }
+    Ok(SliceInfo::try_from(elems).expect("IxDyn is consistent with any elements"))
+}
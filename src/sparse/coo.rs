@@ -0,0 +1,199 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Add;
+use num_traits::Zero;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+
+use super::{sparse_index_error, CsrMat};
+
+/// A sparse matrix in the coordinate format: a list of
+/// `(row, column, value)` triplets.
+///
+/// The triplets are in no particular order and may repeat a position; the
+/// values of repeated positions are summed when converting to another
+/// format. Build it with [`.push()`](Self::push) or
+/// [`CooMat::from_triplets`], and convert it with [`.to_csr()`](Self::to_csr)
+/// for computing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMat<A> {
+    shape: (usize, usize),
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    data: Vec<A>,
+}
+
+impl<A> CooMat<A> {
+    /// Create an empty matrix of shape `shape`, without stored entries.
+    pub fn new(shape: (usize, usize)) -> Self {
+        CooMat {
+            shape,
+            rows: Vec::new(),
+            cols: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Create a matrix of shape `shape` from its triplets, so that entry `k`
+    /// is `data[k]` at `(rows[k], cols[k])`.
+    ///
+    /// **Errors** if the vectors don't have the same length, or if a
+    /// position is out of bounds.
+    pub fn from_triplets(
+        shape: (usize, usize),
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        data: Vec<A>,
+    ) -> Result<Self, ShapeError> {
+        if rows.len() != data.len() || cols.len() != data.len() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        if rows.iter().any(|&i| i >= shape.0) || cols.iter().any(|&j| j >= shape.1) {
+            return Err(from_kind(ErrorKind::OutOfBounds));
+        }
+        Ok(CooMat { shape, rows, cols, data })
+    }
+
+    /// Create a matrix with the nonzero elements of `a`.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = A>,
+        A: Clone + Zero,
+    {
+        let mut coo = CooMat::new(a.dim());
+        for ((i, j), x) in a.indexed_iter() {
+            if !x.is_zero() {
+                coo.push(i, j, x.clone());
+            }
+        }
+        coo
+    }
+
+    /// Add the entry `value` at `(row, col)`.
+    ///
+    /// **Panics** if the position is out of bounds.
+    pub fn push(&mut self, row: usize, col: usize, value: A) {
+        if row >= self.shape.0 || col >= self.shape.1 {
+            sparse_index_error((row, col), self.shape);
+        }
+        self.rows.push(row);
+        self.cols.push(col);
+        self.data.push(value);
+    }
+
+    /// Return the shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Return the number of stored entries, counting repeated positions.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the row of each entry.
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// Return the column of each entry.
+    pub fn cols(&self) -> &[usize] {
+        &self.cols
+    }
+
+    /// Return the value of each entry.
+    pub fn data(&self) -> &[A] {
+        &self.data
+    }
+
+    /// Return an iterator of the entries as `(row, column, &value)`.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &A)> + '_ {
+        self.rows
+            .iter()
+            .zip(&self.cols)
+            .zip(&self.data)
+            .map(|((&i, &j), x)| (i, j, x))
+    }
+
+    /// Return the transposed matrix.
+    pub fn transpose(&self) -> CooMat<A>
+    where
+        A: Clone,
+    {
+        CooMat {
+            shape: (self.shape.1, self.shape.0),
+            rows: self.cols.clone(),
+            cols: self.rows.clone(),
+            data: self.data.clone(),
+        }
+    }
+
+    /// Convert the matrix to the compressed sparse row format, summing the
+    /// values of repeated positions.
+    pub fn to_csr(&self) -> CsrMat<A>
+    where
+        A: Clone + Add<Output = A>,
+    {
+        let nrows = self.shape.0;
+        // Sort the entries by row with a counting sort, and then each row by
+        // column.
+        let mut starts = vec![0; nrows + 1];
+        for &i in &self.rows {
+            starts[i + 1] += 1;
+        }
+        for i in 0..nrows {
+            starts[i + 1] += starts[i];
+        }
+        let mut order = vec![0; self.nnz()];
+        let mut next = starts.clone();
+        for (k, &i) in self.rows.iter().enumerate() {
+            order[next[i]] = k;
+            next[i] += 1;
+        }
+
+        let mut indptr = Vec::with_capacity(nrows + 1);
+        let mut indices = Vec::with_capacity(self.nnz());
+        let mut data: Vec<A> = Vec::with_capacity(self.nnz());
+        indptr.push(0);
+        for i in 0..nrows {
+            let row = &mut order[starts[i]..starts[i + 1]];
+            row.sort_by_key(|&k| self.cols[k]);
+            let row_start = indices.len();
+            for &k in row.iter() {
+                let j = self.cols[k];
+                if indices.len() > row_start && indices[indices.len() - 1] == j {
+                    let last = data.pop().unwrap();
+                    data.push(last + self.data[k].clone());
+                } else {
+                    indices.push(j);
+                    data.push(self.data[k].clone());
+                }
+            }
+            indptr.push(indices.len());
+        }
+        CsrMat::new_unchecked(self.shape, indptr, indices, data)
+    }
+
+    /// Return the matrix as a dense array, summing the values of repeated
+    /// positions.
+    pub fn to_dense(&self) -> Array2<A>
+    where
+        A: Clone + Add<Output = A> + Zero,
+    {
+        let mut a = Array2::<A>::zeros(self.shape);
+        for (i, j, x) in self.iter() {
+            let elt = &mut a[[i, j]];
+            *elt = elt.clone() + x.clone();
+        }
+        a
+    }
+}
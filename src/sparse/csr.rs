@@ -0,0 +1,529 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Sub};
+use num_traits::{One, Zero};
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::linalg::Dot;
+#[cfg(feature = "std")]
+use crate::linalg::LinearOperator;
+use crate::LinalgScalar;
+
+use super::{sparse_dot_shape_error, sparse_index_error, CooMat};
+
+/// A sparse matrix in the compressed sparse row (CSR) format.
+///
+/// The entries of row `i` are at the positions `indptr[i]..indptr[i + 1]`
+/// of `indices`, which has their columns in increasing order, and of
+/// `data`, which has their values. A position is stored at most once;
+/// stored entries may be zero.
+///
+/// Products with dense vectors and matrices, on either side, are
+/// implemented with [`Dot`], so that `a.dot(&x)` and `x.dot(&a)` work for a
+/// `CsrMat` `a` and a one- or two-dimensional array `x`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMat<A> {
+    shape: (usize, usize),
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<A>,
+}
+
+impl<A> CsrMat<A> {
+    /// The caller must uphold the invariants of the format.
+    pub(crate) fn new_unchecked(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<A>,
+    ) -> Self {
+        debug_assert_eq!(indptr.len(), shape.0 + 1);
+        debug_assert_eq!(indices.len(), data.len());
+        CsrMat { shape, indptr, indices, data }
+    }
+
+    /// Create a matrix of shape `shape` from its compressed sparse row
+    /// representation; see [`CsrMat`].
+    ///
+    /// **Errors** if the representation is not valid: `indptr` must have
+    /// one more element than there are rows, start at zero, be
+    /// nondecreasing and end at the length of `indices` and `data`, and the
+    /// columns of each row must be increasing and in bounds.
+    pub fn try_new(
+        shape: (usize, usize),
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<A>,
+    ) -> Result<Self, ShapeError> {
+        if indptr.len() != shape.0 + 1
+            || indptr[0] != 0
+            || indptr[shape.0] != indices.len()
+            || indices.len() != data.len()
+            || indptr.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        for w in indptr.windows(2) {
+            let row = &indices[w[0]..w[1]];
+            if row.windows(2).any(|c| c[0] >= c[1]) {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+            if row.last().map_or(false, |&j| j >= shape.1) {
+                return Err(from_kind(ErrorKind::OutOfBounds));
+            }
+        }
+        Ok(CsrMat { shape, indptr, indices, data })
+    }
+
+    /// Create a matrix of shape `shape` without stored entries.
+    pub fn zeros(shape: (usize, usize)) -> Self {
+        CsrMat {
+            shape,
+            indptr: vec![0; shape.0 + 1],
+            indices: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Create an identity matrix of size `n`.
+    pub fn eye(n: usize) -> Self
+    where
+        A: Clone + One,
+    {
+        CsrMat {
+            shape: (n, n),
+            indptr: (0..=n).collect(),
+            indices: (0..n).collect(),
+            data: vec![A::one(); n],
+        }
+    }
+
+    /// Create a matrix with the nonzero elements of `a`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray::sparse::CsrMat;
+    ///
+    /// let a = array![[1., 0., 2.], [0., 0., 3.]];
+    /// let csr = CsrMat::from_dense(&a);
+    /// assert_eq!(csr.indptr(), &[0, 2, 3]);
+    /// assert_eq!(csr.indices(), &[0, 2, 2]);
+    /// assert_eq!(csr.data(), &[1., 2., 3.]);
+    /// assert_eq!(csr.to_dense(), a);
+    /// ```
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = A>,
+        A: Clone + Zero,
+    {
+        let mut indptr = Vec::with_capacity(a.nrows() + 1);
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        indptr.push(0);
+        for row in a.rows() {
+            for (j, x) in row.indexed_iter() {
+                if !x.is_zero() {
+                    indices.push(j);
+                    data.push(x.clone());
+                }
+            }
+            indptr.push(indices.len());
+        }
+        CsrMat::new_unchecked(a.dim(), indptr, indices, data)
+    }
+
+    /// Return the matrix as a dense array.
+    pub fn to_dense(&self) -> Array2<A>
+    where
+        A: Clone + Zero,
+    {
+        let mut a = Array2::zeros(self.shape);
+        for (i, j, x) in self.iter() {
+            a[[i, j]] = x.clone();
+        }
+        a
+    }
+
+    /// Convert the matrix to the coordinate format, with the entries in row
+    /// major order.
+    pub fn to_coo(&self) -> CooMat<A>
+    where
+        A: Clone,
+    {
+        let rows = (0..self.nrows())
+            .flat_map(|i| vec![i; self.indptr[i + 1] - self.indptr[i]])
+            .collect();
+        CooMat::from_triplets(self.shape, rows, self.indices.clone(), self.data.clone())
+            .expect("valid matrix")
+    }
+
+    /// Return the shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Return the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.shape.0
+    }
+
+    /// Return the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.shape.1
+    }
+
+    /// Return the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the offsets of the rows in `indices` and `data`.
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// Return the column of each stored entry.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Return the value of each stored entry.
+    pub fn data(&self) -> &[A] {
+        &self.data
+    }
+
+    /// Return the value of each stored entry, for modification in place.
+    pub fn data_mut(&mut self) -> &mut [A] {
+        &mut self.data
+    }
+
+    /// Return the columns and values of the stored entries of row `i`.
+    ///
+    /// **Panics** if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> (&[usize], &[A]) {
+        if i >= self.nrows() {
+            sparse_index_error((i, 0), self.shape);
+        }
+        let range = self.indptr[i]..self.indptr[i + 1];
+        (&self.indices[range.clone()], &self.data[range])
+    }
+
+    /// Return a reference to the stored entry at `(i, j)`, or `None` if
+    /// there is none.
+    ///
+    /// **Panics** if the position is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&A> {
+        if i >= self.nrows() || j >= self.ncols() {
+            sparse_index_error((i, j), self.shape);
+        }
+        let (indices, data) = self.row(i);
+        indices.binary_search(&j).ok().map(|k| &data[k])
+    }
+
+    /// Return an iterator of the stored entries as `(row, column, &value)`,
+    /// in row major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &A)> + '_ {
+        (0..self.nrows()).flat_map(move |i| {
+            let (indices, data) = self.row(i);
+            indices.iter().zip(data).map(move |(&j, x)| (i, j, x))
+        })
+    }
+
+    /// Return the transposed matrix.
+    pub fn transpose(&self) -> CsrMat<A>
+    where
+        A: Clone,
+    {
+        let (nrows, ncols) = self.shape;
+        let mut indptr = vec![0; ncols + 1];
+        for &j in &self.indices {
+            indptr[j + 1] += 1;
+        }
+        for j in 0..ncols {
+            indptr[j + 1] += indptr[j];
+        }
+        // Visiting the rows in order keeps the new rows sorted.
+        let mut next = indptr.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut positions = vec![0; self.nnz()];
+        for i in 0..nrows {
+            for k in self.indptr[i]..self.indptr[i + 1] {
+                let j = self.indices[k];
+                indices[next[j]] = i;
+                positions[next[j]] = k;
+                next[j] += 1;
+            }
+        }
+        let data = positions.iter().map(|&k| self.data[k].clone()).collect();
+        CsrMat::new_unchecked((ncols, nrows), indptr, indices, data)
+    }
+
+    /// Return a matrix with the same structure and the values of `f`
+    /// applied to each stored entry.
+    pub fn map<B, F>(&self, f: F) -> CsrMat<B>
+    where
+        F: FnMut(&A) -> B,
+    {
+        CsrMat {
+            shape: self.shape,
+            indptr: self.indptr.clone(),
+            indices: self.indices.clone(),
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Perform a product of `self` with the dense vector or matrix `rhs`;
+    /// see [`Dot`].
+    ///
+    /// **Panics** if the shapes are incompatible.
+    pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
+    where
+        Self: Dot<Rhs>,
+    {
+        Dot::dot(self, rhs)
+    }
+
+    /// Return the dot product of row `i` and `x`.
+    pub(crate) fn row_dot<S>(&self, i: usize, x: &ArrayBase<S, Ix1>) -> A
+    where
+        S: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let (indices, data) = self.row(i);
+        indices
+            .iter()
+            .zip(data)
+            .fold(A::zero(), |acc, (&j, &a)| acc + a * x[j])
+    }
+
+    /// Combine the entries of `self` and `rhs` position by position with
+    /// `f`, which gets the entry of each operand if it's stored and returns
+    /// the new entry, if any.
+    fn merge<B, F>(&self, rhs: &CsrMat<A>, op: &str, mut f: F) -> CsrMat<B>
+    where
+        F: FnMut(Option<&A>, Option<&A>) -> Option<B>,
+    {
+        if self.shape != rhs.shape {
+            panic!(
+                "ndarray: could not {} sparse matrices of shapes {:?} and {:?}",
+                op, self.shape, rhs.shape
+            );
+        }
+        let mut indptr = Vec::with_capacity(self.nrows() + 1);
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        indptr.push(0);
+        for i in 0..self.nrows() {
+            let (ja, xa) = self.row(i);
+            let (jb, xb) = rhs.row(i);
+            let (mut ka, mut kb) = (0, 0);
+            while ka < ja.len() || kb < jb.len() {
+                let order = match (ja.get(ka), jb.get(kb)) {
+                    (Some(a), Some(b)) => a.cmp(b),
+                    (Some(_), None) => Ordering::Less,
+                    _ => Ordering::Greater,
+                };
+                let (j, value) = match order {
+                    Ordering::Less => (ja[ka], f(Some(&xa[ka]), None)),
+                    Ordering::Greater => (jb[kb], f(None, Some(&xb[kb]))),
+                    Ordering::Equal => (ja[ka], f(Some(&xa[ka]), Some(&xb[kb]))),
+                };
+                if order != Ordering::Greater {
+                    ka += 1;
+                }
+                if order != Ordering::Less {
+                    kb += 1;
+                }
+                if let Some(value) = value {
+                    indices.push(j);
+                    data.push(value);
+                }
+            }
+            indptr.push(indices.len());
+        }
+        CsrMat::new_unchecked(self.shape, indptr, indices, data)
+    }
+}
+
+/// Elementwise addition; the result stores the union of the positions of
+/// the operands.
+///
+/// **Panics** if the shapes are not equal.
+impl<'b, A> Add<&'b CsrMat<A>> for &CsrMat<A>
+where
+    A: Clone + Add<Output = A>,
+{
+    type Output = CsrMat<A>;
+
+    fn add(self, rhs: &'b CsrMat<A>) -> CsrMat<A> {
+        self.merge(rhs, "add", |a, b| match (a, b) {
+            (Some(a), Some(b)) => Some(a.clone() + b.clone()),
+            (a, b) => a.or(b).cloned(),
+        })
+    }
+}
+
+/// Elementwise subtraction; the result stores the union of the positions
+/// of the operands.
+///
+/// **Panics** if the shapes are not equal.
+impl<'b, A> Sub<&'b CsrMat<A>> for &CsrMat<A>
+where
+    A: Clone + Sub<Output = A> + Zero,
+{
+    type Output = CsrMat<A>;
+
+    fn sub(self, rhs: &'b CsrMat<A>) -> CsrMat<A> {
+        self.merge(rhs, "subtract", |a, b| {
+            let zero = A::zero();
+            Some(a.unwrap_or(&zero).clone() - b.unwrap_or(&zero).clone())
+        })
+    }
+}
+
+/// Elementwise multiplication; the result stores the intersection of the
+/// positions of the operands.
+///
+/// **Panics** if the shapes are not equal.
+impl<'b, A> Mul<&'b CsrMat<A>> for &CsrMat<A>
+where
+    A: Clone + Mul<Output = A>,
+{
+    type Output = CsrMat<A>;
+
+    fn mul(self, rhs: &'b CsrMat<A>) -> CsrMat<A> {
+        self.merge(rhs, "multiply", |a, b| match (a, b) {
+            (Some(a), Some(b)) => Some(a.clone() * b.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// Sparse matrix as the operator of the iterative solvers.
+///
+/// **Panics** if the lengths of `x` and `y` don't match the matrix.
+#[cfg(feature = "std")]
+impl<A> LinearOperator<A> for CsrMat<A>
+where
+    A: LinalgScalar,
+{
+    fn apply(&self, x: ArrayView1<'_, A>, mut y: ArrayViewMut1<'_, A>) {
+        let (m, n) = self.shape;
+        if n != x.len() || m != y.len() {
+            sparse_dot_shape_error(m, n, x.len(), 1);
+        }
+        for (i, y) in y.iter_mut().enumerate() {
+            *y = self.row_dot(i, &x);
+        }
+    }
+}
+
+/// Sparse matrix times dense vector.
+///
+/// If `self` is *M* × *N*, then `rhs` is *N* and the result is *M*.
+///
+/// **Panics** if the shapes are incompatible.
+impl<A, S> Dot<ArrayBase<S, Ix1>> for CsrMat<A>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Array1<A>;
+
+    fn dot(&self, rhs: &ArrayBase<S, Ix1>) -> Array1<A> {
+        let (m, n) = self.shape;
+        if n != rhs.len() {
+            sparse_dot_shape_error(m, n, rhs.len(), 1);
+        }
+        Array1::from_shape_fn(m, |i| self.row_dot(i, rhs))
+    }
+}
+
+/// Sparse matrix times dense matrix.
+///
+/// If `self` is *M* × *N*, then `rhs` is *N* × *K* and the result is
+/// *M* × *K*.
+///
+/// **Panics** if the shapes are incompatible.
+impl<A, S> Dot<ArrayBase<S, Ix2>> for CsrMat<A>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Array2<A>;
+
+    fn dot(&self, rhs: &ArrayBase<S, Ix2>) -> Array2<A> {
+        let (m, n) = self.shape;
+        let (n2, k) = rhs.dim();
+        if n != n2 {
+            sparse_dot_shape_error(m, n, n2, k);
+        }
+        let mut out = Array2::zeros((m, k));
+        for (i, j, &a) in self.iter() {
+            out.row_mut(i).scaled_add(a, &rhs.row(j));
+        }
+        out
+    }
+}
+
+/// Dense vector times sparse matrix, where the vector is a row vector.
+///
+/// If `self` is *M*, then `rhs` is *M* × *N* and the result is *N*.
+///
+/// **Panics** if the shapes are incompatible.
+impl<A, S> Dot<CsrMat<A>> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Array1<A>;
+
+    fn dot(&self, rhs: &CsrMat<A>) -> Array1<A> {
+        let (m, n) = rhs.shape;
+        if self.len() != m {
+            sparse_dot_shape_error(1, self.len(), m, n);
+        }
+        let mut out = Array1::zeros(n);
+        for (i, j, &a) in rhs.iter() {
+            out[j] = out[j] + self[i] * a;
+        }
+        out
+    }
+}
+
+/// Dense matrix times sparse matrix.
+///
+/// If `self` is *M* × *N*, then `rhs` is *N* × *K* and the result is
+/// *M* × *K*.
+///
+/// **Panics** if the shapes are incompatible.
+impl<A, S> Dot<CsrMat<A>> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Array2<A>;
+
+    fn dot(&self, rhs: &CsrMat<A>) -> Array2<A> {
+        let (m, n) = self.dim();
+        let (n2, k) = rhs.shape;
+        if n != n2 {
+            sparse_dot_shape_error(m, n, n2, k);
+        }
+        let mut out = Array2::zeros((m, k));
+        for (i, j, &a) in rhs.iter() {
+            out.column_mut(j).scaled_add(a, &self.column(i));
+        }
+        out
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2014-2020 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sparse matrices.
+//!
+//! Two storage formats are provided:
+//!
+//! - [`CooMat`], the coordinate format, a list of `(row, column, value)`
+//!   triplets. It is convenient for building a matrix entry by entry.
+//! - [`CsrMat`], the compressed sparse row format. It is the format for
+//!   computing with: matrix-vector and matrix-matrix products with dense
+//!   arrays through [`Dot`](crate::linalg::Dot), transposition and
+//!   elementwise arithmetic.
+//!
+//! Both convert from and to [`Array2`](crate::Array2).
+//!
+//! ```
+//! use ndarray::array;
+//! use ndarray::sparse::CooMat;
+//!
+//! // the Laplacian of the path graph 0 - 1 - 2
+//! let mut coo = CooMat::new((3, 3));
+//! for &(i, j) in &[(0, 1), (1, 2)] {
+//!     coo.push(i, j, -1.);
+//!     coo.push(j, i, -1.);
+//!     coo.push(i, i, 1.);
+//!     coo.push(j, j, 1.);
+//! }
+//! let laplacian = coo.to_csr();
+//! assert_eq!(laplacian.nnz(), 7);
+//! assert_eq!(laplacian.dot(&array![1., 2., 4.]), array![-1., -1., 2.]);
+//! ```
+
+mod coo;
+mod csr;
+
+pub use self::coo::CooMat;
+pub use self::csr::CsrMat;
+
+#[cold]
+#[inline(never)]
+pub(crate) fn sparse_dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {} and {} × {} are not compatible for matrix multiplication",
+        m, k, k2, n
+    );
+}
+
+#[cold]
+#[inline(never)]
+fn sparse_index_error(index: (usize, usize), shape: (usize, usize)) -> ! {
+    panic!(
+        "ndarray: index {:?} is out of bounds for a sparse matrix of shape {:?}",
+        index, shape
+    );
+}
@@ -13,6 +13,7 @@ use itertools::{zip, Itertools};
 use ndarray::prelude::*;
 use ndarray::{arr3, rcarr2};
 use ndarray::indices;
+use ndarray::IndexMode;
 use ndarray::{Slice, SliceInfo, SliceInfoElem};
 use num_complex::Complex;
 use std::convert::TryFrom;
@@ -207,6 +208,223 @@ fn test_slice_array_fixed() {
     arr.view().slice_collapse(info2);
 }
 
+#[test]
+fn test_slice_ellipsis() {
+    let a = Array::from_shape_fn((2, 3, 4, 5), |(i, j, k, l)| 1000 * i + 100 * j + 10 * k + l);
+
+    // fixed dimensions are resolved at compile time
+    let b: ArrayView3<_> = a.slice(s![..., 1]);
+    assert_eq!(b, a.slice(s![.., .., .., 1]));
+    let c: ArrayView3<_> = a.slice(s![1, ..., 2..4;-1]);
+    assert_eq!(c, a.slice(s![1, .., .., 2..4;-1]));
+    let d: ArrayView4<_> = a.slice(s![0, ..., NewAxis, 1..]);
+    assert_eq!(d.shape(), &[3, 4, 1, 4]);
+    let e: ArrayView4<_> = a.slice(s![...]);
+    assert_eq!(e, a);
+    let f: ArrayView0<_> = a.slice(s![1, 2, ..., 3, 4,]);
+    assert_eq!(f[()], 1234);
+
+    // IxDyn arrays are resolved at runtime
+    let dyn_a = a.clone().into_dyn();
+    assert_eq!(dyn_a.slice(s![..., 1]), b.into_dyn());
+    assert_eq!(dyn_a.slice(s![1, 2, ..., 3, 4]).shape(), &[]);
+    let info = SliceInfo::<_, IxDyn, IxDyn>::try_from(vec![
+        SliceInfoElem::from(0),
+        SliceInfoElem::Ellipsis,
+        SliceInfoElem::from(NewAxis),
+    ])
+    .unwrap();
+    assert_eq!(dyn_a.slice(&info).shape(), &[3, 4, 5, 1]);
+    assert_eq!(format!("{}", info.iter().format(", ")), "0, ..., NewAxis");
+
+    let mut g = a.clone();
+    g.slice_mut(s![.., 0, ...]).fill(0);
+    assert_eq!(g.slice(s![.., 0, .., ..]).sum(), 0);
+    g.slice_collapse(s![1, ..., 3]);
+    assert_eq!(g.shape(), &[1, 3, 4, 1]);
+    let (mut first, mut rest) = g.multi_slice_mut((s![..., 0, ..], s![..., 1.., ..]));
+    first.fill(1);
+    rest.fill(2);
+    assert_eq!(g.sum(), 3 + 2 * 9);
+}
+
+#[test]
+fn test_sliceinfo_ellipsis_dims() {
+    let elems = vec![
+        SliceInfoElem::from(1),
+        SliceInfoElem::Ellipsis,
+        SliceInfoElem::from(..),
+    ];
+    assert!(SliceInfo::<_, Ix3, Ix2>::try_from(elems.clone()).is_ok());
+    assert!(SliceInfo::<_, Ix3, Ix1>::try_from(elems.clone()).is_err());
+    assert!(SliceInfo::<_, Ix1, IxDyn>::try_from(elems.clone()).is_err());
+    assert!(SliceInfo::<_, IxDyn, Ix2>::try_from(elems.clone()).is_err());
+    let info = SliceInfo::<_, Ix3, Ix2>::try_from(elems).unwrap();
+    let a = Array3::<u8>::zeros((2, 3, 4));
+    assert_eq!(a.slice(&info).shape(), &[3, 4]);
+
+    let two = vec![SliceInfoElem::Ellipsis, SliceInfoElem::Ellipsis];
+    assert!(SliceInfo::<_, IxDyn, IxDyn>::try_from(two).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_slice_ellipsis_too_many_axes() {
+    let a = Array2::<u8>::zeros((2, 3)).into_dyn();
+    a.slice(s![0, ..., 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_slice_multiple_ellipses_dyn() {
+    let a = Array2::<u8>::zeros((2, 3)).into_dyn();
+    a.slice(&[SliceInfoElem::Ellipsis, SliceInfoElem::from(0), SliceInfoElem::Ellipsis][..]);
+}
+
+#[test]
+fn test_try_slice() {
+    use ndarray::{ErrorKind, IndexOutOfBounds};
+
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+    assert_eq!(a.try_slice(s![1.., -1]).unwrap(), array![13, 23]);
+    assert_eq!(a.try_slice(s![..;-1, 4..]).unwrap().shape(), &[3, 0]);
+    a.try_slice_mut(s![2, 1..2]).unwrap().fill(99);
+    assert_eq!(a[[2, 1]], 99);
+
+    let err = a.try_slice(s![.., 2..5]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    assert_eq!(err.out_of_bounds(), Some(IndexOutOfBounds { axis: 1, index: 5, len: 4 }));
+    let err = a.try_slice_mut(s![-4, ..]).unwrap_err();
+    assert_eq!(err.out_of_bounds(), Some(IndexOutOfBounds { axis: 0, index: -4, len: 3 }));
+    let err = a.try_slice(s![NewAxis, 1, 4]).unwrap_err();
+    assert_eq!(err.out_of_bounds(), Some(IndexOutOfBounds { axis: 1, index: 4, len: 4 }));
+
+    let d = a.into_dyn();
+    let zero_step = SliceInfoElem::Slice { start: 0, end: None, step: 0 };
+    let err = d.try_slice(&[zero_step, SliceInfoElem::from(..)][..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    let err = d.try_slice(&[SliceInfoElem::from(0)][..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.out_of_bounds(), None);
+}
+
+#[test]
+fn test_try_index_axis_select() {
+    use ndarray::{ErrorKind, IndexOutOfBounds};
+
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(a.try_index_axis(Axis(1), 2).unwrap(), array![3, 6]);
+    let err = a.try_index_axis(Axis(0), 2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    assert_eq!(err.out_of_bounds(), Some(IndexOutOfBounds { axis: 0, index: 2, len: 2 }));
+    let err = a.try_index_axis(Axis(2), 0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    assert_eq!(err.out_of_bounds(), None);
+
+    assert_eq!(a.try_select(Axis(1), &[2, 2, 0]).unwrap(), array![[3, 3, 1], [6, 6, 4]]);
+    assert_eq!(a.try_select(Axis(0), &[]).unwrap().shape(), &[0, 3]);
+    let err = a.try_select(Axis(1), &[0, 5, 3]).unwrap_err();
+    assert_eq!(err.out_of_bounds(), Some(IndexOutOfBounds { axis: 1, index: 5, len: 3 }));
+    assert_eq!(err.to_string(),
+               "ShapeError/OutOfBounds: out of bounds indexing: \
+                index 5 is out of bounds for axis 1 with length 3");
+    assert!(a.try_select(Axis(2), &[0]).is_err());
+
+    // an index past `isize::MAX` is reported as `isize::MAX`
+    let max = IndexOutOfBounds { axis: 0, index: isize::MAX, len: 2 };
+    assert_eq!(a.try_index_axis(Axis(0), usize::MAX).unwrap_err().out_of_bounds(), Some(max));
+    assert_eq!(a.try_select(Axis(0), &[0, usize::MAX]).unwrap_err().out_of_bounds(), Some(max));
+}
+
+#[test]
+fn test_try_broadcast_to() {
+    use ndarray::ErrorKind;
+
+    let a = array![[1], [2]];
+    assert_eq!(a.try_broadcast_to((2, 3)).unwrap(), array![[1, 1, 1], [2, 2, 2]]);
+    assert_eq!(a.try_broadcast_to((4, 2, 2)).unwrap().shape(), &[4, 2, 2]);
+    let err = a.try_broadcast_to((3, 3)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = a.try_broadcast_to((2, usize::MAX)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+}
+
+#[test]
+fn test_parse_slice() {
+    use ndarray::parse_slice;
+
+    let arr = Array::from_shape_fn((4, 5, 6), |(i, j, k)| 100 * i + 10 * j + k).into_dyn();
+    let cases = [
+        ("1:4:2, ..., NewAxis, -1", s![1..4;2, .., NewAxis, -1].as_ref().to_vec()),
+        ("1..3, ::-1, 2", s![1..3, ..;-1, 2].as_ref().to_vec()),
+        ("..=2;2 , -2:", s![..=2;2, -2.., ..].as_ref().to_vec()),
+        ("..., 0", s![.., .., 0].as_ref().to_vec()),
+        ("NewAxis, ...", s![NewAxis, .., .., ..].as_ref().to_vec()),
+        ("", s![.., .., ..].as_ref().to_vec()),
+        // a negative step counts down from `start`, like in NumPy
+        ("..., 4:1:-1", s![.., .., 2..5;-1].as_ref().to_vec()),
+        ("-1::-1, :-3:-1, -2::-2", s![..;-1, -2..;-1, ..-1;-2].as_ref().to_vec()),
+        ("1:4:-1, 3:-1:-1, -2:-1:-1", s![0..0;-1, 0..0;-1, 0..0;-1].as_ref().to_vec()),
+        // but selects the range first in the syntax of `s![]`
+        ("1..4;-1, ..;-2", s![1..4;-1, ..;-2, ..].as_ref().to_vec()),
+    ];
+    for (spec, expected) in &cases {
+        let info = parse_slice(spec, 3).unwrap();
+        assert_eq!(info.as_ref(), &expected[..], "{}", spec);
+        let expected = SliceInfo::<_, IxDyn, IxDyn>::try_from(expected.clone()).unwrap();
+        assert_eq!(arr.slice(&info), arr.slice(&expected));
+    }
+
+    let reversed = |spec| {
+        let info = parse_slice(spec, 3).unwrap();
+        arr.slice(&info).slice_move(s![..1, ..1, ..]).iter().cloned().collect::<Vec<_>>()
+    };
+    assert_eq!(reversed("..., 4:1:-1"), vec![4, 3, 2]);
+    assert_eq!(reversed("..., -1::-1"), vec![5, 4, 3, 2, 1, 0]);
+    assert_eq!(reversed("..., 1:4:-1"), Vec::<usize>::new());
+    assert_eq!(reversed("..., 1..4;-1"), vec![3, 2, 1]);
+
+    // the Display format parses back
+    let info = parse_slice("-3..;-2, 2..=4, NewAxis, 1", 3).unwrap();
+    let text = info.iter().map(|elem| elem.to_string()).join(", ");
+    assert_eq!(parse_slice(&text, 3).unwrap().as_ref(), info.as_ref());
+
+    let mut view = arr.view();
+    view.slice_collapse(&parse_slice("1, ..., 2:", 3).unwrap());
+    assert_eq!(view.shape(), &[1, 5, 4]);
+    let moved = arr.clone().slice_move(&parse_slice("-1", 3).unwrap());
+    assert_eq!(moved, arr.index_axis(Axis(0), 3));
+}
+
+#[test]
+fn test_parse_slice_errors() {
+    use ndarray::{parse_slice, ParseSliceError};
+
+    let err = |spec| parse_slice(spec, 2).unwrap_err();
+    assert_eq!(err("0,,1"), ParseSliceError::Empty { element: 1 });
+    assert_eq!(err("0, "), ParseSliceError::Empty { element: 1 });
+    assert_eq!(
+        err("1:2:3:4"),
+        ParseSliceError::Invalid { element: 0, text: "1:2:3:4".to_string() }
+    );
+    assert_eq!(
+        err(":, x"),
+        ParseSliceError::Invalid { element: 1, text: "x".to_string() }
+    );
+    // the exclusive end of `..=isize::MAX` overflows
+    let max = format!("0..={}", isize::MAX);
+    assert_eq!(err(&max), ParseSliceError::Invalid { element: 0, text: max.clone() });
+    assert_eq!(
+        err("99999999999999999999999:"),
+        ParseSliceError::Invalid { element: 0, text: "99999999999999999999999:".to_string() }
+    );
+    assert_eq!(err("::0"), ParseSliceError::ZeroStep { element: 0 });
+    assert_eq!(err("..;0"), ParseSliceError::ZeroStep { element: 0 });
+    assert_eq!(err("..., ..., 0"), ParseSliceError::MultipleEllipsis { element: 1 });
+    assert_eq!(err("0, NewAxis, 1, 2"), ParseSliceError::TooManyAxes { count: 3, ndim: 2 });
+    assert_eq!(err("0, ...,...").to_string(), "ParseSliceError: element 2 is a second ellipsis");
+}
+
 #[test]
 fn test_slice_dyninput_array_fixed() {
     let mut arr = Array3::<f64>::zeros((5, 2, 5)).into_dyn();
@@ -724,6 +942,331 @@ fn test_select_1d() {
     assert_eq!(r3, arr1(&[]));
 }
 
+#[test]
+fn mask_select() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let even = a.mapv(|x| x % 2 == 0);
+    assert_eq!(a.mask_select(&even), arr1(&[0, 2, 4, 6, 8, 10]));
+    // logical order, also for other memory layouts
+    let t = a.t();
+    assert_eq!(t.mask_select(&t.mapv(|x| x % 2 == 0)), arr1(&[0, 4, 8, 2, 6, 10]));
+    let rev = a.slice(s![..;-1, ..]);
+    assert_eq!(rev.mask_select(&rev.mapv(|x| x > 5)), arr1(&[8, 9, 10, 11, 6, 7]));
+    // broadcast masks
+    assert_eq!(a.mask_select(&arr1(&[false, true, false, false])), arr1(&[1, 5, 9]));
+    assert_eq!(a.mask_select(&arr2(&[[true], [false], [true]])).len(), 8);
+    assert_eq!(a.mask_select(&arr0(true)), Array::from_iter(0..12));
+    assert_eq!(a.mask_select(&Array2::from_elem((3, 4), false)), arr1(&[]));
+}
+
+#[test]
+#[should_panic]
+fn mask_select_broadcast_error() {
+    Array2::<i32>::zeros((3, 4)).mask_select(&arr1(&[true, false, true]));
+}
+
+#[test]
+fn mask_assign() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let mask = a.mapv(|x| x % 3 == 0);
+    a.mask_assign(&mask, -1);
+    assert_eq!(a.mask_select(&mask), arr1(&[-1; 4]));
+    a.mask_assign(&mask, &arr1(&[10, 20, 30, 40]));
+    assert_eq!(a, arr2(&[[10, 1, 2, 20], [4, 5, 30, 7], [8, 40, 10, 11]]));
+
+    // in logical order through a transposed view
+    let mut b = Array2::<i32>::zeros((2, 3));
+    b.view_mut().reversed_axes().mask_assign(&arr1(&[true, false]), &arr1(&[1, 2, 3]).view());
+    assert_eq!(b, arr2(&[[1, 2, 3], [0, 0, 0]]));
+
+    let mut c = arr1(&[1.5, f64::NAN, 2.5]);
+    let nan = c.mapv(f64::is_nan);
+    c.mask_assign(&nan, 0.);
+    assert_eq!(c, arr1(&[1.5, 0., 2.5]));
+}
+
+#[test]
+#[should_panic]
+fn mask_assign_wrong_count() {
+    let mut a = arr1(&[1, 2, 3]);
+    a.mask_assign(&arr1(&[true, false, true]), &arr1(&[1, 2, 3]));
+}
+
+#[test]
+fn compress() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i * 8 + j * 2 + k) as i32);
+    let c = a.compress(Axis(1), &arr1(&[true, false, false, true]));
+    assert_eq!(c, a.select(Axis(1), &[0, 3]));
+    assert_eq!(a.compress(Axis(0), &arr1(&[false; 3])).shape(), &[0, 4, 2]);
+    let v = arr1(&[1, 2, 3]);
+    assert_eq!(v.compress(Axis(0), &arr1(&[true, false, true])), arr1(&[1, 3]));
+}
+
+#[test]
+#[should_panic]
+fn compress_wrong_length() {
+    arr1(&[1, 2, 3]).compress(Axis(0), &arr1(&[true, false]));
+}
+
+#[test]
+fn take_put() {
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as i32);
+    assert_eq!(a.take(&[3, 0, 3], Axis(0), IndexMode::Raise), a.select(Axis(0), &[3, 0, 3]));
+    assert_eq!(a.take(&[-1, -4], Axis(0), IndexMode::Raise), a.select(Axis(0), &[3, 0]));
+    assert_eq!(a.take(&[4, -5, 7], Axis(0), IndexMode::Wrap), a.select(Axis(0), &[0, 3, 3]));
+    assert_eq!(a.take(&[-2, 1, 9], Axis(1), IndexMode::Clip), a.select(Axis(1), &[0, 1, 2]));
+    assert_eq!(a.take(&[7usize], Axis(1), IndexMode::Wrap), a.select(Axis(1), &[1]));
+    assert_eq!(a.take::<usize>(&[], Axis(1), IndexMode::Raise).shape(), &[4, 0]);
+
+    let mut b = a.clone();
+    b.put(&[-1, 0], &arr2(&[[-1, -2, -3]]), Axis(0), IndexMode::Raise);
+    assert_eq!(b.row(0), arr1(&[-1, -2, -3]));
+    assert_eq!(b.row(3), arr1(&[-1, -2, -3]));
+    assert_eq!(b.slice(s![1..3, ..]), a.slice(s![1..3, ..]));
+    // the last of repeated indices wins
+    let mut c = Array1::zeros(3);
+    c.put(&[1, 1, 4], &arr1(&[1, 2, 3]), Axis(0), IndexMode::Wrap);
+    assert_eq!(c, arr1(&[0, 3, 0]));
+}
+
+#[test]
+#[should_panic(expected = "index -5 is out of bounds for axis 0 with length 4")]
+fn take_out_of_bounds() {
+    Array2::<i32>::zeros((4, 3)).take(&[-5], Axis(0), IndexMode::Raise);
+}
+
+#[test]
+#[should_panic]
+fn take_wrap_empty_axis() {
+    Array2::<i32>::zeros((0, 3)).take(&[0], Axis(0), IndexMode::Wrap);
+}
+
+#[test]
+fn take_put_along_axis() {
+    let a = arr2(&[[3, 1, 2], [6, 5, 4]]);
+    let order = arr2(&[[1usize, 2, 0], [2, 1, 0]]);
+    let sorted = a.take_along_axis(&order, Axis(1));
+    assert_eq!(sorted, arr2(&[[1, 2, 3], [4, 5, 6]]));
+    let mut b = Array2::zeros((2, 3));
+    b.put_along_axis(&order, &sorted, Axis(1));
+    assert_eq!(b, a);
+
+    // broadcasting indices and arrays
+    let first_last = arr2(&[[0isize, -1]]);
+    assert_eq!(a.take_along_axis(&first_last, Axis(1)), arr2(&[[3, 2], [6, 4]]));
+    assert_eq!(a.take_along_axis(&arr2(&[[1usize], [0]]), Axis(0)), arr2(&[[6, 5, 4], [3, 1, 2]]));
+    let column = arr2(&[[10], [20]]);
+    let rows = arr2(&[[1usize, 0, 1]]);
+    assert_eq!(column.take_along_axis(&rows, Axis(0)), arr2(&[[20, 10, 20]]));
+
+    let a3 = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i32);
+    let argmax = Array::from_elem((2, 1, 4), 2usize);
+    assert_eq!(a3.take_along_axis(&argmax, Axis(1)), a3.slice(s![.., 2..3, ..]));
+
+    let mut c = Array2::<i32>::zeros((2, 3));
+    c.put_along_axis(&arr2(&[[0usize], [2]]), &arr0(1), Axis(1));
+    assert_eq!(c, arr2(&[[1, 0, 0], [0, 0, 1]]));
+    c.put_along_axis(&arr2(&[[1usize, 1]]), &arr1(&[7, 8]), Axis(1));
+    assert_eq!(c, arr2(&[[1, 8, 0], [0, 8, 1]]));
+}
+
+#[test]
+#[should_panic]
+fn take_along_axis_broadcast_error() {
+    let a = Array2::<i32>::zeros((2, 3));
+    a.take_along_axis(&Array2::<usize>::zeros((3, 1)), Axis(1));
+}
+
+#[test]
+fn take_outer() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i * 8 + j * 2 + k) as i32);
+    let rows = vec![2, 0];
+    let cols = vec![-1, 1, 1];
+    let outer = a.take_outer(&[Some(&rows[..]), Some(&cols[..])], IndexMode::Raise);
+    assert_eq!(outer.shape(), &[2, 3, 2]);
+    let expected = a
+        .select(Axis(0), &[2, 0])
+        .select(Axis(1), &[3, 1, 1]);
+    assert_eq!(outer, expected);
+    let middle = a.take_outer(&[None, Some([5, -6]), Some([1, 1])], IndexMode::Wrap);
+    assert_eq!(middle, a.select(Axis(1), &[1, 2]).select(Axis(2), &[1, 1]));
+    assert_eq!(a.take_outer::<isize, Vec<_>>(&[], IndexMode::Raise), a);
+}
+
+#[test]
+#[should_panic]
+fn take_outer_too_many_lists() {
+    arr1(&[1, 2]).take_outer(&[Some([0]), Some([0])], IndexMode::Raise);
+}
+
+#[test]
+fn sort_axis() {
+    let mut a = arr2(&[[3, 1, 2], [5, 6, 4], [0, 0, -1]]);
+    let mut b = a.clone();
+    a.sort_axis(Axis(1));
+    assert_eq!(a, arr2(&[[1, 2, 3], [4, 5, 6], [-1, 0, 0]]));
+    // non-contiguous lanes, and lanes with a negative stride
+    b.sort_axis(Axis(0));
+    assert_eq!(b, arr2(&[[0, 0, -1], [3, 1, 2], [5, 6, 4]]));
+    let mut c = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    c.slice_mut(s![.., ..;-1, ..]).sort_axis_unstable(Axis(1));
+    let expected = arr2(&[[8, 9, 10, 11], [4, 5, 6, 7], [0, 1, 2, 3]]);
+    assert_eq!(c.index_axis(Axis(0), 0), expected);
+    c.sort_axis_unstable(Axis(1));
+    assert_eq!(c, Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap());
+
+    let mut empty = Array2::<i32>::zeros((0, 3));
+    empty.sort_axis(Axis(0));
+    empty.sort_axis(Axis(1));
+}
+
+#[test]
+fn sort_axis_by_stable() {
+    // sort by the first element only; the second records the original order
+    let keys = [2, 1, 2, 0, 1, 2];
+    let pairs = Array::from_iter(keys.iter().enumerate().map(|(i, &k)| (k, i)));
+    let mut a = pairs.clone().into_shape((6, 1)).unwrap();
+    a.sort_axis_by(Axis(0), |x, y| x.0.cmp(&y.0));
+    let expected = [(0, 3), (1, 1), (1, 4), (2, 0), (2, 2), (2, 5)];
+    assert_eq!(a.column(0).to_vec(), expected);
+    let mut s = pairs.clone();
+    s.slice_mut(s![..;-1]).sort_axis_by(Axis(0), |x, y| y.0.cmp(&x.0));
+    assert_eq!(s.to_vec(), expected);
+    let mut u = pairs.clone();
+    u.sort_axis_unstable_by(Axis(0), |x, y| x.0.cmp(&y.0));
+    assert_eq!(u.map(|x| x.0).to_vec(), vec![0, 1, 1, 2, 2, 2]);
+
+    let order = pairs.argsort_axis_by(Axis(0), |x, y| x.0.cmp(&y.0));
+    assert_eq!(order, arr1(&[3, 1, 4, 0, 2, 5]));
+}
+
+#[test]
+fn argsort_axis() {
+    let a = arr2(&[[10, 30, 20, 30], [60, 40, 50, 40]]);
+    let rows = a.argsort_axis(Axis(1));
+    assert_eq!(rows, arr2(&[[0, 2, 1, 3], [1, 3, 2, 0]]));
+    let mut sorted = a.clone();
+    sorted.sort_axis(Axis(1));
+    assert_eq!(a.take_along_axis(&rows, Axis(1)), sorted);
+    let columns = a.t().argsort_axis(Axis(1));
+    assert_eq!(columns, arr2(&[[0, 1], [0, 1], [0, 1], [0, 1]]));
+    let reversed = a.slice(s![..;-1, ..]).argsort_axis(Axis(0));
+    assert_eq!(reversed, arr2(&[[1, 1, 1, 1], [0, 0, 0, 0]]));
+    assert_eq!(Array1::<u8>::zeros(0).argsort_axis(Axis(0)).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn sort_axis_out_of_bounds() {
+    let mut a = arr2(&[[1, 2], [3, 4]]);
+    a.sort_axis(Axis(2));
+}
+
+#[test]
+fn scatter_add() {
+    let mut a = Array2::<i32>::zeros((3, 2));
+    let src = array![[1, 2], [3, 4], [5, 6], [7, 8]];
+    a.scatter_add(Axis(0), &[2, -1, 0, 2], &src);
+    assert_eq!(a, array![[5, 6], [0, 0], [11, 14]]);
+
+    let mut b = Array2::<i32>::ones((2, 3));
+    b.scatter_add(Axis(1), &[1, 1], &array![[1, 2], [3, 4]]);
+    assert_eq!(b, array![[1, 4, 1], [1, 8, 1]]);
+    b.scatter_add(Axis(1), &[0usize; 0], &Array2::zeros((2, 0)));
+    assert_eq!(b, array![[1, 4, 1], [1, 8, 1]]);
+}
+
+#[test]
+#[should_panic]
+fn scatter_add_shape_error() {
+    let mut a = Array2::<i32>::zeros((3, 2));
+    a.scatter_add(Axis(0), &[0, 1], &Array2::ones((2, 3)));
+}
+
+#[test]
+#[should_panic]
+fn scatter_add_index_error() {
+    let mut a = Array1::<i32>::zeros(3);
+    a.scatter_add(Axis(0), &[3], &array![1]);
+}
+
+#[test]
+fn segment_sum_max() {
+    let a = array![[1., 8.], [3., 4.], [5., f64::NAN], [7., 2.], [0., 1.]];
+    let ids = [1, 1, 1, 3, 3];
+    assert_eq!(a.column(0).segment_sum(Axis(0), &ids), array![0., 9., 0., 7.]);
+    let max = a.segment_max(Axis(0), &ids);
+    let min = f64::MIN;
+    assert_eq!(max, array![[min, min], [5., 8.], [min, min], [7., 2.]]);
+    assert_eq!(a.segment_max(Axis(1), &[0, 0]).column(0), array![8., 4., 5., 7., 1.]);
+
+    let inf = f64::INFINITY;
+    let b = array![-inf, -inf, 1., -inf];
+    assert_eq!(b.segment_max(Axis(0), &[0, 0, 1, 2]), array![-inf, 1., -inf]);
+
+    let empty = Array2::<f64>::zeros((0, 2));
+    assert_eq!(empty.segment_sum(Axis(0), &[]).shape(), &[0, 2]);
+}
+
+#[test]
+#[should_panic]
+fn segment_sum_unsorted() {
+    array![1, 2, 3].segment_sum(Axis(0), &[0, 1, 0]);
+}
+
+#[test]
+#[should_panic]
+fn segment_sum_length_error() {
+    array![1, 2, 3].segment_sum(Axis(0), &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "too many elements")]
+fn segment_sum_id_overflow() {
+    array![1, 2].segment_sum(Axis(0), &[0, usize::MAX]);
+}
+
+#[test]
+#[should_panic(expected = "too many elements")]
+fn segment_max_id_overflow() {
+    Array2::<i32>::zeros((1, 1 << 16)).segment_max(Axis(0), &[isize::MAX as usize >> 16]);
+}
+
+#[test]
+fn where_broadcast() {
+    use ndarray::where_;
+
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let b = -&a;
+    let even = a.mapv(|x| x % 2 == 0);
+    assert_eq!(where_(&even, &a, &b), array![[-1, 2, -3], [4, -5, 6]]);
+
+    // the result has the broadcast shape of all three
+    let cond = array![[true], [false]];
+    let x = where_(&cond, &array![1, 2], &aview0(&0));
+    assert_eq!(x, array![[1, 2], [0, 0]]);
+    let y = where_(&aview0(&true), &arr3(&[[[1]]]), &array![4, 5]);
+    assert_eq!(y, arr3(&[[[1, 1]]]));
+
+    let dyn_cond = cond.into_dyn();
+    assert_eq!(where_(&dyn_cond, &a, &b).shape(), &[2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn where_broadcast_error() {
+    ndarray::where_(&array![true, false], &array![1, 2, 3], &array![4, 5, 6]);
+}
+
+#[test]
+fn clip_broadcast() {
+    let a = array![[1, 5, 9], [-3, 4, 12]];
+    let clipped = a.clip_broadcast(&array![0, 6, 0], &array![[3], [10]]);
+    assert_eq!(clipped, array![[1, 3, 3], [0, 6, 10]]);
+    // max wins where min > max
+    assert_eq!(a.clip_broadcast(&aview0(&5), &aview0(&2)), Array::from_elem((2, 3), 2));
+}
+
 #[test]
 fn diag() {
     let d = arr2(&[[1., 2., 3.0f32]]).into_diag();
@@ -737,6 +1280,61 @@ fn diag() {
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn trace() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.trace(), 6);
+    assert_eq!(a.t().trace(), 6);
+    assert_eq!(a.slice(s![.., ..;-1]).trace(), 8);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).trace(), 0.);
+}
+
+#[test]
+fn diag_offset() {
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.diag_offset(0), a.diag());
+    assert_eq!(a.diag_offset(2), arr1(&[3]));
+    assert_eq!(a.diag_offset(-1), arr1(&[4]));
+    assert_eq!(a.diag_offset(3).len(), 0);
+    assert_eq!(a.diag_offset(-5).len(), 0);
+    assert_eq!(a.diag_offset(isize::MIN).len(), 0);
+    assert_eq!(a.t().diag_offset(-1), arr1(&[2, 6]));
+    assert_eq!(a.slice(s![.., ..;-1]).diag_offset(1), arr1(&[2, 4]));
+    a.diag_offset_mut(1).fill(0);
+    assert_eq!(a, arr2(&[[1, 0, 3], [4, 5, 0]]));
+    assert_eq!(a.into_diag_offset(-1), arr1(&[4]));
+}
+
+#[test]
+fn triu_tril() {
+    let a = Array::from_iter(1..=12).into_shape((3, 4)).unwrap();
+    assert_eq!(a.triu(0), arr2(&[[1, 2, 3, 4], [0, 6, 7, 8], [0, 0, 11, 12]]));
+    assert_eq!(a.triu(2), arr2(&[[0, 0, 3, 4], [0, 0, 0, 8], [0, 0, 0, 0]]));
+    assert_eq!(a.tril(1), arr2(&[[1, 2, 0, 0], [5, 6, 7, 0], [9, 10, 11, 12]]));
+    assert_eq!(a.tril(-3), Array2::zeros((3, 4)));
+    assert_eq!(a.triu(-3), a);
+    assert_eq!(a.tril(isize::MAX), a);
+    assert_eq!(a.triu(isize::MIN), a);
+    // the two triangles partition the array
+    for k in -4..5 {
+        assert_eq!(a.triu(k) + a.tril(k - 1), a);
+    }
+
+    let mut b = a.t().to_owned();
+    b.tril_inplace(0);
+    assert_eq!(b, a.triu(0).reversed_axes());
+
+    let upper = a.triu_indices(1);
+    assert_eq!(upper, arr2(&[[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]));
+    let lower = a.tril_indices(0);
+    assert_eq!(lower.nrows() + upper.nrows(), a.len());
+    for ix in lower.rows() {
+        assert!(ix[1] <= ix[0]);
+    }
+    assert_eq!(a.triu_indices(4).shape(), &[0, 2]);
+    assert_eq!(a.tril_indices(-1).row(0), arr1(&[1, 0]));
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
@@ -2241,6 +2839,23 @@ fn test_array2_from_diag() {
     assert_eq!(x.shape(), [0, 0]);
 }
 
+#[test]
+fn test_array2_from_diag_offset_tri() {
+    let diag = arr1(&[1, 2]);
+    assert_eq!(Array2::from_diag_offset(&diag, 0), Array2::from_diag(&diag));
+    let x = Array2::from_diag_offset(&diag, 2);
+    assert_eq!(x.dim(), (4, 4));
+    assert_eq!(x.diag_offset(2), diag);
+    assert_eq!(x.sum(), 3);
+    assert_eq!(Array2::from_diag_offset(&Array1::<f64>::zeros(0), -1), Array2::zeros((1, 1)));
+
+    assert_eq!(Array2::<u8>::tri(3, 2, 0), arr2(&[[1, 0], [1, 1], [1, 1]]));
+    assert_eq!(Array2::<u8>::tri(2, 3, -1), arr2(&[[0, 0, 0], [1, 0, 0]]));
+    assert_eq!(Array2::<u8>::tri(2, 2, 1), Array2::ones((2, 2)));
+    let tri = Array2::<f64>::tri(4, 5, 1);
+    assert_eq!(tri, Array2::ones((4, 5)).tril(1));
+}
+
 #[test]
 fn array_macros() {
     // array
@@ -1,5 +1,5 @@
 use ndarray::Array;
-use ndarray::{arr1, arr2, Axis};
+use ndarray::{arr1, arr2, array, s, Array2, ArcArray, Axis};
 use num_complex::Complex;
 use num_traits::Num;
 
@@ -21,3 +21,56 @@ fn complex_mat_mul() {
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+fn complex_re_im() {
+    let mut a = Array2::from_shape_fn((3, 4), |(i, j)| c(i as f64, j as f64 - 1.));
+    assert_eq!(a.re(), Array2::from_shape_fn((3, 4), |(i, _)| i as f64));
+    assert_eq!(a.im(), Array2::from_shape_fn((3, 4), |(_, j)| j as f64 - 1.));
+    assert_eq!(a.t().re(), a.re().t());
+    assert_eq!(a.slice(s![..;-1, 1..;2]).im(), array![[0., 2.], [0., 2.], [0., 2.]]);
+
+    a.re_mut().fill(7.);
+    a.slice_mut(s![.., 0]).im_mut().fill(-3.);
+    assert_eq!(a[[1, 0]], c(7., -3.));
+    assert_eq!(a[[2, 3]], c(7., 2.));
+}
+
+#[test]
+fn complex_view_as_real() {
+    let mut a = array![[c(1., 2.), c(3., 4.)], [c(5., 6.), c(7., 8.)]];
+    let r = a.view_as_real();
+    assert_eq!(r.shape(), &[2, 2, 2]);
+    assert_eq!(r, array![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]]);
+    assert_eq!(r.index_axis(Axis(2), 0), a.re());
+    assert_eq!(a.t().view_as_real(), array![[[1., 2.], [5., 6.]], [[3., 4.], [7., 8.]]]);
+    assert_eq!(a.slice(s![1.., ..;-1]).view_as_real(), array![[[7., 8.], [5., 6.]]]);
+
+    a.view_as_real_mut()[[0, 1, 1]] = -4.;
+    assert_eq!(a[[0, 1]], c(3., -4.));
+
+    let empty = Array2::<Complex<f32>>::zeros((0, 3));
+    assert_eq!(empty.view_as_real().shape(), &[0, 3, 2]);
+
+    // shared arrays are unshared before mutation
+    let mut shared = ArcArray::from(vec![c(1., 2.)]);
+    let other = shared.clone();
+    shared.view_as_real_mut()[[0, 0]] = 0.;
+    assert_eq!(shared[0], c(0., 2.));
+    assert_eq!(other[0], c(1., 2.));
+}
+
+#[test]
+fn complex_elementwise() {
+    let mut a = array![c(3., 4.), c(0., -2.), c(-1., 0f64)];
+    assert_eq!(a.conj(), array![c(3., -4.), c(0., 2.), c(-1., 0.)]);
+    assert_eq!(a.norm(), array![5., 2., 1.]);
+    assert_eq!(a.norm_sqr(), array![25., 4., 1.]);
+    assert_eq!(a.arg(), a.map(|z| z.im.atan2(z.re)));
+    let polar = Array::from_polar(&a.norm(), &a.arg());
+    for (z, w) in polar.iter().zip(&a) {
+        assert!((z - w).norm() < 1e-12);
+    }
+    a.conj_inplace();
+    assert_eq!(a.im(), array![-4., 2., -0.]);
+}
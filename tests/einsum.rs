@@ -0,0 +1,185 @@
+#![allow(clippy::many_single_char_names)]
+
+use ndarray::linalg::LinalgError;
+use ndarray::prelude::*;
+use ndarray::{einsum, tensordot, ErrorKind, ShapeError};
+
+fn range(shape: &[usize]) -> ArrayD<i64> {
+    let len = shape.iter().product::<usize>();
+    Array::from_iter(0..len as i64).into_shape(shape).unwrap()
+}
+
+#[test]
+fn einsum_matrix_products() {
+    let a = range(&[3, 4]);
+    let b = range(&[4, 5]);
+    let c = einsum("ij,jk->ik", &[&a.view(), &b.view()]).unwrap();
+    let a2 = a.view().into_dimensionality::<Ix2>().unwrap();
+    let b2 = b.view().into_dimensionality::<Ix2>().unwrap();
+    assert_eq!(c, a2.dot(&b2).into_dyn());
+    // implicit output
+    assert_eq!(einsum("ij,jk", &[&a.view(), &b.view()]).unwrap(), c);
+    // transposed output
+    let ct = einsum("ij,jk->ki", &[&a.view(), &b.view()]).unwrap();
+    assert_eq!(ct, c.t());
+
+    let v = range(&[4]);
+    let v1 = v.view().into_dimensionality::<Ix1>().unwrap();
+    assert_eq!(einsum("ij,j->i", &[&a.view(), &v.view()]).unwrap(), a2.dot(&v1).into_dyn());
+    assert_eq!(einsum("i,i", &[&v.view(), &v.view()]).unwrap()[[]], v1.dot(&v1));
+    let outer = einsum("i,j->ij", &[&v.view(), &v.view()]).unwrap();
+    assert_eq!(outer[[2, 3]], 6);
+    assert_eq!(outer.shape(), &[4, 4]);
+}
+
+#[test]
+fn einsum_batched() {
+    let a = Array::from_shape_fn((3, 2, 4), |(b, i, j)| (b * 8 + i * 4 + j) as f64);
+    let b = Array::from_shape_fn((3, 4, 5), |(b, i, j)| (b + i) as f64 - j as f64);
+    let c = einsum("bij,bjk->bik", &[&a.view(), &b.view()]).unwrap();
+    assert_eq!(c, a.matmul(&b));
+
+    // the batch label in a different position, and a non-contiguous operand
+    let bt = b.view().permuted_axes([1, 0, 2]);
+    let c2 = einsum("bij,jbk->bik", &[&a.view(), &bt]).unwrap();
+    assert_eq!(c2, c);
+
+    // elementwise product
+    let h = einsum("bij,bij->bij", &[&a.view(), &a.view()]).unwrap();
+    assert_eq!(h, (&a * &a).into_dyn());
+}
+
+#[test]
+fn einsum_traces_and_diagonals() {
+    let a = range(&[4, 4]);
+    assert_eq!(einsum("ii", &[&a.view()]).unwrap()[[]], 0 + 5 + 10 + 15);
+    assert_eq!(einsum("ii->i", &[&a.view()]).unwrap(), arr1(&[0, 5, 10, 15]).into_dyn());
+    assert_eq!(einsum("ij->", &[&a.view()]).unwrap()[[]], a.sum());
+    assert_eq!(einsum("ij->j", &[&a.view()]).unwrap(), a.sum_axis(Axis(0)));
+
+    let t = range(&[3, 2, 3]);
+    let diag = einsum("iji->ij", &[&t.view()]).unwrap();
+    assert_eq!(diag, Array::from_shape_fn((3, 2), |(i, j)| t[[i, j, i]]).into_dyn());
+
+    // the trace of a product
+    let b = range(&[4, 4]);
+    let tr = einsum("ij,ji->", &[&a.view(), &b.view()]).unwrap()[[]];
+    let a2 = a.view().into_dimensionality::<Ix2>().unwrap();
+    let b2 = b.view().into_dimensionality::<Ix2>().unwrap();
+    assert_eq!(tr, a2.dot(&b2).diag().sum());
+}
+
+#[test]
+fn einsum_ellipsis() {
+    let a = range(&[2, 3, 4]);
+    let b = range(&[4, 5]);
+    let c = einsum("...ij,jk->...ik", &[&a.view(), &b.view()]).unwrap();
+    assert_eq!(c.shape(), &[2, 3, 5]);
+    assert_eq!(c, a.matmul(&b));
+    // implicit output puts the ellipsis axes first
+    assert_eq!(einsum("...ij,jk", &[&a.view(), &b.view()]).unwrap(), c);
+    // ellipsis axes are summed over without an ellipsis in the output
+    assert_eq!(einsum("...j->j", &[&a.view()]).unwrap(), einsum("abj->j", &[&a.view()]).unwrap());
+
+    // broadcasting of the ellipsis axes
+    let x = range(&[2, 1, 4]);
+    let y = range(&[3, 4]);
+    let z = einsum("...i,...i->...", &[&x.view(), &y.view()]).unwrap();
+    assert_eq!(z.shape(), &[2, 3]);
+    let expected = Array::from_shape_fn((2, 3), |(p, q)| {
+        (0..4).map(|i| x[[p, 0, i]] * y[[q, i]]).sum::<i64>()
+    });
+    assert_eq!(z, expected.into_dyn());
+
+    // ellipsis covering no axes
+    let v = range(&[4]);
+    assert_eq!(einsum("...i->...", &[&v.view()]).unwrap()[[]], 6);
+}
+
+#[test]
+fn einsum_many_operands() {
+    let a = range(&[2, 3]);
+    let b = range(&[3, 4]);
+    let c = range(&[4, 5]);
+    let d = einsum("ij,jk,kl->il", &[&a.view(), &b.view(), &c.view()]).unwrap();
+    let (a2, b2, c2) = (
+        a.view().into_dimensionality::<Ix2>().unwrap(),
+        b.view().into_dimensionality::<Ix2>().unwrap(),
+        c.view().into_dimensionality::<Ix2>().unwrap(),
+    );
+    assert_eq!(d, a2.dot(&b2).dot(&c2).into_dyn());
+
+    // a label shared by three operands
+    let v = range(&[3]);
+    let w = einsum("i,i,i->", &[&v.view(), &v.view(), &v.view()]).unwrap();
+    assert_eq!(w[[]], 1 + 8);
+    let w = einsum("i,i,i->i", &[&v.view(), &v.view(), &v.view()]).unwrap();
+    assert_eq!(w, arr1(&[0, 1, 8]).into_dyn());
+}
+
+#[test]
+fn einsum_empty() {
+    let a = Array2::<f32>::zeros((0, 3));
+    let b = Array2::<f32>::ones((3, 2));
+    assert_eq!(einsum("ij,jk->ik", &[&a.view(), &b.view()]).unwrap().shape(), &[0, 2]);
+    let a = Array2::<f32>::ones((2, 0));
+    let b = Array2::<f32>::ones((0, 2));
+    assert_eq!(einsum("ij,jk->ik", &[&a.view(), &b.view()]).unwrap(), Array::zeros((2, 2)).into_dyn());
+}
+
+#[test]
+fn einsum_errors() {
+    let a = range(&[2, 3]);
+    let invalid = |s: &str, ops: &[&ArrayViewD<'_, i64>]| match einsum(s, ops) {
+        Err(LinalgError::InvalidSubscripts(_)) => {}
+        other => panic!("{:?} should be invalid, got {:?}", s, other),
+    };
+    invalid("ij,jk->ik", &[&a.view()]);
+    invalid("ijk", &[&a.view()]);
+    invalid("i", &[&a.view()]);
+    invalid("i1", &[&a.view()]);
+    invalid("ij->ii", &[&a.view()]);
+    invalid("ij->k", &[&a.view()]);
+    invalid("......", &[&a.view()]);
+    assert_eq!(
+        einsum("ii", &[&a.view()]),
+        Err(LinalgError::Shape(ShapeError::from_kind(ErrorKind::IncompatibleShape)))
+    );
+    let b = range(&[2]);
+    assert!(einsum("ij,j", &[&a.view(), &b.view()]).is_err());
+    assert!(einsum("...j,...j", &[&range(&[2, 3]).view(), &range(&[3, 3]).view()]).is_err());
+}
+
+#[test]
+fn tensordot_axes() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as i64);
+    let b = Array::from_shape_fn((5, 4, 2), |(i, j, k)| (i + j * k) as i64);
+
+    let c = tensordot(&a, &b, &[Axis(1), Axis(2)], &[Axis(1), Axis(0)]);
+    assert_eq!(c, einsum("ijk,kjl->il", &[&a.view().into_dyn(), &b.view().into_dyn()]).unwrap());
+
+    // matrix product
+    let m = Array::from_shape_fn((3, 4), |(i, j)| (i + 2 * j) as i64);
+    let n = Array::from_shape_fn((4, 2), |(i, j)| (i * j) as i64);
+    assert_eq!(tensordot(&m, &n, &[Axis(1)], &[Axis(0)]), m.dot(&n).into_dyn());
+    // outer product without contracted axes
+    let o = tensordot(&m, &n, &[], &[]);
+    assert_eq!(o.shape(), &[3, 4, 4, 2]);
+    assert_eq!(o[[2, 3, 1, 1]], m[[2, 3]] * n[[1, 1]]);
+    // non-contiguous operands
+    assert_eq!(tensordot(&m.t(), &n, &[Axis(0)], &[Axis(0)]), m.dot(&n).into_dyn());
+}
+
+#[test]
+#[should_panic]
+fn tensordot_mismatched_lengths() {
+    let a = Array2::<f64>::zeros((3, 4));
+    tensordot(&a, &a, &[Axis(0)], &[Axis(1)]);
+}
+
+#[test]
+#[should_panic]
+fn tensordot_repeated_axis() {
+    let a = Array2::<f64>::zeros((3, 3));
+    tensordot(&a, &a, &[Axis(0), Axis(0)], &[Axis(0), Axis(1)]);
+}
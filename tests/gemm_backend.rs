@@ -0,0 +1,167 @@
+#![cfg(feature = "std")]
+
+use ndarray::linalg::{gemm_backend, gemm_stats, reset_gemm_stats, set_gemm_backend};
+use ndarray::linalg::{general_mat_mul, general_mat_vec_mul, with_gemm_backend};
+use ndarray::linalg::{CustomGemm, GemmBackend, GemmFallback, GemmStats};
+use ndarray::prelude::*;
+
+fn range_mat(m: usize, n: usize) -> Array2<f64> {
+    Array::linspace(0., (m * n) as f64 - 1., m * n)
+        .into_shape((m, n))
+        .unwrap()
+}
+
+/// C ← α A B + β C, by rows and columns.
+fn naive_gemm(
+    alpha: f64,
+    a: ArrayView2<f64>,
+    b: ArrayView2<f64>,
+    beta: f64,
+    mut c: ArrayViewMut2<f64>,
+) {
+    for ((i, j), c) in c.indexed_iter_mut() {
+        *c = alpha * a.row(i).dot(&b.column(j)) + beta * *c;
+    }
+}
+
+fn custom() -> GemmBackend {
+    GemmBackend::Custom(CustomGemm { f64: Some(naive_gemm), ..Default::default() })
+}
+
+#[test]
+fn native_backend() {
+    let a = range_mat(10, 12);
+    let b = range_mat(12, 9);
+    reset_gemm_stats();
+    let c = with_gemm_backend(GemmBackend::Native, || {
+        assert!(matches!(gemm_backend(), GemmBackend::Native));
+        let mut c = Array2::zeros((10, 9));
+        general_mat_mul(1., &a, &b, 0., &mut c);
+        assert_eq!(Array2::<i32>::eye(3).dot(&Array2::eye(3)), Array2::eye(3));
+        c
+    });
+    let expected = Array::from_shape_fn((10, 9), |(i, j)| a.row(i).dot(&b.column(j)));
+    assert_eq!(c, expected);
+    let stats = gemm_stats();
+    assert_eq!((stats.native, stats.blas, stats.custom, stats.total()), (2, 0, 0, 2));
+    assert_eq!(stats.fallbacks(GemmFallback::ElementType), 0);
+    reset_gemm_stats();
+    assert_eq!(gemm_stats(), GemmStats::default());
+}
+
+#[test]
+#[cfg(not(feature = "blas"))]
+fn blas_unavailable() {
+    assert!(matches!(GemmBackend::default(), GemmBackend::Native));
+    let a = range_mat(20, 20);
+    reset_gemm_stats();
+    let c = with_gemm_backend(GemmBackend::Blas, || a.dot(&a));
+    assert_eq!(c, with_gemm_backend(GemmBackend::Native, || a.dot(&a)));
+    let stats = gemm_stats();
+    assert_eq!((stats.native, stats.blas), (2, 0));
+    assert_eq!(stats.fallbacks(GemmFallback::Unavailable), 1);
+}
+
+#[test]
+fn custom_backend() {
+    let a = range_mat(7, 11);
+    let b = range_mat(22, 10);
+    let b = b.slice(s![..;2, ..;-3]);
+    let expected = with_gemm_backend(GemmBackend::Native, || a.dot(&b));
+    reset_gemm_stats();
+    with_gemm_backend(custom(), || {
+        assert_eq!(a.dot(&b), expected);
+        let mut c = Array2::ones((7, 4));
+        general_mat_mul(2., &a, &b, 3., &mut c);
+        assert_eq!(c, &expected * 2. + 3.);
+        // with beta zero, the function reads zeros instead of the old elements
+        let mut c = Array2::from_elem((7, 4), f64::NAN);
+        general_mat_mul(1., &a, &b, 0., &mut c);
+        assert_eq!(c, expected);
+
+        // other element types fall back
+        let a32 = a.mapv(|x| x as f32);
+        let b32 = b.mapv(|x| x as f32);
+        assert_eq!(a32.dot(&b32), expected.mapv(|x| x as f32));
+    });
+    let stats = gemm_stats();
+    assert_eq!((stats.native, stats.custom), (1, 3));
+    assert_eq!(stats.fallbacks(GemmFallback::ElementType), 1);
+}
+
+#[test]
+fn mat_vec_backend() {
+    let a = range_mat(6, 5);
+    let x = Array::linspace(1., 5., 5);
+    let y = Array::linspace(-1., 1., 6);
+    let ax = Array::from_shape_fn(6, |i| a.row(i).dot(&x));
+    let ya = Array::from_shape_fn(5, |j| y.dot(&a.column(j)));
+
+    reset_gemm_stats();
+    with_gemm_backend(GemmBackend::Native, || {
+        assert_eq!(a.dot(&x), ax);
+        assert_eq!(y.dot(&a), ya);
+        let mut z = Array1::ones(6);
+        general_mat_vec_mul(2., &a, &x, 1., &mut z);
+        assert_eq!(z, &ax * 2. + 1.);
+        // dot products of vectors don't use the backend
+        x.dot(&x);
+    });
+    let stats = gemm_stats();
+    assert_eq!((stats.native, stats.custom, stats.total()), (3, 0, 3));
+
+    reset_gemm_stats();
+    with_gemm_backend(custom(), || {
+        assert_eq!(a.dot(&x), ax);
+        assert_eq!(y.dot(&a), ya);
+        let mut z = Array1::from_elem(6, f64::NAN);
+        general_mat_vec_mul(1., &a, &x, 0., &mut z);
+        assert_eq!(z, ax);
+        // other element types fall back
+        assert_eq!(Array2::<i32>::eye(2).dot(&Array1::ones(2)), Array1::ones(2));
+    });
+    let stats = gemm_stats();
+    assert_eq!((stats.native, stats.custom), (1, 3));
+    assert_eq!(stats.fallbacks(GemmFallback::ElementType), 1);
+
+    #[cfg(not(feature = "blas"))]
+    {
+        reset_gemm_stats();
+        assert_eq!(with_gemm_backend(GemmBackend::Blas, || a.dot(&x)), ax);
+        assert_eq!(gemm_stats().fallbacks(GemmFallback::Unavailable), 1);
+    }
+}
+
+#[test]
+fn backend_override() {
+    let default = gemm_backend();
+    with_gemm_backend(custom(), || {
+        with_gemm_backend(GemmBackend::Native, || {
+            assert!(matches!(gemm_backend(), GemmBackend::Native));
+        });
+        assert!(matches!(gemm_backend(), GemmBackend::Custom(_)));
+        // the override is only for the current thread
+        let other = std::thread::spawn(|| matches!(gemm_backend(), GemmBackend::Custom(_)));
+        assert!(!other.join().unwrap());
+    });
+    assert_eq!(format!("{:?}", gemm_backend()), format!("{:?}", default));
+
+    // the override is restored when the closure panics
+    let result = std::panic::catch_unwind(|| {
+        with_gemm_backend(custom(), || panic!("in the closure"));
+    });
+    assert!(result.is_err());
+    assert_eq!(format!("{:?}", gemm_backend()), format!("{:?}", default));
+
+    // the global backend, which the other tests of this file override
+    set_gemm_backend(custom());
+    let a = range_mat(5, 5);
+    let stats = std::thread::spawn(move || {
+        reset_gemm_stats();
+        a.dot(&a);
+        gemm_stats()
+    });
+    let stats = stats.join().unwrap();
+    set_gemm_backend(default);
+    assert_eq!((stats.native, stats.custom), (0, 1));
+}
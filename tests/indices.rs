@@ -21,3 +21,60 @@ fn test_ixdyn_index_iterate() {
         assert_eq!(c, a.len());
     }
 }
+
+#[test]
+fn test_unravel_ravel_index() {
+    use ndarray::{ravel_multi_index, unravel_index, Order};
+
+    for &order in &[Order::RowMajor, Order::ColumnMajor] {
+        let shape = (2, 3, 4).set_f(order.is_column_major());
+        let a = Array::from_shape_vec(shape, (0..24).collect()).unwrap();
+        for flat in 0..24 {
+            let index = unravel_index(flat, (2, 3, 4), order);
+            assert_eq!(a[index], flat);
+            assert_eq!(ravel_multi_index(index, (2, 3, 4), order), flat);
+        }
+    }
+    assert_eq!(unravel_index(0, (), Order::RowMajor), ());
+    assert_eq!(ravel_multi_index((), (), Order::ColumnMajor), 0);
+    let dyn_index = unravel_index(5, &[2, 3][..], Order::ColumnMajor);
+    assert_eq!(dyn_index, IxDyn(&[1, 2]));
+    assert_eq!(ravel_multi_index(&[1, 2][..], &[2, 3][..], Order::ColumnMajor), 5);
+}
+
+#[test]
+fn test_unravel_ravel_index_array() {
+    use ndarray::{ravel_multi_index_array, unravel_index_array, Order};
+
+    let flat = array![0, 5, 6, 11];
+    let index = unravel_index_array(&flat, (3, 4), Order::RowMajor);
+    assert_eq!(index, array![[0, 0], [1, 1], [1, 2], [2, 3]]);
+    assert_eq!(ravel_multi_index_array(&index, (3, 4), Order::RowMajor), flat);
+    let index = unravel_index_array(&flat, (3, 4), Order::ColumnMajor);
+    assert_eq!(index, array![[0, 0], [2, 1], [0, 2], [2, 3]]);
+    assert_eq!(ravel_multi_index_array(&index, (3, 4), Order::F), flat);
+    // rows of a column major array are strided
+    let index_f = index.t().as_standard_layout().into_owned().reversed_axes();
+    assert_eq!(ravel_multi_index_array(&index_f, (3, 4), Order::F), flat);
+    let empty = unravel_index_array(&Array1::<usize>::zeros(0), &[2, 3, 4][..], Order::C);
+    assert_eq!(empty.shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_unravel_index_out_of_bounds() {
+    ndarray::unravel_index(12, (3, 4), ndarray::Order::RowMajor);
+}
+
+#[test]
+#[should_panic]
+fn test_ravel_multi_index_out_of_bounds() {
+    ndarray::ravel_multi_index((1, 4), (3, 4), ndarray::Order::RowMajor);
+}
+
+#[test]
+#[should_panic]
+fn test_ravel_multi_index_array_wrong_axes() {
+    let index = array![[0, 1, 2]];
+    ndarray::ravel_multi_index_array(&index, (3, 4), ndarray::Order::RowMajor);
+}
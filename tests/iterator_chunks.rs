@@ -99,3 +99,19 @@ fn chunks_different_size_3() {
     let mut a = Array::<f32, _>::zeros(vec![2, 3]);
     a.exact_chunks_mut(vec![2, 3, 4]);
 }
+
+#[test]
+fn chunks_negative_strides() {
+    let a = Array::from_shape_fn((4, 6), |(i, j)| i * 6 + j);
+    let r = a.slice(s![..;-1, ..;-1]);
+    let chunks: Vec<_> = r.exact_chunks((2, 3)).into_iter().map(|c| c.to_owned()).collect();
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks[0], aview2(&[[23, 22, 21], [17, 16, 15]]));
+    assert_eq!(chunks[3], aview2(&[[8, 7, 6], [2, 1, 0]]));
+
+    let mut b = a.clone();
+    for mut chunk in b.slice_mut(s![..;-1, ..]).exact_chunks_mut((2, 2)) {
+        chunk.fill(chunk[[0, 0]]);
+    }
+    assert_eq!(b.row(3), aview1(&[18, 18, 20, 20, 22, 22]));
+}
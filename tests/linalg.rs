@@ -0,0 +1,542 @@
+#![cfg(all(feature = "std", feature = "approx"))]
+#![allow(clippy::many_single_char_names)]
+
+use approx::assert_abs_diff_eq;
+use ndarray::linalg::{bicgstab, cg, gmres, IterOptions};
+use ndarray::linalg::{Diag, LinalgError, MatrixNorm, Side, Transpose, Triangle};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use num_complex::Complex64;
+
+/// Deterministic pseudo-random matrix with entries in [-1, 1).
+fn rand_matrix(m: usize, n: usize, seed: u64) -> Array2<f64> {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    Array::from_shape_simple_fn((m, n), move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 * 2. - 1.
+    })
+}
+
+fn rand_complex_matrix(m: usize, n: usize, seed: u64) -> Array2<Complex64> {
+    let re = rand_matrix(m, n, seed);
+    let im = rand_matrix(m, n, seed + 1);
+    Zip::from(&re).and(&im).map_collect(|&re, &im| Complex64::new(re, im))
+}
+
+#[test]
+fn lu_reconstruct() {
+    for &n in &[0, 1, 2, 5, 64, 65, 150] {
+        let a = rand_matrix(n, n, n as u64);
+        let lu = a.lu().unwrap();
+        let pa = a.select(Axis(0), &lu.permutation());
+        assert_abs_diff_eq!(lu.l().dot(&lu.u()), pa, epsilon = 1e-10);
+        // partial pivoting keeps the multipliers bounded by one
+        assert!(lu.l().iter().all(|x| x.abs() <= 1.));
+    }
+}
+
+#[test]
+fn lu_solve_inv() {
+    for &n in &[1, 3, 70, 130] {
+        let a = rand_matrix(n, n, 10 + n as u64);
+        let x = rand_matrix(n, 3, 20 + n as u64);
+        let b = a.dot(&x);
+        assert_abs_diff_eq!(a.solve(&b).unwrap(), x, epsilon = 1e-8);
+        assert_abs_diff_eq!(a.solve(&b.column(1)).unwrap(), x.column(1), epsilon = 1e-8);
+        // non-contiguous inputs
+        let at = a.t().to_owned();
+        assert_abs_diff_eq!(at.t().solve(&b.t().t()).unwrap(), x, epsilon = 1e-8);
+
+        let inv = a.inv().unwrap();
+        assert_abs_diff_eq!(inv.dot(&a), Array2::eye(n), epsilon = 1e-8);
+        assert_abs_diff_eq!(a.dot(&inv), Array2::eye(n), epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn lu_det() {
+    let a = array![[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+    assert_abs_diff_eq!(a.det().unwrap(), 4., epsilon = 1e-12);
+    let (sign, logabsdet) = a.slogdet().unwrap();
+    assert_eq!(sign, 1.);
+    assert_abs_diff_eq!(logabsdet, 4f64.ln(), epsilon = 1e-12);
+
+    let p = array![[0., 1., 0.], [1., 0., 0.], [0., 0., 3.]];
+    assert_abs_diff_eq!(p.det().unwrap(), -3., epsilon = 1e-12);
+    assert_eq!(p.slogdet().unwrap().0, -1.);
+
+    // det(A B) = det(A) det(B)
+    let b = rand_matrix(80, 80, 1);
+    let c = rand_matrix(80, 80, 2);
+    let (sb, lb) = b.slogdet().unwrap();
+    let (sc, lc) = c.slogdet().unwrap();
+    let (sbc, lbc) = b.dot(&c).slogdet().unwrap();
+    assert_eq!(sbc, sb * sc);
+    assert_abs_diff_eq!(lbc, lb + lc, epsilon = 1e-8);
+
+    // huge determinant overflows det but not slogdet
+    let big = Array2::<f64>::eye(400) * 1e3;
+    assert!(big.det().unwrap().is_infinite());
+    assert_abs_diff_eq!(big.slogdet().unwrap().1, 400. * 1e3f64.ln(), epsilon = 1e-8);
+
+    assert_eq!(Array2::<f64>::zeros((0, 0)).det().unwrap(), 1.);
+}
+
+#[test]
+fn lu_singular() {
+    let a = array![[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]];
+    let lu = a.lu().unwrap();
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), 0.);
+    assert_eq!(lu.slogdet(), (0., f64::NEG_INFINITY));
+    assert_eq!(a.inv(), Err(LinalgError::Singular));
+    assert_eq!(a.solve(&array![1., 2., 3.]), Err(LinalgError::Singular));
+    assert_eq!(Array2::<f64>::zeros((3, 3)).inv(), Err(LinalgError::Singular));
+}
+
+#[test]
+fn lu_errors() {
+    let a = Array2::<f64>::eye(3);
+    assert_eq!(
+        a.slice(s![.., ..2]).lu().unwrap_err(),
+        LinalgError::NotSquare { rows: 3, cols: 2 }
+    );
+    assert!(matches!(a.solve(&Array1::zeros(2)), Err(LinalgError::Shape(_))));
+    assert!(matches!(a.solve(&Array3::zeros((3, 1, 1))), Err(LinalgError::Shape(_))));
+}
+
+#[test]
+fn lu_complex() {
+    let n = 70;
+    let a = rand_complex_matrix(n, n, 3);
+    let x = rand_complex_matrix(n, 2, 5);
+    let b = a.dot(&x);
+    let sol = a.solve(&b).unwrap();
+    assert!((&sol - &x).iter().all(|d| d.norm() < 1e-8));
+    let inv = a.inv().unwrap();
+    let eye = Array2::<Complex64>::eye(n);
+    assert!((&inv.dot(&a) - &eye).iter().all(|d| d.norm() < 1e-8));
+
+    let d = array![[Complex64::new(0., 2.), Complex64::new(0., 0.)],
+                   [Complex64::new(0., 0.), Complex64::new(3., 0.)]];
+    let det = d.det().unwrap();
+    assert!((det - Complex64::new(0., 6.)).norm() < 1e-12);
+    let (sign, logabsdet) = d.slogdet().unwrap();
+    assert!((sign - Complex64::new(0., 1.)).norm() < 1e-12);
+    assert_abs_diff_eq!(logabsdet, 6f64.ln(), epsilon = 1e-12);
+}
+
+fn max_abs_diff(a: &Array2<Complex64>, b: &Array2<Complex64>) -> f64 {
+    Zip::from(a).and(b).fold(0., |acc, x, y| f64::max(acc, (x - y).norm()))
+}
+
+fn adjoint(a: &Array2<Complex64>) -> Array2<Complex64> {
+    a.t().mapv(|x| x.conj())
+}
+
+#[test]
+fn solve_triangular() {
+    let n = 40;
+    let a = rand_matrix(n, n, 7) + Array2::<f64>::eye(n) * n as f64;
+    let lower = Array2::from_shape_fn((n, n), |(i, j)| if i >= j { a[[i, j]] } else { 0. });
+    let upper = Array2::from_shape_fn((n, n), |(i, j)| if i <= j { a[[i, j]] } else { 0. });
+    let mut unit_lower = lower.clone();
+    unit_lower.diag_mut().fill(1.);
+    let x = rand_matrix(n, 3, 8);
+
+    for &(uplo, t) in &[(Triangle::Lower, &lower), (Triangle::Upper, &upper)] {
+        for &trans in &[Transpose::No, Transpose::Transpose] {
+            let op = if trans == Transpose::No { t.view() } else { t.t() };
+            // only the selected triangle of `a` is read
+            let left = a.solve_triangular(&op.dot(&x), Side::Left, uplo, trans, Diag::NonUnit);
+            assert_abs_diff_eq!(left.unwrap(), x, epsilon = 1e-10);
+            let xt = x.t();
+            let right = a.solve_triangular(&xt.dot(&op), Side::Right, uplo, trans, Diag::NonUnit);
+            assert_abs_diff_eq!(right.unwrap(), xt, epsilon = 1e-10);
+            // a vector with the right side solves x op(A) = b
+            let b = op.t().dot(&x.column(0));
+            let v = a.t().t().solve_triangular(&b, Side::Right, uplo, trans, Diag::NonUnit);
+            assert_abs_diff_eq!(v.unwrap(), x.column(0), epsilon = 1e-10);
+        }
+    }
+
+    let b = unit_lower.dot(&x);
+    let sol = a.solve_triangular(&b, Side::Left, Triangle::Lower, Transpose::No, Diag::Unit);
+    assert_abs_diff_eq!(sol.unwrap(), x, epsilon = 1e-10);
+
+    let c = rand_complex_matrix(n, n, 9) + Array2::<Complex64>::eye(n) * Complex64::new(n as f64, 0.);
+    let c_upper = Array2::from_shape_fn((n, n), |(i, j)| if i <= j { c[[i, j]] } else { Complex64::new(0., 0.) });
+    let y = rand_complex_matrix(n, 2, 11);
+    let b = adjoint(&c_upper).dot(&y);
+    let sol = c.solve_triangular(&b, Side::Left, Triangle::Upper, Transpose::Adjoint, Diag::NonUnit);
+    assert!(max_abs_diff(&sol.unwrap(), &y) < 1e-10);
+
+    let mut singular = upper.clone();
+    singular[[3, 3]] = 0.;
+    let res = singular.solve_triangular(&x, Side::Left, Triangle::Upper, Transpose::No, Diag::NonUnit);
+    assert_eq!(res, Err(LinalgError::Singular));
+    let res = a.solve_triangular(&x, Side::Right, Triangle::Upper, Transpose::No, Diag::NonUnit);
+    assert!(matches!(res, Err(LinalgError::Shape(_))));
+}
+
+#[test]
+fn cholesky() {
+    for &n in &[0, 1, 4, 50] {
+        let b = rand_matrix(n, n, 30 + n as u64);
+        let a = b.dot(&b.t()) + Array2::<f64>::eye(n);
+        let l = a.cholesky(Triangle::Lower).unwrap();
+        assert_abs_diff_eq!(l.dot(&l.t()), a, epsilon = 1e-10);
+        assert!(l.indexed_iter().all(|((i, j), &x)| i >= j || x == 0.));
+        let u = a.cholesky(Triangle::Upper).unwrap();
+        assert_abs_diff_eq!(u.t().dot(&u), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(u, l.t(), epsilon = 1e-10);
+        // only one triangle is read
+        let mut lower_only = a.clone();
+        lower_only.indexed_iter_mut().filter(|((i, j), _)| i < j).for_each(|(_, x)| *x = f64::NAN);
+        assert_abs_diff_eq!(lower_only.cholesky(Triangle::Lower).unwrap(), l, epsilon = 1e-12);
+        assert_abs_diff_eq!(lower_only.t().cholesky(Triangle::Upper).unwrap(), u, epsilon = 1e-12);
+    }
+
+    let b = rand_complex_matrix(20, 20, 40);
+    let a = b.dot(&adjoint(&b)) + Array2::<Complex64>::eye(20);
+    let l = a.cholesky(Triangle::Lower).unwrap();
+    assert!(max_abs_diff(&l.dot(&adjoint(&l)), &a) < 1e-10);
+    let u = a.cholesky(Triangle::Upper).unwrap();
+    assert!(max_abs_diff(&adjoint(&u).dot(&u), &a) < 1e-10);
+
+    let indefinite = array![[1., 2.], [2., 1.]];
+    assert_eq!(indefinite.cholesky(Triangle::Lower), Err(LinalgError::NotPositiveDefinite));
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).cholesky(Triangle::Upper),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn qr() {
+    for &(m, n) in &[(0, 0), (1, 1), (5, 3), (3, 5), (40, 40), (60, 25)] {
+        let a = rand_matrix(m, n, (m * 100 + n) as u64);
+        let qr = a.qr();
+        let k = m.min(n);
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!(q.dim(), (m, k));
+        assert_eq!(r.dim(), (k, n));
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(q.t().dot(&q), Array2::eye(k), epsilon = 1e-10);
+        assert!(r.indexed_iter().all(|((i, j), &x)| i <= j || x == 0.));
+
+        let (q, r) = (qr.q_full(), qr.r_full());
+        assert_eq!(q.dim(), (m, m));
+        assert_eq!(r.dim(), (m, n));
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(q.dot(&q.t()), Array2::eye(m), epsilon = 1e-10);
+    }
+
+    // non-contiguous input
+    let a = rand_matrix(30, 20, 50);
+    let qr = a.t().qr();
+    assert_abs_diff_eq!(qr.q().dot(&qr.r()), a.t(), epsilon = 1e-10);
+
+    let c = rand_complex_matrix(12, 7, 51);
+    let qr = c.qr();
+    assert!(max_abs_diff(&qr.q().dot(&qr.r()), &c) < 1e-10);
+    assert!(max_abs_diff(&adjoint(&qr.q()).dot(&qr.q()), &Array2::eye(7)) < 1e-10);
+    assert!(max_abs_diff(&qr.q_full().dot(&qr.r_full()), &c) < 1e-10);
+}
+
+#[test]
+fn lstsq() {
+    // overdetermined: the residual is orthogonal to the columns of A
+    let a = rand_matrix(50, 6, 60);
+    let b = rand_matrix(50, 2, 61);
+    let x = a.lstsq(&b).unwrap();
+    assert_eq!(x.dim(), (6, 2));
+    let residual = &b - &a.dot(&x);
+    assert_abs_diff_eq!(a.t().dot(&residual), Array2::zeros((6, 2)), epsilon = 1e-10);
+    assert_abs_diff_eq!(a.lstsq(&b.column(1)).unwrap(), x.column(1), epsilon = 1e-10);
+
+    // consistent square system
+    let a = rand_matrix(8, 8, 62);
+    let x = rand_matrix(8, 1, 63);
+    assert_abs_diff_eq!(a.lstsq(&a.dot(&x)).unwrap(), x, epsilon = 1e-8);
+
+    // underdetermined: exact solution in the row space of A
+    let a = rand_matrix(4, 9, 64);
+    let b = array![1., -2., 0.5, 3.];
+    let x = a.lstsq(&b).unwrap();
+    assert_eq!(x.len(), 9);
+    assert_abs_diff_eq!(a.dot(&x), b, epsilon = 1e-10);
+    let via_normal = a.t().dot(&a.dot(&a.t()).solve(&b).unwrap());
+    assert_abs_diff_eq!(x, via_normal, epsilon = 1e-8);
+
+    let c = rand_complex_matrix(3, 5, 65);
+    let b = rand_complex_matrix(3, 2, 66);
+    let x = c.lstsq(&b).unwrap();
+    assert!(max_abs_diff(&c.dot(&x), &b) < 1e-10);
+
+    let rank_deficient = array![[1., 0.], [2., 0.], [3., 0.]];
+    assert_eq!(rank_deficient.lstsq(&array![1., 2., 3.]), Err(LinalgError::Singular));
+    assert!(matches!(a.lstsq(&array![1., 2.]), Err(LinalgError::Shape(_))));
+}
+
+#[test]
+fn eigh() {
+    for &n in &[0, 1, 2, 6, 40] {
+        let b = rand_matrix(n, n, 70 + n as u64);
+        let a = &b + &b.t();
+        let (w, v) = a.eigh(Triangle::Upper).unwrap();
+        assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+        assert_abs_diff_eq!(v.t().dot(&v), Array2::eye(n), epsilon = 1e-10);
+        assert_abs_diff_eq!((&v * &w).dot(&v.t()), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(a.eigvalsh(Triangle::Lower).unwrap(), w, epsilon = 1e-10);
+        // trace is the sum of the eigenvalues
+        assert_abs_diff_eq!(w.sum(), a.diag().sum(), epsilon = 1e-10);
+    }
+
+    // only one triangle is read
+    let a = array![[2., f64::NAN], [1., 2.]];
+    assert_abs_diff_eq!(a.eigvalsh(Triangle::Lower).unwrap(), array![1., 3.], epsilon = 1e-12);
+
+    // repeated eigenvalues
+    let (w, v) = Array2::<f64>::eye(3).eigh(Triangle::Upper).unwrap();
+    assert_eq!(w, array![1., 1., 1.]);
+    assert_eq!(v, Array2::eye(3));
+
+    let b = rand_complex_matrix(15, 15, 80);
+    let a = &b + &adjoint(&b);
+    let (w, v) = a.eigh(Triangle::Lower).unwrap();
+    let vw = Zip::from(&v).and_broadcast(&w).map_collect(|&x, &w| x * w);
+    assert!(max_abs_diff(&vw.dot(&adjoint(&v)), &a) < 1e-10);
+    assert!(max_abs_diff(&adjoint(&v).dot(&v), &Array2::eye(15)) < 1e-10);
+
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).eigh(Triangle::Upper).unwrap_err(),
+        LinalgError::NotSquare { rows: 2, cols: 3 }
+    );
+}
+
+#[test]
+fn svd() {
+    for &(m, n) in &[(0, 0), (1, 1), (4, 4), (7, 3), (3, 7), (30, 20)] {
+        let a = rand_matrix(m, n, (m * 10 + n) as u64);
+        let k = m.min(n);
+        let (u, s, vt) = a.svd(true, true).unwrap();
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        assert_eq!(u.dim(), (m, k));
+        assert_eq!(s.len(), k);
+        assert_eq!(vt.dim(), (k, n));
+        assert!(s.windows(2).into_iter().all(|s| s[0] >= s[1]));
+        assert_abs_diff_eq!((&u * &s).dot(&vt), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(k), epsilon = 1e-10);
+        assert_abs_diff_eq!(vt.dot(&vt.t()), Array2::eye(k), epsilon = 1e-10);
+
+        let (u2, s2, vt2) = a.svd(false, false).unwrap();
+        assert!(u2.is_none() && vt2.is_none());
+        assert_abs_diff_eq!(s2, s, epsilon = 1e-10);
+    }
+
+    // rank deficient: U is completed to an orthonormal basis
+    let a = array![[1., 2., 3.], [2., 4., 6.], [0., 0., 0.]];
+    let (u, s, vt) = a.svd(true, true).unwrap();
+    let u = u.unwrap();
+    assert_abs_diff_eq!(s[1], 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(3), epsilon = 1e-10);
+    assert_abs_diff_eq!((&u * &s).dot(&vt.unwrap()), a, epsilon = 1e-10);
+
+    let nan = array![[1., f64::NAN], [0., 1.]];
+    assert_eq!(nan.svd(false, false).unwrap_err(), LinalgError::NoConvergence);
+
+    let c = rand_complex_matrix(6, 9, 90);
+    let (u, s, vt) = c.svd(true, true).unwrap();
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    let us = Zip::from(&u).and_broadcast(&s).map_collect(|&x, &s| x * s);
+    assert!(max_abs_diff(&us.dot(&vt), &c) < 1e-10);
+    assert!(max_abs_diff(&adjoint(&u).dot(&u), &Array2::eye(6)) < 1e-10);
+}
+
+#[test]
+fn pinv_rank_norm_cond() {
+    let a = rand_matrix(8, 5, 100);
+    let p = a.pinv().unwrap();
+    assert_eq!(p.dim(), (5, 8));
+    assert_abs_diff_eq!(p.dot(&a), Array2::eye(5), epsilon = 1e-10);
+    assert_abs_diff_eq!(p, a.lstsq(&Array2::eye(8)).unwrap(), epsilon = 1e-10);
+    assert_abs_diff_eq!(p.pinv().unwrap(), a, epsilon = 1e-10);
+    assert_eq!(a.matrix_rank().unwrap(), 5);
+
+    // rank 2 product of 6 × 2 and 2 × 4 matrices
+    let r = rand_matrix(6, 2, 101).dot(&rand_matrix(2, 4, 102));
+    assert_eq!(r.matrix_rank().unwrap(), 2);
+    let p = r.pinv().unwrap();
+    assert_abs_diff_eq!(r.dot(&p).dot(&r), r, epsilon = 1e-10);
+    assert_abs_diff_eq!(p.dot(&r).dot(&p), p, epsilon = 1e-10);
+    assert_eq!(Array2::<f64>::zeros((3, 2)).matrix_rank().unwrap(), 0);
+    assert_eq!(Array2::<f64>::zeros((3, 2)).pinv().unwrap(), Array2::zeros((2, 3)));
+
+    let s = a.svd(false, false).unwrap().1;
+    assert_abs_diff_eq!(a.matrix_norm(MatrixNorm::Two).unwrap(), s[0], epsilon = 1e-12);
+    assert_abs_diff_eq!(a.matrix_norm(MatrixNorm::Nuc).unwrap(), s.sum(), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        a.matrix_norm(MatrixNorm::Fro).unwrap(),
+        s.mapv(|s| s * s).sum().sqrt(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(a.cond().unwrap(), s[0] / s[4], epsilon = 1e-10);
+    let at = a.t();
+    assert_eq!(at.matrix_norm(MatrixNorm::One).unwrap(), a.matrix_norm(MatrixNorm::Inf).unwrap());
+    assert_eq!(Array2::<f64>::zeros((0, 3)).matrix_norm(MatrixNorm::Two).unwrap(), 0.);
+
+    let c = array![[Complex64::new(3., 4.), Complex64::new(0., 0.)],
+                   [Complex64::new(0., 0.), Complex64::new(0., 1.)]];
+    assert_abs_diff_eq!(c.matrix_norm(MatrixNorm::One).unwrap(), 5., epsilon = 1e-12);
+    assert_abs_diff_eq!(c.cond().unwrap(), 5., epsilon = 1e-12);
+    assert_eq!(c.matrix_rank().unwrap(), 2);
+}
+
+#[test]
+fn expm() {
+    // exp(A) = V diag(exp(w)) Vᵀ for a symmetric A = V diag(w) Vᵀ, with
+    // norms that select each Padé approximant and the scaling
+    let s = rand_matrix(6, 6, 110);
+    let s = &s + &s.t();
+    for &scale in &[1e-3, 0.05, 0.3, 1., 3., 40.] {
+        let a = &s * scale;
+        let (w, v) = a.eigh(Triangle::Lower).unwrap();
+        let expected = (&v * &w.mapv(f64::exp)).dot(&v.t());
+        let e = a.expm().unwrap();
+        let tol = 1e-12 * expected.iter().fold(1., |acc, x| f64::max(acc, x.abs()));
+        assert_abs_diff_eq!(e, expected, epsilon = tol);
+    }
+
+    let a = rand_matrix(5, 5, 111);
+    let product = a.expm().unwrap().dot(&(-&a).expm().unwrap());
+    assert_abs_diff_eq!(product, Array2::eye(5), epsilon = 1e-12);
+    assert_eq!(Array2::<f64>::zeros((3, 3)).expm().unwrap(), Array2::eye(3));
+    assert_eq!(array![[0., 1.], [0., 0.]].expm().unwrap(), array![[1., 1.], [0., 1.]]);
+    assert_eq!(Array2::<f64>::zeros((0, 0)).expm().unwrap().dim(), (0, 0));
+
+    let z = array![[Complex64::new(0., std::f64::consts::PI)]];
+    assert!((z.expm().unwrap()[[0, 0]] - Complex64::new(-1., 0.)).norm() < 1e-12);
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).expm(),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+/// The residual norm `‖b - A x‖`.
+fn residual(a: &Array2<f64>, x: &Array1<f64>, b: &Array1<f64>) -> f64 {
+    (b - &a.dot(x)).mapv(|r| r * r).sum().sqrt()
+}
+
+#[test]
+fn cg_solve() {
+    let n = 40;
+    let m = rand_matrix(n, n, 120);
+    let a = m.t().dot(&m) + Array2::<f64>::eye(n);
+    let b = rand_matrix(n, 1, 121).remove_axis(Axis(1));
+    let options = IterOptions { rtol: 1e-10, ..Default::default() };
+    let solution = cg(&a, &b, &options).unwrap();
+    assert!(residual(&a, &solution.x, &b) <= 1e-10 * b.dot(&b).sqrt());
+    assert_eq!(solution.residuals.len(), solution.iterations + 1);
+    assert!(solution.iterations <= 2 * n);
+
+    // the Jacobi preconditioner, as a closure
+    let diag = a.diag().to_owned();
+    let jacobi = |x: ArrayView1<f64>| &x / &diag;
+    let options = IterOptions { preconditioner: Some(&jacobi), ..options };
+    let preconditioned = cg(&a, &b, &options).unwrap();
+    assert_abs_diff_eq!(preconditioned.x, solution.x, epsilon = 1e-8);
+
+    // a sparse operator: the 1-d Laplacian
+    let laplacian = Array2::from_shape_fn((n, n), |(i, j)| match i as isize - j as isize {
+        0 => 2.,
+        1 | -1 => -1.,
+        _ => 0.,
+    });
+    let sparse = ndarray::sparse::CsrMat::from_dense(&laplacian);
+    let solution = cg(&sparse, &b, &IterOptions { rtol: 1e-12, ..Default::default() }).unwrap();
+    assert_abs_diff_eq!(laplacian.dot(&solution.x), b, epsilon = 1e-9);
+
+    let a = rand_complex_matrix(8, 8, 122);
+    let a = a.t().mapv(|x| x.conj()).dot(&a) + Array2::<Complex64>::eye(8);
+    let b = rand_complex_matrix(8, 1, 123).remove_axis(Axis(1));
+    let x = cg(&a, &b, &IterOptions { rtol: 1e-12, ..Default::default() }).unwrap().x;
+    assert!((a.dot(&x) - &b).iter().all(|r| r.norm() < 1e-9));
+}
+
+#[test]
+fn cg_errors() {
+    let b = array![1., 1.];
+    let indefinite = array![[1., 0.], [0., -1.]];
+    let options = IterOptions::default();
+    assert_eq!(cg(&indefinite, &b, &options).unwrap_err(), LinalgError::NotPositiveDefinite);
+
+    let a = rand_matrix(30, 30, 124);
+    let a = a.t().dot(&a) + Array2::<f64>::eye(30) * 1e-3;
+    let b = Array1::ones(30);
+    let options = IterOptions { max_iter: 2, ..Default::default() };
+    assert_eq!(cg(&a, &b, &options).unwrap_err(), LinalgError::NoConvergence);
+
+    // a zero right hand side is solved without iterating
+    let solution = cg(&a, &Array1::zeros(30), &options).unwrap();
+    assert_eq!(solution.x, Array1::zeros(30));
+    assert_eq!((solution.iterations, solution.residuals), (0, vec![0.]));
+}
+
+#[test]
+fn bicgstab_solve() {
+    let n = 40;
+    let a = rand_matrix(n, n, 130) + Array2::<f64>::eye(n) * 8.;
+    let b = rand_matrix(n, 1, 131).remove_axis(Axis(1));
+    let options = IterOptions { rtol: 1e-10, ..Default::default() };
+    let solution = bicgstab(&a, &b, &options).unwrap();
+    assert!(residual(&a, &solution.x, &b) <= 1e-10 * b.dot(&b).sqrt());
+    assert_eq!(solution.residuals.len(), solution.iterations + 1);
+
+    let op = |x: ArrayView1<f64>| a.dot(&x);
+    let diag = a.diag().to_owned();
+    let jacobi = |x: ArrayView1<f64>| &x / &diag;
+    let options = IterOptions { preconditioner: Some(&jacobi), ..options };
+    let preconditioned = bicgstab(&op, &b, &options).unwrap();
+    assert_abs_diff_eq!(preconditioned.x, solution.x, epsilon = 1e-8);
+
+    let a = rand_complex_matrix(10, 10, 132) + Array2::<Complex64>::eye(10) * 4.;
+    let b = rand_complex_matrix(10, 1, 133).remove_axis(Axis(1));
+    let x = bicgstab(&a, &b, &IterOptions { rtol: 1e-12, ..Default::default() }).unwrap().x;
+    assert!((a.dot(&x) - &b).iter().all(|r| r.norm() < 1e-9));
+
+    let options = IterOptions { max_iter: 1, ..Default::default() };
+    assert_eq!(bicgstab(&a, &b, &options).unwrap_err(), LinalgError::NoConvergence);
+}
+
+#[test]
+fn gmres_solve() {
+    let n = 40;
+    let a = rand_matrix(n, n, 140) + Array2::<f64>::eye(n) * 6.;
+    let b = rand_matrix(n, 1, 141).remove_axis(Axis(1));
+    let options = IterOptions { rtol: 1e-10, ..Default::default() };
+    let solution = gmres(&a, &b, &options).unwrap();
+    assert!(residual(&a, &solution.x, &b) <= 1e-10 * b.dot(&b).sqrt());
+    assert_eq!(solution.residuals.len(), solution.iterations + 1);
+    assert!(solution.residuals.windows(2).all(|r| r[1] <= r[0] * (1. + 1e-12)));
+
+    // restarted, and preconditioned
+    let diag = a.diag().to_owned();
+    let jacobi = |x: ArrayView1<f64>| &x / &diag;
+    let options = IterOptions { restart: 4, preconditioner: Some(&jacobi), ..options };
+    let restarted = gmres(&a, &b, &options).unwrap();
+    assert!(restarted.iterations > 4);
+    assert_abs_diff_eq!(restarted.x, solution.x, epsilon = 1e-8);
+
+    // converges in n iterations without restarts
+    let a = rand_complex_matrix(6, 6, 142);
+    let b = rand_complex_matrix(6, 1, 143).remove_axis(Axis(1));
+    let solution = gmres(&a, &b, &IterOptions { rtol: 1e-12, ..Default::default() }).unwrap();
+    assert!(solution.iterations <= 6);
+    assert!((a.dot(&solution.x) - &b).iter().all(|r| r.norm() < 1e-9));
+
+    let options = IterOptions { max_iter: 3, ..Default::default() };
+    assert_eq!(gmres(&a, &b, &options).unwrap_err(), LinalgError::NoConvergence);
+}
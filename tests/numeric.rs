@@ -7,7 +7,7 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, s, Array, Array1, Array2, Array3, Axis};
 use std::f64;
 
 #[test]
@@ -307,3 +307,254 @@ fn std_axis_empty_axis() {
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+#[cfg(feature = "approx")]
+fn running_stats_batches() {
+    use ndarray::RunningStats;
+    let a = Array::linspace(-1., 4., 60).into_shape((20, 3)).unwrap();
+    let mut stats = RunningStats::new(3);
+    for chunk in a.axis_chunks_iter(Axis(0), 7) {
+        stats.update_batch(&chunk, Axis(0));
+    }
+    assert_eq!(stats.count(), 20);
+    assert_abs_diff_eq!(stats.mean().unwrap(), a.mean_axis(Axis(0)).unwrap(), epsilon = 1e-12);
+    assert_abs_diff_eq!(stats.var(1.), a.var_axis(Axis(0), 1.), epsilon = 1e-12);
+    assert_abs_diff_eq!(stats.std(0.), a.std_axis(Axis(0), 0.), epsilon = 1e-12);
+    assert_eq!(stats.min().unwrap(), a.row(0));
+    assert_eq!(stats.max().unwrap(), a.row(19));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn running_stats_merge() {
+    use ndarray::RunningStats;
+    let a = array![3., -1.5, 8., 0.25, 4., 4., -7., 2.];
+    let mut left = RunningStats::new(());
+    let mut right = RunningStats::new(());
+    left.update_batch(&a.slice(s![..3]), Axis(0));
+    right.update_batch(&a.slice(s![3..]), Axis(0));
+    left.merge(&right);
+    assert_eq!(left.count(), 8);
+    assert_abs_diff_eq!(left.mean().unwrap()[()], a.mean().unwrap(), epsilon = 1e-12);
+    assert_abs_diff_eq!(left.var(1.)[()], a.var(1.), epsilon = 1e-12);
+    assert_eq!(left.min().unwrap()[()], -7.);
+    assert_eq!(left.max().unwrap()[()], 8.);
+
+    let mut empty = RunningStats::new(());
+    empty.merge(&left);
+    assert_eq!(empty.count(), 8);
+    assert_abs_diff_eq!(empty.var(0.)[()], a.var(0.), epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn running_stats_empty() {
+    use ndarray::RunningStats;
+    let stats = RunningStats::<f64, _>::new((2, 2));
+    assert_eq!(stats.count(), 0);
+    assert!(stats.mean().is_none());
+    assert!(stats.min().is_none());
+    assert!(stats.max().is_none());
+    stats.var(0.).mapv(|x| assert!(x.is_nan()));
+}
+
+#[test]
+fn any_all_count_nonzero() {
+    let a = array![[1, -2, 3], [4, 5, -6]];
+    let neg = a.mapv(|x| x < 0);
+    assert!(neg.any());
+    assert!(!neg.all());
+    assert!(a.mapv(|x| x != 0).all());
+    assert_eq!(neg.count_nonzero(), 2);
+
+    // non-contiguous
+    let s = neg.slice(s![.., ..;2]);
+    assert!(s.any());
+    assert_eq!(s.count_nonzero(), 1);
+    assert!(!neg.slice(s![.., 0]).any());
+    assert!(!neg.t().slice(s![1, ..]).all());
+    assert!(a.t().mapv(|x| x > -7).all());
+
+    let empty = Array2::<bool>::default((0, 3));
+    assert!(!empty.any());
+    assert!(empty.all());
+    assert_eq!(empty.count_nonzero(), 0);
+}
+
+#[test]
+fn any_all_axis() {
+    let a = array![[[true, false], [false, false]], [[true, true], [false, true]]];
+    assert_eq!(a.any_axis(Axis(0)), array![[true, true], [false, true]]);
+    assert_eq!(a.all_axis(Axis(0)), array![[true, false], [false, false]]);
+    assert_eq!(a.any_axis(Axis(2)), array![[true, false], [true, true]]);
+    assert_eq!(a.all_axis(Axis(2)), array![[false, false], [true, false]]);
+    let empty = Array2::<bool>::default((2, 0));
+    assert_eq!(empty.any_axis(Axis(1)), array![false, false]);
+    assert_eq!(empty.all_axis(Axis(1)), array![true, true]);
+}
+
+#[test]
+fn nonzero() {
+    let a = array![[[true, false], [false, false]], [[true, true], [false, true]]];
+    assert_eq!(
+        a.nonzero(),
+        vec![array![0, 1, 1, 1], array![0, 0, 0, 1], array![0, 0, 1, 1]]
+    );
+    assert_eq!(a.t().nonzero()[0], array![0, 0, 1, 1]);
+    assert_eq!(arr0(true).nonzero(), Vec::<Array1<usize>>::new());
+    assert_eq!(Array1::<bool>::default(3).nonzero(), vec![array![]]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_maths() {
+    let a = array![[-1.5, 0.25], [2.5, 4.]];
+    assert_eq!(a.abs(), a.mapv(f64::abs));
+    assert_eq!(a.signum(), array![[-1., 1.], [1., 1.]]);
+    assert_eq!(a.floor(), array![[-2., 0.], [2., 4.]]);
+    assert_eq!(a.ceil(), array![[-1., 1.], [3., 4.]]);
+    assert_eq!(a.round(), array![[-2., 0.], [3., 4.]]);
+    assert_eq!(a.exp(), a.mapv(f64::exp));
+    assert_eq!(a.sin(), a.mapv(f64::sin));
+    assert_eq!(a.tanh(), a.mapv(f64::tanh));
+    assert_eq!(a.powi(2), a.mapv(|x| x * x));
+    assert_eq!(a.slice(s![.., 1]).sqrt(), array![0.5, 2.]);
+    assert_eq!(a.slice(s![.., 1]).log2(), array![-2., 2.]);
+    assert_eq!(a.slice(s![.., 1]).powf(0.5), array![0.5, 2.]);
+    assert_eq!(a.clip(0., 2.), array![[0., 0.25], [2., 2.]]);
+
+    let b = array![f64::NAN, f64::INFINITY, 1.];
+    assert_eq!(b.is_nan(), array![true, false, false]);
+    assert_eq!(b.is_finite(), array![false, false, true]);
+    assert!(b.clip(0., 2.)[0].is_nan());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_maths_inplace() {
+    let a = Array::linspace(-2., 2., 12).into_shape((3, 4)).unwrap();
+
+    // contiguous and non-contiguous receivers
+    let mut b = a.clone();
+    b.exp_inplace();
+    assert_eq!(b, a.exp());
+    let mut b = a.t().to_owned();
+    b.cos_inplace();
+    assert_eq!(b, a.t().cos());
+    let mut b = a.clone();
+    b.slice_mut(s![.., ..;2]).abs_inplace();
+    assert_eq!(b.slice(s![.., ..;2]), a.slice(s![.., ..;2]).abs());
+    assert_eq!(b.slice(s![.., 1..;2]), a.slice(s![.., 1..;2]));
+
+    let mut b = a.clone();
+    b.powi_inplace(3);
+    assert_eq!(b, a.powi(3));
+    let mut b = a.clone();
+    b.clip_inplace(-1., 1.);
+    assert_eq!(b, a.clip(-1., 1.));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "std")]
+fn clip_bad_bounds() {
+    let a = array![1., 2.];
+    let _ = a.clip(2., 1.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nan_to_num_clip_broadcast() {
+    let a = array![[f64::NAN, -5.], [f64::INFINITY, 0.5], [f64::NEG_INFINITY, 2.]];
+    let b = a.nan_to_num(0., f64::MAX, f64::MIN);
+    assert_eq!(b, array![[0., -5.], [f64::MAX, 0.5], [f64::MIN, 2.]]);
+    let mut c = a.t().to_owned();
+    c.nan_to_num_inplace(-1., 1., -2.);
+    assert_eq!(c, array![[-1., 1., -2.], [-5., 0.5, 2.]]);
+
+    // NaN is left unchanged, and the bounds broadcast
+    let d = a.clip_broadcast(&arr1(&[-1., 0.]), &arr0(1.));
+    assert!(d[[0, 0]].is_nan());
+    assert_eq!(d.slice(s![1.., ..]), array![[1., 0.5], [-1., 1.]]);
+    assert_eq!(d[[0, 1]], 0.);
+}
+
+#[cfg(feature = "approx")]
+fn naive_softmax(a: &Array2<f64>) -> Array2<f64> {
+    let mut p = a.mapv(f64::exp);
+    for mut row in p.rows_mut() {
+        let sum = row.sum();
+        row /= sum;
+    }
+    p
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn logsumexp_softmax() {
+    let a = array![[0.5, -1.25, 3.], [2., 2., -0.75], [-4., 0., 1.5]];
+    let naive_lse = a.mapv(f64::exp).sum_axis(Axis(1)).mapv(f64::ln);
+    assert_abs_diff_eq!(a.logsumexp(Axis(1)), naive_lse, epsilon = 1e-12);
+    assert_abs_diff_eq!(a.softmax(Axis(1)), naive_softmax(&a), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        a.log_softmax(Axis(1)),
+        naive_softmax(&a).mapv(f64::ln),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        a.softmax(Axis(0)),
+        naive_softmax(&a.t().to_owned()).reversed_axes(),
+        epsilon = 1e-12
+    );
+
+    // IxDyn, middle axis
+    let b = Array::linspace(-3., 3., 24).into_shape((2, 3, 4)).unwrap().into_dyn();
+    let p = b.softmax(Axis(1));
+    assert_abs_diff_eq!(p.sum_axis(Axis(1)), Array::ones(vec![2, 4]).into_dyn(), epsilon = 1e-12);
+    assert_abs_diff_eq!(p.mapv(f64::ln), b.log_softmax(Axis(1)), epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn logsumexp_softmax_extreme() {
+    let a = array![[1000., 999., 998.], [-1000., -1001., -1002.]];
+    let shifted = array![[0., -1., -2.], [0., -1., -2.]];
+    let lse = a.logsumexp(Axis(1));
+    let shifted_lse = shifted.mapv(f64::exp).sum_axis(Axis(1)).mapv(f64::ln);
+    assert_abs_diff_eq!(lse, &shifted_lse + &array![1000., -1000.], epsilon = 1e-9);
+    assert_abs_diff_eq!(a.softmax(Axis(1)), naive_softmax(&shifted), epsilon = 1e-12);
+    assert!(a.log_softmax(Axis(1)).iter().all(|x| x.is_finite()));
+
+    let inf = array![f64::NEG_INFINITY, f64::NEG_INFINITY];
+    assert_eq!(inf.logsumexp(Axis(0))[()], f64::NEG_INFINITY);
+    assert_eq!(Array1::<f64>::zeros(0).logsumexp(Axis(0))[()], f64::NEG_INFINITY);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn normalize() {
+    use ndarray::Norm;
+    let a = array![[3., -4.], [0., 0.], [1e300, 1e300], [1e-300, -1e-300]];
+    let l2 = a.normalize(Norm::L2, Axis(1));
+    let half = f64::sqrt(0.5);
+    assert_abs_diff_eq!(
+        l2,
+        array![[0.6, -0.8], [0., 0.], [half, half], [half, -half]],
+        epsilon = 1e-12
+    );
+    let l1 = a.normalize(Norm::L1, Axis(1));
+    assert_abs_diff_eq!(
+        l1,
+        array![[3. / 7., -4. / 7.], [0., 0.], [0.5, 0.5], [0.5, -0.5]],
+        epsilon = 1e-12
+    );
+    let linf = a.normalize(Norm::Linf, Axis(1));
+    assert_abs_diff_eq!(
+        linf,
+        array![[0.75, -1.], [0., 0.], [1., 1.], [1., -1.]],
+        epsilon = 1e-12
+    );
+    let col = array![[3.], [4.]].normalize(Norm::L2, Axis(0));
+    assert_abs_diff_eq!(col, array![[0.6], [0.8]], epsilon = 1e-12);
+}
@@ -7,6 +7,7 @@
 #![cfg(feature = "std")]
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::kron;
+use ndarray::linalg::{Diag, Transpose, Triangle};
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{Data, LinalgScalar};
@@ -822,6 +823,234 @@ fn vec_mat_mul() {
     }
 }
 
+#[cfg(feature = "approx")]
+fn strided_mat64(m: Ix, n: Ix, s1: Ixs, s2: Ixs, rev: bool) -> Array2<f64> {
+    let (l1, l2) = (s1.abs() as usize, s2.abs() as usize);
+    if rev {
+        range_mat64(n * l2, m * l1)
+            .slice_move(s![..;s2, ..;s1])
+            .reversed_axes()
+    } else {
+        range_mat64(m * l1, n * l2).slice_move(s![..;s1, ..;s2])
+    }
+}
+
+#[cfg(feature = "approx")]
+fn strided_vec64(n: Ix, s: Ixs) -> Array1<f64> {
+    range1_mat64(n * s.abs() as usize).slice_move(s![..;s])
+}
+
+/// The `uplo` triangle of `a`, with ones on the diagonal if `diag` is unit
+#[cfg(feature = "approx")]
+fn triangle_of(a: &Array2<f64>, uplo: Triangle, diag: Diag) -> Array2<f64> {
+    Array2::from_shape_fn(a.dim(), |(i, j)| match uplo {
+        _ if i == j && diag == Diag::Unit => 1.,
+        Triangle::Upper if i <= j => a[[i, j]],
+        Triangle::Lower if i >= j => a[[i, j]],
+        _ => 0.,
+    })
+}
+
+/// The symmetric matrix with the `uplo` triangle of `a`
+#[cfg(feature = "approx")]
+fn symmetric_of(a: &Array2<f64>, uplo: Triangle) -> Array2<f64> {
+    let t = triangle_of(a, uplo, Diag::NonUnit);
+    &t + &t.t() - Array2::from_diag(&t.diag())
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn ger_syr_syrk() {
+    use approx::assert_relative_eq;
+
+    let alpha = -2.3;
+    let beta = 3.14;
+    let close = |a: &Array2<f64>, b: &Array2<f64>| {
+        assert_relative_eq!(a, b, epsilon = 1e-12, max_relative = 1e-7);
+    };
+    let sizes = vec![(4, 4), (8, 8), (17, 15), (4, 17), (1, 8), (8, 1)];
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &(m, n) in &sizes {
+                for &rev in &[false, true] {
+                    let x = strided_vec64(m, s1);
+                    let y = strided_vec64(n, s2);
+                    let x_col = x.view().insert_axis(Axis(1));
+                    let y_row = y.view().insert_axis(Axis(0));
+
+                    let mut a = strided_mat64(m, n, s1, s2, rev);
+                    let answer = &a + &(alpha * reference_mat_mul(&x_col, &y_row));
+                    a.ger(alpha, &x, &y);
+                    close(&a, &answer);
+
+                    let b = strided_mat64(m, n, s2, s1, !rev);
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        // the other triangle is not modified
+                        let outside = |c: &Array2<f64>| c - &triangle_of(c, uplo, Diag::NonUnit);
+
+                        let mut c = strided_mat64(m, m, s1, s2, rev);
+                        let c0 = c.clone();
+                        let xx = reference_mat_mul(&x_col, &x_col.t());
+                        let answer = symmetric_of(&c, uplo) + alpha * xx;
+                        c.syr(alpha, &x, uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                        assert_eq!(outside(&c), outside(&c0));
+
+                        let bb = reference_mat_mul(&b, &b.t());
+                        let answer = beta * symmetric_of(&c, uplo) + alpha * bb;
+                        c.syrk(alpha, &b, beta, uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                        assert_eq!(outside(&c), outside(&c0));
+
+                        let mut c = strided_mat64(n, n, s2, s1, rev);
+                        let answer = alpha * reference_mat_mul(&b.t(), &b);
+                        c.syrk(alpha, &b.t(), 0., uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn symv() {
+    use approx::assert_relative_eq;
+
+    let alpha = -2.3;
+    let beta = 3.14;
+    let close = |a: &Array1<f64>, b: &Array1<f64>| {
+        assert_relative_eq!(a, b, epsilon = 1e-12, max_relative = 1e-7);
+    };
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &n in &[1, 4, 8, 17] {
+                for &rev in &[false, true] {
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        let a = strided_mat64(n, n, s1, s2, rev);
+                        let x = strided_vec64(n, s2);
+                        let mut y = strided_vec64(n, s1);
+                        let x_col = x.view().insert_axis(Axis(1));
+                        let product =
+                            reference_mat_mul(&symmetric_of(&a, uplo), &x_col).remove_axis(Axis(1));
+                        let answer = alpha * &product + beta * &y;
+                        a.symv(alpha, &x, beta, &mut y, uplo);
+                        close(&y, &answer);
+
+                        // y is not read if beta is zero
+                        y.fill(f64::NAN);
+                        a.symv(alpha, &x, 0., &mut y, uplo);
+                        close(&y, &(alpha * &product));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn trmv_trsv() {
+    use approx::assert_relative_eq;
+
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &n in &[1, 4, 8, 17] {
+                for &rev in &[false, true] {
+                    // well conditioned
+                    let mut a = strided_mat64(n, n, s1, s2, rev);
+                    a.mapv_inplace(|x| x / (n * n) as f64);
+                    a.diag_mut().map_inplace(|x| *x += 1.);
+                    let x0 = strided_vec64(n, s2);
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        for &trans in &[Transpose::No, Transpose::Transpose, Transpose::Adjoint] {
+                            for &diag in &[Diag::NonUnit, Diag::Unit] {
+                                let mut t = triangle_of(&a, uplo, diag);
+                                if trans != Transpose::No {
+                                    t = t.reversed_axes();
+                                }
+                                let answer = reference_mat_mul(&t, &x0.view().insert_axis(Axis(1)))
+                                    .remove_axis(Axis(1));
+                                let mut x = x0.clone();
+                                a.trmv(&mut x, uplo, trans, diag);
+                                assert_relative_eq!(x, answer, max_relative = 1e-7);
+                                a.trsv(&mut x, uplo, trans, diag);
+                                assert_relative_eq!(x, x0, epsilon = 1e-9, max_relative = 1e-7);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trmv_trsv_adjoint_complex() {
+    use num_complex::Complex64 as c64;
+
+    let a = array![[c64::new(2., 1.), c64::new(1., -1.)], [c64::new(9., 9.), c64::new(3., -2.)]];
+    let x0 = array![c64::new(1., 0.), c64::new(0., 1.)];
+    let mut x = x0.clone();
+    a.trmv(&mut x, Triangle::Upper, Transpose::Adjoint, Diag::NonUnit);
+    assert_eq!(x, array![c64::new(2., -1.), c64::new(-1., 4.)]);
+    a.trsv(&mut x, Triangle::Upper, Transpose::Adjoint, Diag::NonUnit);
+    assert!(x.iter().zip(&x0).all(|(x, y)| (x - y).norm() < 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn ger_shape_mismatch() {
+    let mut a = Array2::<f64>::zeros((3, 4));
+    a.ger(1., &Array1::zeros(3), &Array1::zeros(3));
+}
+
+#[test]
+#[should_panic]
+fn trsv_not_square() {
+    let a = Array2::<f64>::zeros((3, 4));
+    a.trsv(&mut Array1::zeros(3), Triangle::Lower, Transpose::No, Diag::Unit);
+}
+
+#[test]
+fn matrix_power() {
+    let a = arr2(&[[2, 1, 0], [0, 1, 3], [1, 0, 1]]);
+    let mut power = Array2::eye(3);
+    for n in 0..12 {
+        assert_eq!(a.matrix_power(n), power);
+        power = power.dot(&a);
+    }
+    // non-contiguous input
+    let b = a.slice(s![..;-1, ..]);
+    assert_eq!(b.matrix_power(3), b.dot(&b).dot(&b));
+    assert_eq!(Array2::<f32>::zeros((0, 0)).matrix_power(5).dim(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn matrix_power_not_square() {
+    Array2::<f32>::zeros((2, 3)).matrix_power(2);
+}
+
+#[test]
+fn outer_product() {
+    use ndarray::linalg::outer;
+
+    let a = arr1(&[1, 2, 3]);
+    let b = arr1(&[1, -1]);
+    let c = outer(&a, &b);
+    assert_eq!(c, arr2(&[[1, -1], [2, -2], [3, -3]]));
+    assert_eq!(outer(&b, &a), c.t());
+    assert_eq!(outer(&a.slice(s![..;-2]), &b), arr2(&[[3, -3], [1, -1]]));
+    assert_eq!(outer(&a, &arr1(&[])).dim(), (3, 0));
+    let (x, y) = (a.mapv(|x| x as f64), b.mapv(|x| x as f64));
+    assert_eq!(
+        outer(&x, &y),
+        x.view().insert_axis(Axis(1)).dot(&y.view().insert_axis(Axis(0)))
+    );
+}
+
 #[test]
 fn kron_square_f64() {
     let a = arr2(&[[1.0, 0.0], [0.0, 1.0]]);
@@ -883,3 +1112,91 @@ fn kron_i64() {
     ]);
     assert_eq!(kron(&a, &b), r);
 }
+
+#[test]
+fn matmul_batched() {
+    let a = Array::from_shape_fn((3, 4, 5), |(b, i, j)| (b * 20 + i * 5 + j) as i32);
+    let b = Array::from_shape_fn((3, 5, 2), |(b, i, j)| (b + i * 2 + j) as i32 - 4);
+    let c = a.matmul(&b).into_dimensionality::<Ix3>().unwrap();
+    assert_eq!(c.shape(), &[3, 4, 2]);
+    for k in 0..3 {
+        assert_eq!(c.index_axis(Axis(0), k), a.index_axis(Axis(0), k).dot(&b.index_axis(Axis(0), k)));
+    }
+
+    // non-contiguous operands
+    let at = a.clone().permuted_axes([0, 2, 1]).to_owned();
+    let at = at.view().permuted_axes([0, 2, 1]);
+    assert_eq!(at.matmul(&b), c.clone().into_dyn());
+    let reversed = c.slice(s![.., .., ..;-1]).into_dyn();
+    assert_eq!(a.matmul(&b.slice(s![.., .., ..;-1])), reversed);
+}
+
+#[test]
+fn matmul_broadcast() {
+    // batch axes broadcast: (2, 1, 3, 4) @ (5, 4, 2) -> (2, 5, 3, 2)
+    let a = Array::from_shape_fn((2, 1, 3, 4), |(p, _, i, j)| (p * 12 + i * 4 + j) as f64);
+    let b = Array::from_shape_fn((5, 4, 2), |(q, i, j)| (q as f64) - (i * 2 + j) as f64);
+    let c = a.matmul(&b).into_dimensionality::<Ix4>().unwrap();
+    assert_eq!(c.shape(), &[2, 5, 3, 2]);
+    for p in 0..2 {
+        for q in 0..5 {
+            let (lhs, rhs): (ArrayView2<'_, f64>, ArrayView2<'_, f64>) =
+                (a.slice(s![p, 0, .., ..]), b.slice(s![q, .., ..]));
+            let expected = lhs.dot(&rhs);
+            assert_eq!(c.slice(s![p, q, .., ..]), expected);
+        }
+    }
+
+    // a single matrix is broadcast against a stack
+    let m = Array::from_shape_fn((3, 3), |(i, j)| (i + 2 * j) as f64);
+    let stack = b.slice(s![.., ..3, ..]);
+    let c = m.matmul(&stack);
+    assert_eq!(c.shape(), &[5, 3, 2]);
+    assert_eq!(c.index_axis(Axis(0), 4), m.dot(&stack.index_axis(Axis(0), 4)).into_dyn());
+
+    // dynamic dimensionality
+    assert_eq!(a.view().into_dyn().matmul(&b.view().into_dyn()).shape(), &[2, 5, 3, 2]);
+}
+
+#[test]
+fn matmul_promote_1d() {
+    let a = arr2(&[[1., 2.], [3., 4.], [5., 6.]]);
+    let v2 = arr1(&[1., -1.]);
+    let v3 = arr1(&[1., 0., 2.]);
+    assert_eq!(a.matmul(&v2), a.dot(&v2).into_dyn());
+    assert_eq!(v3.matmul(&a), v3.dot(&a).into_dyn());
+    assert_eq!(v2.matmul(&v2), arr0(2.).into_dyn());
+
+    let stack = Array::from_shape_fn((4, 3, 2), |(b, i, j)| (b + i + j) as f64);
+    assert_eq!(stack.matmul(&v2).shape(), &[4, 3]);
+    assert_eq!(v3.matmul(&stack).shape(), &[4, 2]);
+    let row = v3.matmul(&stack).into_dimensionality::<Ix2>().unwrap();
+    assert_eq!(row.row(1), v3.dot(&stack.index_axis(Axis(0), 1)));
+}
+
+#[test]
+fn matmul_empty() {
+    let a = Array3::<f32>::zeros((2, 3, 0));
+    let b = Array3::<f32>::zeros((2, 0, 4));
+    assert_eq!(a.matmul(&b), Array::zeros((2, 3, 4)).into_dyn());
+    let a = Array3::<f32>::ones((0, 3, 2));
+    assert_eq!(a.matmul(&Array2::<f32>::ones((2, 4))).shape(), &[0, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn matmul_incompatible_inner() {
+    Array3::<f32>::zeros((2, 3, 4)).matmul(&Array3::<f32>::zeros((2, 3, 4)));
+}
+
+#[test]
+#[should_panic]
+fn matmul_incompatible_batch() {
+    Array3::<f32>::zeros((2, 3, 4)).matmul(&Array3::<f32>::zeros((3, 4, 4)));
+}
+
+#[test]
+#[should_panic]
+fn matmul_zero_dimensional() {
+    arr0(1.).matmul(&arr1(&[1.]));
+}
@@ -86,3 +86,77 @@ fn test_axis_chunks_iter_mut() {
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+#[cfg(feature = "approx")]
+fn test_par_running_stats() {
+    use approx::assert_abs_diff_eq;
+    let a = Array::linspace(0., 1.0f64, M * N)
+        .into_shape((M, N))
+        .unwrap();
+    let stats = a.par_running_stats(Axis(0));
+    assert_eq!(stats.count(), M);
+    assert_abs_diff_eq!(stats.mean().unwrap(), a.mean_axis(Axis(0)).unwrap(), epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.var(1.), a.var_axis(Axis(0), 1.), epsilon = 1e-9);
+    assert_eq!(stats.min().unwrap(), a.row(0));
+    assert_eq!(stats.max().unwrap(), a.row(M - 1));
+}
+
+#[test]
+fn test_par_matmul() {
+    let a = Array::from_shape_fn((64, 8, 5), |(b, i, j)| (b * 40 + i * 5 + j) as f64);
+    let b = Array::from_shape_fn((5, 3), |(i, j)| (i as f64) - (j as f64));
+    let c = a.par_matmul(&b);
+    assert_eq!(c, a.matmul(&b));
+    assert_eq!(c.index_axis(Axis(0), 63), a.index_axis(Axis(0), 63).dot(&b).into_dyn());
+}
+
+#[test]
+fn test_par_sparse_dot() {
+    use ndarray::sparse::CsrMat;
+
+    let a = Array::from_shape_fn((300, 200), |(i, j)| ((i * 7 + j) % 11 == 0) as i32 as f64);
+    let csr = CsrMat::from_dense(&a);
+    let x = Array::from_shape_fn(200, |i| i as f64 - 100.);
+    assert_eq!(csr.par_dot(&x), csr.dot(&x));
+    assert_eq!(csr.par_dot(&x), a.dot(&x));
+}
+
+#[test]
+fn test_par_scatter_add_segments() {
+    let src = Array::from_shape_fn((2000, 3), |(i, j)| (i * 3 + j) as f64);
+    let indices: Vec<isize> = (0..2000).map(|i| (i * i % 13) as isize - 6).collect();
+    let mut a = Array::ones((13, 3));
+    let mut b = a.clone();
+    a.par_scatter_add(Axis(0), &indices, &src);
+    b.scatter_add(Axis(0), &indices, &src);
+    assert_eq!(a, b);
+
+    let ids: Vec<usize> = (0..2000).map(|i| i / 9 * 2).collect();
+    assert_eq!(src.par_segment_sum(Axis(0), &ids), src.segment_sum(Axis(0), &ids));
+    assert_eq!(src.par_segment_max(Axis(0), &ids), src.segment_max(Axis(0), &ids));
+
+    let neg = Array::from_elem((20, 3), f64::NEG_INFINITY);
+    let ids: Vec<usize> = (0..20).map(|i| i / 4 * 2).collect();
+    let max = neg.par_segment_max(Axis(0), &ids);
+    assert_eq!(max, neg.segment_max(Axis(0), &ids));
+    assert!(max.row(0).iter().all(|&x| x == f64::NEG_INFINITY));
+    assert!(max.row(1).iter().all(|&x| x == f64::MIN));
+}
+
+#[test]
+fn test_par_sort_axis() {
+    let a = Array::from_shape_fn((300, 40), |(i, j)| (i * 37 + j * 11) % 23);
+    for &axis in &[Axis(0), Axis(1)] {
+        let mut b = a.clone();
+        let mut c = a.clone();
+        b.par_sort_axis(axis);
+        c.sort_axis(axis);
+        assert_eq!(b, c);
+        let mut d = a.clone();
+        d.par_sort_axis_unstable_by(axis, |x, y| y.cmp(x));
+        c.sort_axis_by(axis, |x, y| y.cmp(x));
+        assert_eq!(d, c);
+        assert_eq!(a.par_argsort_axis(axis), a.argsort_axis(axis));
+    }
+}
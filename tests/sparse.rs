@@ -0,0 +1,162 @@
+use ndarray::prelude::*;
+use ndarray::sparse::{CooMat, CsrMat};
+use ndarray::{ErrorKind, ShapeError};
+
+fn sample() -> Array2<i32> {
+    array![[1, 0, 0, 2], [0, 0, 0, 0], [0, 3, 4, 0]]
+}
+
+#[test]
+fn csr_from_to_dense() {
+    let a = sample();
+    let csr = CsrMat::from_dense(&a);
+    assert_eq!(csr.shape(), (3, 4));
+    assert_eq!(csr.nnz(), 4);
+    assert_eq!(csr.indptr(), &[0, 2, 2, 4]);
+    assert_eq!(csr.indices(), &[0, 3, 1, 2]);
+    assert_eq!(csr.data(), &[1, 2, 3, 4]);
+    assert_eq!(csr.to_dense(), a);
+    assert_eq!(CsrMat::from_dense(&a.t()).to_dense(), a.t());
+    assert_eq!(CsrMat::from_dense(&a.slice(s![..;-1, ..])).to_dense(), a.slice(s![..;-1, ..]));
+
+    assert_eq!(csr.row(2), (&[1, 2][..], &[3, 4][..]));
+    assert_eq!(csr.row(1), (&[][..], &[][..]));
+    assert_eq!(csr.get(0, 3), Some(&2));
+    assert_eq!(csr.get(0, 2), None);
+    let entries: Vec<_> = csr.iter().map(|(i, j, &x)| (i, j, x)).collect();
+    assert_eq!(entries, vec![(0, 0, 1), (0, 3, 2), (2, 1, 3), (2, 2, 4)]);
+
+    assert_eq!(CsrMat::<f64>::zeros((2, 3)).to_dense(), Array2::zeros((2, 3)));
+    assert_eq!(CsrMat::<f64>::eye(3).to_dense(), Array2::eye(3));
+    assert_eq!(CsrMat::<f64>::zeros((0, 3)).nnz(), 0);
+}
+
+#[test]
+fn csr_try_new() {
+    let csr = CsrMat::try_new((2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1., 2., 3.]).unwrap();
+    assert_eq!(csr.to_dense(), array![[0., 0., 1.], [2., 3., 0.]]);
+
+    let err = |kind| Err(ShapeError::from_kind(kind));
+    let incompatible = err(ErrorKind::IncompatibleShape);
+    assert_eq!(CsrMat::try_new((2, 3), vec![0, 1], vec![0], vec![1.]), incompatible);
+    assert_eq!(CsrMat::try_new((2, 3), vec![1, 1, 1], vec![0], vec![1.]), incompatible);
+    assert_eq!(CsrMat::try_new((2, 3), vec![0, 2, 1], vec![0], vec![1.]), incompatible);
+    assert_eq!(CsrMat::try_new((2, 3), vec![0, 1, 2], vec![0, 1], vec![1.]), incompatible);
+    // unsorted or repeated columns
+    assert_eq!(CsrMat::try_new((1, 3), vec![0, 2], vec![2, 1], vec![1., 2.]), incompatible);
+    assert_eq!(CsrMat::try_new((1, 3), vec![0, 2], vec![1, 1], vec![1., 2.]), incompatible);
+    assert_eq!(
+        CsrMat::try_new((1, 3), vec![0, 1], vec![3], vec![1.]),
+        err(ErrorKind::OutOfBounds)
+    );
+}
+
+#[test]
+fn coo_to_csr() {
+    let mut coo = CooMat::new((3, 4));
+    coo.push(2, 2, 4);
+    coo.push(0, 3, 1);
+    coo.push(2, 1, 3);
+    coo.push(0, 0, 1);
+    coo.push(0, 3, 1);
+    assert_eq!(coo.nnz(), 5);
+    let csr = coo.to_csr();
+    assert_eq!(csr.nnz(), 4);
+    assert_eq!(csr, CsrMat::from_dense(&sample()));
+    assert_eq!(coo.to_dense(), sample());
+    assert_eq!(coo.transpose().to_dense(), sample().t());
+
+    let coo = csr.to_coo();
+    assert_eq!(coo.rows(), &[0, 0, 2, 2]);
+    assert_eq!(coo.cols(), &[0, 3, 1, 2]);
+    assert_eq!(coo.data(), &[1, 2, 3, 4]);
+    assert_eq!(CooMat::from_dense(&sample()), coo);
+
+    let coo = CooMat::from_triplets((2, 2), vec![1, 0, 1], vec![1, 0, 1], vec![1, 2, 3]).unwrap();
+    assert_eq!(coo.to_dense(), array![[2, 0], [0, 4]]);
+    assert_eq!(
+        CooMat::from_triplets((2, 2), vec![0], vec![0, 1], vec![1]),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+    assert_eq!(
+        CooMat::from_triplets((2, 2), vec![2], vec![0], vec![1]),
+        Err(ShapeError::from_kind(ErrorKind::OutOfBounds))
+    );
+}
+
+#[test]
+#[should_panic]
+fn coo_push_out_of_bounds() {
+    CooMat::new((2, 2)).push(0, 2, 1.);
+}
+
+#[test]
+fn csr_transpose() {
+    let a = sample();
+    let t = CsrMat::from_dense(&a).transpose();
+    assert_eq!(t, CsrMat::from_dense(&a.t()));
+    assert_eq!(t.shape(), (4, 3));
+    assert_eq!(t.transpose().to_dense(), a);
+}
+
+#[test]
+fn csr_dot() {
+    let a = sample();
+    let csr = CsrMat::from_dense(&a);
+    let x = array![1, -1, 2, 3];
+    assert_eq!(csr.dot(&x), a.dot(&x));
+    assert_eq!(csr.dot(&x.slice(s![..;-1])), a.dot(&x.slice(s![..;-1])));
+    let y = array![2, 5, -1];
+    assert_eq!(y.dot(&csr), y.dot(&a));
+
+    let b = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as i32 - 7);
+    assert_eq!(csr.dot(&b), a.dot(&b));
+    assert_eq!(csr.dot(&b.slice(s![.., ..;-2])), a.dot(&b.slice(s![.., ..;-2])));
+    let c = Array::from_shape_fn((2, 3), |(i, j)| (i + 2 * j) as i32);
+    assert_eq!(c.dot(&csr), c.dot(&a));
+    assert_eq!(c.t().t().dot(&csr), c.dot(&a));
+
+    let empty = CsrMat::<f64>::zeros((0, 4));
+    assert_eq!(empty.dot(&Array1::ones(4)), Array1::zeros(0));
+}
+
+#[test]
+#[should_panic]
+fn csr_dot_shape_mismatch() {
+    CsrMat::from_dense(&sample()).dot(&array![1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn csr_left_dot_shape_mismatch() {
+    array![1, 2, 3, 4].dot(&CsrMat::from_dense(&sample()));
+}
+
+#[test]
+fn csr_elementwise() {
+    let a = sample();
+    let b = array![[0, 5, 0, -2], [0, 0, 0, 1], [0, 1, 0, 0]];
+    let (sa, sb) = (CsrMat::from_dense(&a), CsrMat::from_dense(&b));
+
+    let sum = &sa + &sb;
+    assert_eq!(sum.to_dense(), &a + &b);
+    // the union of the positions, including the cancelled one
+    assert_eq!(sum.nnz(), 6);
+    assert_eq!(sum.get(0, 3), Some(&0));
+    assert_eq!((&sa - &sb).to_dense(), &a - &b);
+    assert_eq!((&sb - &sa).to_dense(), &b - &a);
+    let product = &sa * &sb;
+    assert_eq!(product.to_dense(), &a * &b);
+    assert_eq!(product.nnz(), 2);
+
+    assert_eq!(sa.map(|&x| x as f64 * 0.5).to_dense(), a.mapv(|x| x as f64 * 0.5));
+    let mut sc = sa.clone();
+    sc.data_mut().iter_mut().for_each(|x| *x *= 2);
+    assert_eq!(sc.to_dense(), &a * 2);
+}
+
+#[test]
+#[should_panic]
+fn csr_add_shape_mismatch() {
+    let _ = &CsrMat::<f64>::zeros((2, 3)) + &CsrMat::zeros((3, 2));
+}
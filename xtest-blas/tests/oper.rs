@@ -9,7 +9,8 @@ use ndarray::prelude::*;
 
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::general_mat_vec_mul;
-use ndarray::{Data, Ix, LinalgScalar};
+use ndarray::linalg::{Diag, Transpose, Triangle};
+use ndarray::{Data, Ix, Ixs, LinalgScalar};
 
 use approx::assert_relative_eq;
 use defmac::defmac;
@@ -426,3 +427,215 @@ fn vec_mat_mul() {
         }
     }
 }
+
+fn strided_mat64(m: Ix, n: Ix, s1: Ixs, s2: Ixs, rev: bool) -> Array2<f64> {
+    let (l1, l2) = (s1.abs() as usize, s2.abs() as usize);
+    if rev {
+        range_mat64(n * l2, m * l1)
+            .slice_move(s![..;s2, ..;s1])
+            .reversed_axes()
+    } else {
+        range_mat64(m * l1, n * l2).slice_move(s![..;s1, ..;s2])
+    }
+}
+
+fn strided_vec64(n: Ix, s: Ixs) -> Array1<f64> {
+    range1_mat64(n * s.abs() as usize).slice_move(s![..;s])
+}
+
+/// The `uplo` triangle of `a`, with ones on the diagonal if `diag` is unit
+fn triangle_of(a: &Array2<f64>, uplo: Triangle, diag: Diag) -> Array2<f64> {
+    Array2::from_shape_fn(a.dim(), |(i, j)| match uplo {
+        _ if i == j && diag == Diag::Unit => 1.,
+        Triangle::Upper if i <= j => a[[i, j]],
+        Triangle::Lower if i >= j => a[[i, j]],
+        _ => 0.,
+    })
+}
+
+/// The symmetric matrix with the `uplo` triangle of `a`
+fn symmetric_of(a: &Array2<f64>, uplo: Triangle) -> Array2<f64> {
+    let t = triangle_of(a, uplo, Diag::NonUnit);
+    &t + &t.t() - Array2::from_diag(&t.diag())
+}
+
+#[test]
+fn ger_syr_syrk() {
+    let alpha = -2.3;
+    let beta = 3.14;
+    let close = |a: &Array2<f64>, b: &Array2<f64>| {
+        assert_relative_eq!(a, b, epsilon = 1e-12, max_relative = 1e-7);
+    };
+    let sizes = vec![(4, 4), (8, 8), (17, 15), (4, 17), (1, 8), (8, 1)];
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &(m, n) in &sizes {
+                for &rev in &[false, true] {
+                    let x = strided_vec64(m, s1);
+                    let y = strided_vec64(n, s2);
+                    let x_col = x.view().insert_axis(Axis(1));
+                    let y_row = y.view().insert_axis(Axis(0));
+
+                    let mut a = strided_mat64(m, n, s1, s2, rev);
+                    let answer = &a + &(alpha * reference_mat_mul(&x_col, &y_row));
+                    a.ger(alpha, &x, &y);
+                    close(&a, &answer);
+
+                    let b = strided_mat64(m, n, s2, s1, !rev);
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        // the other triangle is not modified
+                        let outside = |c: &Array2<f64>| c - &triangle_of(c, uplo, Diag::NonUnit);
+
+                        let mut c = strided_mat64(m, m, s1, s2, rev);
+                        let c0 = c.clone();
+                        let xx = reference_mat_mul(&x_col, &x_col.t());
+                        let answer = symmetric_of(&c, uplo) + alpha * xx;
+                        c.syr(alpha, &x, uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                        assert_eq!(outside(&c), outside(&c0));
+
+                        let bb = reference_mat_mul(&b, &b.t());
+                        let answer = beta * symmetric_of(&c, uplo) + alpha * bb;
+                        c.syrk(alpha, &b, beta, uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                        assert_eq!(outside(&c), outside(&c0));
+
+                        let mut c = strided_mat64(n, n, s2, s1, rev);
+                        let answer = alpha * reference_mat_mul(&b.t(), &b);
+                        c.syrk(alpha, &b.t(), 0., uplo);
+                        close(&symmetric_of(&c, uplo), &answer);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn symv() {
+    let alpha = -2.3;
+    let beta = 3.14;
+    let close = |a: &Array1<f64>, b: &Array1<f64>| {
+        assert_relative_eq!(a, b, epsilon = 1e-12, max_relative = 1e-7);
+    };
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &n in &[1, 4, 8, 17] {
+                for &rev in &[false, true] {
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        let a = strided_mat64(n, n, s1, s2, rev);
+                        let x = strided_vec64(n, s2);
+                        let mut y = strided_vec64(n, s1);
+                        let x_col = x.view().insert_axis(Axis(1));
+                        let product =
+                            reference_mat_mul(&symmetric_of(&a, uplo), &x_col).remove_axis(Axis(1));
+                        let answer = alpha * &product + beta * &y;
+                        a.symv(alpha, &x, beta, &mut y, uplo);
+                        close(&y, &answer);
+
+                        // y is not read if beta is zero
+                        y.fill(f64::NAN);
+                        a.symv(alpha, &x, 0., &mut y, uplo);
+                        close(&y, &(alpha * &product));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn trmv_trsv() {
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &n in &[1, 4, 8, 17] {
+                for &rev in &[false, true] {
+                    // well conditioned
+                    let mut a = strided_mat64(n, n, s1, s2, rev);
+                    a.mapv_inplace(|x| x / (n * n) as f64);
+                    a.diag_mut().map_inplace(|x| *x += 1.);
+                    let x0 = strided_vec64(n, s2);
+                    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+                        for &trans in &[Transpose::No, Transpose::Transpose, Transpose::Adjoint] {
+                            for &diag in &[Diag::NonUnit, Diag::Unit] {
+                                let mut t = triangle_of(&a, uplo, diag);
+                                if trans != Transpose::No {
+                                    t = t.reversed_axes();
+                                }
+                                let answer = reference_mat_mul(&t, &x0.view().insert_axis(Axis(1)))
+                                    .remove_axis(Axis(1));
+                                let mut x = x0.clone();
+                                a.trmv(&mut x, uplo, trans, diag);
+                                assert_relative_eq!(x, answer, max_relative = 1e-7);
+                                a.trsv(&mut x, uplo, trans, diag);
+                                assert_relative_eq!(x, x0, epsilon = 1e-9, max_relative = 1e-7);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn scaled_add_axpy() {
+    let alpha = -2.3;
+    for &(m, n) in &[(4, 4), (17, 15), (67, 63)] {
+        for &rev in &[false, true] {
+            for &s in &[1, 2] {
+                let x = strided_mat64(m, n, s, s, rev);
+                let mut y = strided_mat64(m, n, 1, 1, rev);
+                let answer = &y + &(alpha * &x);
+                y.scaled_add(alpha, &x);
+                assert_relative_eq!(y, answer, epsilon = 1e-12, max_relative = 1e-7);
+            }
+        }
+    }
+}
+
+#[test]
+fn gemm_backend_stats() {
+    use ndarray::linalg::{gemm_stats, reset_gemm_stats, with_gemm_backend};
+    use ndarray::linalg::{GemmBackend, GemmFallback};
+
+    let a = range_mat64(16, 16);
+    let strided = range_mat64(32, 32);
+    let strided = strided.slice(s![..;2, ..;2]);
+    let expected = reference_mat_mul(&a, &a);
+    reset_gemm_stats();
+    with_gemm_backend(GemmBackend::Blas, || {
+        assert_eq!(a.dot(&a), expected);
+        assert_eq!(a.dot(&strided), reference_mat_mul(&a, &strided));
+        let small = range_mat64(3, 3);
+        assert_eq!(small.dot(&small), reference_mat_mul(&small, &small));
+        let ints = range_i32(16, 16);
+        assert_eq!(ints.dot(&ints), reference_mat_mul(&ints, &ints));
+    });
+    let stats = gemm_stats();
+    assert_eq!((stats.blas, stats.native), (1, 3));
+    assert_eq!(stats.fallbacks(GemmFallback::Layout), 1);
+    assert_eq!(stats.fallbacks(GemmFallback::Small), 1);
+    assert_eq!(stats.fallbacks(GemmFallback::ElementType), 1);
+
+    reset_gemm_stats();
+    assert_eq!(with_gemm_backend(GemmBackend::Native, || a.dot(&a)), expected);
+    assert_eq!((gemm_stats().blas, gemm_stats().native), (0, 1));
+
+    // matrix-vector products use gemv
+    let v = range1_mat64(16);
+    reset_gemm_stats();
+    with_gemm_backend(GemmBackend::Blas, || {
+        assert_eq!(a.dot(&v), reference_mat_vec_mul(&a, &v));
+        assert_eq!(v.dot(&a), reference_vec_mat_mul(&v, &a));
+        let ints = range_i32(4, 4);
+        let w = Array1::from(vec![1, 2, 3, 4]);
+        assert_eq!(ints.dot(&w), reference_mat_vec_mul(&ints, &w));
+    });
+    let stats = gemm_stats();
+    assert_eq!((stats.blas, stats.native), (2, 1));
+    assert_eq!(stats.fallbacks(GemmFallback::ElementType), 1);
+    reset_gemm_stats();
+    with_gemm_backend(GemmBackend::Native, || a.dot(&v));
+    assert_eq!((gemm_stats().blas, gemm_stats().native), (0, 1));
+}